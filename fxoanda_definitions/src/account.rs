@@ -0,0 +1,44 @@
+use crate::{Account, AccountSummary};
+
+macro_rules! account_accessors {
+    ($($account:ident),*) => {
+      $(
+        impl $account {
+            /// Net asset value as used for margin closeout, in the home currency.
+            pub fn margin_closeout_nav(&self) -> Option<f64> {
+                self.margin_closeout_nav
+            }
+
+            /// Margin used as counted towards margin closeout, in the home currency.
+            pub fn margin_closeout_margin_used(&self) -> Option<f64> {
+                self.margin_closeout_margin_used
+            }
+
+            /// Value of open positions as used for margin closeout, in the home currency.
+            pub fn margin_closeout_position_value(&self) -> Option<f64> {
+                self.margin_closeout_position_value
+            }
+
+            /// Margin closeout usage as a ratio: OANDA closes positions out once
+            /// this reaches 1.0.
+            pub fn margin_closeout_percent(&self) -> Option<f64> {
+                self.margin_closeout_percent
+            }
+
+            /// Whether the margin closeout ratio has reached `threshold` (e.g. 0.8 to
+            /// warn at 80% of the way to closeout). False if OANDA didn't report it.
+            pub fn is_near_margin_closeout(&self, threshold: f64) -> bool {
+                self.margin_closeout_percent().is_some_and(|percent| percent >= threshold)
+            }
+
+            /// Whether the Account keeps long and short positions in an instrument
+            /// separately (hedging) instead of netting them. `None` if not reported.
+            pub fn is_hedging(&self) -> Option<bool> {
+                self.hedging_enabled
+            }
+        }
+      )*
+    };
+}
+
+account_accessors!(Account, AccountSummary);
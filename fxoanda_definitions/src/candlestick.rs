@@ -0,0 +1,102 @@
+use crate::{Candlestick, CandlestickData};
+#[cfg(feature = "decimal")]
+use fxoanda_serdes::{serdates, serdecimal};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+impl Candlestick {
+    /// Open, high, low and close of the mid prices, if the candle has all four.
+    pub fn mid_ohlc(&self) -> Option<(f64, f64, f64, f64)> {
+        self.mid.as_ref()?.ohlc()
+    }
+
+    /// Open, high, low and close of the bid prices, if the candle has all four.
+    pub fn bid_ohlc(&self) -> Option<(f64, f64, f64, f64)> {
+        self.bid.as_ref()?.ohlc()
+    }
+
+    /// Open, high, low and close of the ask prices, if the candle has all four.
+    pub fn ask_ohlc(&self) -> Option<(f64, f64, f64, f64)> {
+        self.ask.as_ref()?.ohlc()
+    }
+
+    /// Whether the candle's period has ended. A candle that doesn't say is taken
+    /// to be still forming.
+    pub fn is_complete(&self) -> bool {
+        self.complete.unwrap_or(false)
+    }
+}
+
+impl CandlestickData {
+    fn ohlc(&self) -> Option<(f64, f64, f64, f64)> {
+        Some((self.o?, self.h?, self.l?, self.c?))
+    }
+}
+
+/// The open, high, low and close of a candle as `Decimal`s, read straight from
+/// the strings OANDA sends so that nothing passes through a float and the quoted
+/// scale is kept (`"1.10000"` stays `1.10000`).
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct DecimalCandlestickData {
+    #[serde(default, with = "serdecimal", skip_serializing_if = "Option::is_none")]
+    pub o: Option<Decimal>,
+    #[serde(default, with = "serdecimal", skip_serializing_if = "Option::is_none")]
+    pub h: Option<Decimal>,
+    #[serde(default, with = "serdecimal", skip_serializing_if = "Option::is_none")]
+    pub l: Option<Decimal>,
+    #[serde(default, with = "serdecimal", skip_serializing_if = "Option::is_none")]
+    pub c: Option<Decimal>,
+}
+
+#[cfg(feature = "decimal")]
+impl DecimalCandlestickData {
+    /// Open, high, low and close, if the candle has all four.
+    pub fn ohlc(&self) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        Some((self.o?, self.h?, self.l?, self.c?))
+    }
+}
+
+/// A [`Candlestick`] with [`DecimalCandlestickData`] prices. It deserializes from
+/// the same JSON, for callers who want exact prices; see
+/// `GetInstrumentCandlesRequest::remote_decimal`.
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DecimalCandlestick {
+    #[serde(default, with = "serdates", skip_serializing_if = "Option::is_none")]
+    pub time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bid: Option<DecimalCandlestickData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mid: Option<DecimalCandlestickData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ask: Option<DecimalCandlestickData>,
+}
+
+#[cfg(feature = "decimal")]
+impl DecimalCandlestick {
+    /// Open, high, low and close of the mid prices, if the candle has all four.
+    pub fn mid_ohlc(&self) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        self.mid.as_ref()?.ohlc()
+    }
+
+    /// Open, high, low and close of the bid prices, if the candle has all four.
+    pub fn bid_ohlc(&self) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        self.bid.as_ref()?.ohlc()
+    }
+
+    /// Open, high, low and close of the ask prices, if the candle has all four.
+    pub fn ask_ohlc(&self) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        self.ask.as_ref()?.ohlc()
+    }
+
+    /// Whether the candle's period has ended. A candle that doesn't say is taken
+    /// to be still forming.
+    pub fn is_complete(&self) -> bool {
+        self.complete.unwrap_or(false)
+    }
+}
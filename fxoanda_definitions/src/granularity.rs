@@ -0,0 +1,75 @@
+use crate::CandlestickGranularity;
+
+impl CandlestickGranularity {
+    /// The granularity as OANDA writes it, e.g. `"H4"`; also what `Display` prints.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CandlestickGranularity::S5 => "S5",
+            CandlestickGranularity::S10 => "S10",
+            CandlestickGranularity::S15 => "S15",
+            CandlestickGranularity::S30 => "S30",
+            CandlestickGranularity::M1 => "M1",
+            CandlestickGranularity::M2 => "M2",
+            CandlestickGranularity::M4 => "M4",
+            CandlestickGranularity::M5 => "M5",
+            CandlestickGranularity::M10 => "M10",
+            CandlestickGranularity::M15 => "M15",
+            CandlestickGranularity::M30 => "M30",
+            CandlestickGranularity::H1 => "H1",
+            CandlestickGranularity::H2 => "H2",
+            CandlestickGranularity::H3 => "H3",
+            CandlestickGranularity::H4 => "H4",
+            CandlestickGranularity::H6 => "H6",
+            CandlestickGranularity::H8 => "H8",
+            CandlestickGranularity::H12 => "H12",
+            CandlestickGranularity::D => "D",
+            CandlestickGranularity::W => "W",
+            CandlestickGranularity::M => "M",
+        }
+    }
+
+    /// The length of one candle in seconds, or `None` for weekly and monthly
+    /// candles, which don't have a fixed length.
+    pub fn seconds(&self) -> Option<u64> {
+        match self {
+            CandlestickGranularity::S5 => Some(5),
+            CandlestickGranularity::S10 => Some(10),
+            CandlestickGranularity::S15 => Some(15),
+            CandlestickGranularity::S30 => Some(30),
+            CandlestickGranularity::M1 => Some(60),
+            CandlestickGranularity::M2 => Some(2 * 60),
+            CandlestickGranularity::M4 => Some(4 * 60),
+            CandlestickGranularity::M5 => Some(5 * 60),
+            CandlestickGranularity::M10 => Some(10 * 60),
+            CandlestickGranularity::M15 => Some(15 * 60),
+            CandlestickGranularity::M30 => Some(30 * 60),
+            CandlestickGranularity::H1 => Some(3600),
+            CandlestickGranularity::H2 => Some(2 * 3600),
+            CandlestickGranularity::H3 => Some(3 * 3600),
+            CandlestickGranularity::H4 => Some(4 * 3600),
+            CandlestickGranularity::H6 => Some(6 * 3600),
+            CandlestickGranularity::H8 => Some(8 * 3600),
+            CandlestickGranularity::H12 => Some(12 * 3600),
+            CandlestickGranularity::D => Some(86400),
+            CandlestickGranularity::W | CandlestickGranularity::M => None,
+        }
+    }
+
+    /// The length of one candle as a `chrono::Duration`; `None` for W and M.
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        self.seconds().map(|s| chrono::Duration::seconds(s as i64))
+    }
+}
+
+/// A string that isn't one of OANDA's candlestick granularities, from
+/// `CandlestickGranularity::from_str`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGranularityError(pub String);
+
+impl std::fmt::Display for ParseGranularityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "unknown candlestick granularity '{}', expected one of S5-S30, M1-M30, H1-H12, D, W or M", self.0)
+    }
+}
+
+impl std::error::Error for ParseGranularityError {}
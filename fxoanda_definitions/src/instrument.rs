@@ -0,0 +1,56 @@
+use crate::Instrument;
+use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
+
+impl Instrument {
+    /// Whether the instrument trades at `when`.
+    ///
+    /// OANDA's instrument metadata carries financing days but no session times,
+    /// so this follows the weekly FX schedule: closed from Friday 21:00 UTC until
+    /// Sunday 22:00 UTC. The bounds are the earliest New York close and latest
+    /// reopen across daylight saving, so the check errs towards closed. Holidays
+    /// and instrument-specific breaks aren't known.
+    pub fn is_tradeable_at(&self, when: DateTime<Utc>) -> bool {
+        match when.weekday() {
+            Weekday::Fri => when.hour() < 21,
+            Weekday::Sat => false,
+            Weekday::Sun => when.hour() >= 22,
+            _ => true,
+        }
+    }
+
+    /// Days of the week on which financing is charged, with the number of days
+    /// charged on each. Days that charge nothing are left out.
+    pub fn financing_days(&self) -> Vec<(String, i32)> {
+        self.financing
+            .iter()
+            .flat_map(|financing| financing.financing_days_of_week.iter().flatten())
+            .filter_map(|day| Some((day.day_of_week.clone()?, day.days_charged?)))
+            .filter(|(_, charged)| *charged > 0)
+            .collect()
+    }
+
+    /// `price_delta` in pips, where a pip is `10^pipLocation`.
+    ///
+    /// The pip sits at different places for different instruments: `pipLocation`
+    /// is -4 for most pairs (a pip of 0.0001 in EUR_USD), but -2 for JPY-quoted
+    /// pairs such as USD_JPY, where prices carry three decimals and a pip is 0.01.
+    /// Metals and CFDs vary further (XAU_USD is -2, indices often 0), so always use
+    /// the instrument's own value rather than assuming four decimals. An instrument
+    /// without a pip location is treated as 0, leaving the delta unchanged.
+    pub fn pips(&self, price_delta: f64) -> f64 {
+        price_delta / 10f64.powi(self.pip_location.unwrap_or(0))
+    }
+
+    /// `price` rounded to the instrument's `displayPrecision`, the number of
+    /// decimals OANDA accepts on order prices (5 for EUR_USD, 3 for USD_JPY).
+    /// Returned unchanged if the instrument has no display precision.
+    pub fn round_to_display_precision(&self, price: f64) -> f64 {
+        match self.display_precision {
+            Some(precision) => {
+                let scale = 10f64.powi(precision);
+                (price * scale).round() / scale
+            }
+            None => price,
+        }
+    }
+}
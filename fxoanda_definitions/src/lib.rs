@@ -6,7 +6,24 @@ extern crate serde;
 use fxoanda_serdes::*;
 use chrono::prelude::*;
 use std::str::FromStr;
-#[derive(Debug, Serialize, Deserialize)]
+
+mod account;
+mod candlestick;
+mod granularity;
+mod instrument;
+mod order;
+mod pricing;
+mod transaction;
+
+pub use granularity::ParseGranularityError;
+pub use order::{PositionFill, TriggerCondition};
+#[cfg(feature = "decimal")]
+pub use candlestick::{DecimalCandlestick, DecimalCandlestickData};
+pub use transaction::TransactionKind;
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeClientExtensionsModifyRejectTransaction {
     /// The ID of the Trade who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -24,8 +41,12 @@ pub struct TradeClientExtensionsModifyRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -127,9 +148,9 @@ impl TradeClientExtensionsModifyRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TradeClientExtensionsModifyRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -214,7 +235,7 @@ impl TradeClientExtensionsModifyRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -248,7 +269,7 @@ pub struct MarketIfTouchedOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub initial_market_price: Option<f32>,
+    pub initial_market_price: Option<f64>,
 
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -274,7 +295,7 @@ pub struct MarketIfTouchedOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The MarketIfTouched Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -299,7 +320,7 @@ pub struct MarketIfTouchedOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The type of the Order. Always set to "MARKET_IF_TOUCHED" for Market If
     /// Touched Orders.
@@ -329,7 +350,7 @@ pub struct MarketIfTouchedOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// Trade ID of Trade opened when the Order was filled (only provided when
     /// the Order's state is FILLED and a Trade was opened as a result of the
@@ -559,13 +580,21 @@ impl MarketIfTouchedOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return MarketIfTouchedOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Market price at the time when the MarketIfTouched Order was
     /// created.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrder
-    pub fn with_initial_market_price(mut self, x: f32) -> Self {
+    pub fn with_initial_market_price(mut self, x: f64) -> Self {
         self.initial_market_price = Some(x);
         self
     }
@@ -585,8 +614,8 @@ impl MarketIfTouchedOrder {
     /// Order is filled.
     /// - param String
     /// - return MarketIfTouchedOrder
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -594,9 +623,9 @@ impl MarketIfTouchedOrder {
     /// Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrder
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
@@ -624,9 +653,9 @@ impl MarketIfTouchedOrder {
     /// number of units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrder
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -640,6 +669,14 @@ impl MarketIfTouchedOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return MarketIfTouchedOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
     /// that opens a Trade requiring a Stop Loss, or when a Trade's dependent
@@ -659,9 +696,9 @@ impl MarketIfTouchedOrder {
     /// MarketIfTouchedOrder will behave like a Limit or a Stop Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrder
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -773,8 +810,8 @@ impl MarketIfTouchedOrder {
     /// valid.
     /// - param String
     /// - return MarketIfTouchedOrder
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -859,7 +896,7 @@ impl MarketIfTouchedOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeClientExtensionsModifyTransaction {
     /// The ID of the Trade who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -877,8 +914,12 @@ pub struct TradeClientExtensionsModifyTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -974,9 +1015,9 @@ impl TradeClientExtensionsModifyTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TradeClientExtensionsModifyTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -1053,7 +1094,7 @@ impl TradeClientExtensionsModifyTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixedPriceOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -1123,7 +1164,7 @@ pub struct FixedPriceOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -1216,7 +1257,7 @@ pub struct FixedPriceOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// ID of the Transaction that cancelled the Order (only provided when the
     /// Order's state is CANCELLED)
@@ -1350,9 +1391,9 @@ impl FixedPriceOrder {
     /// price that the Fixed Price Order will be filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return FixedPriceOrder
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -1427,8 +1468,8 @@ impl FixedPriceOrder {
     /// Order is filled.
     /// - param String
     /// - return FixedPriceOrder
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -1459,9 +1500,9 @@ impl FixedPriceOrder {
     /// number of units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return FixedPriceOrder
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -1495,6 +1536,14 @@ impl FixedPriceOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return FixedPriceOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -1518,12 +1567,16 @@ impl FixedPriceOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelayedTradeClosureTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -1601,9 +1654,9 @@ impl DelayedTradeClosureTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return DelayedTradeClosureTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -1689,7 +1742,7 @@ impl DelayedTradeClosureTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LimitOrderRejectTransaction {
     /// The time-in-force requested for the Limit Order.
     #[serde(default)]
@@ -1715,8 +1768,12 @@ pub struct LimitOrderRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The Limit Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -1736,7 +1793,7 @@ pub struct LimitOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "LIMIT_ORDER_REJECT" in a
     /// LimitOrderRejectTransaction.
@@ -1762,7 +1819,7 @@ pub struct LimitOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -1948,15 +2005,15 @@ impl LimitOrderRejectTransaction {
     /// Order is filled.
     /// - param String
     /// - return LimitOrderRejectTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return LimitOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -1976,9 +2033,9 @@ impl LimitOrderRejectTransaction {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrderRejectTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -2007,9 +2064,9 @@ impl LimitOrderRejectTransaction {
     /// this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrderRejectTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -2085,8 +2142,8 @@ impl LimitOrderRejectTransaction {
     /// valid.
     /// - param String
     /// - return LimitOrderRejectTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -2164,7 +2221,7 @@ impl LimitOrderRejectTransaction {
 /// In the context of an Order or a Trade, defines whether the units are
 /// positive or negative.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Direction {
     #[serde(rename = "LONG")]
     Long,
@@ -2192,7 +2249,7 @@ impl std::fmt::Display for Direction {
 /// The day of the week to use for candlestick granularities with weekly
 /// alignment.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WeeklyAlignment {
     #[serde(rename = "Monday")]
     Monday,
@@ -2232,7 +2289,7 @@ impl std::fmt::Display for WeeklyAlignment {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderPositionCloseout {
     /// Indication of how much of the Position to close. Either "ALL", or a
     /// DecimalNumber reflection a partial close of the Trade. The
@@ -2279,7 +2336,7 @@ impl MarketOrderPositionCloseout {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeSummary {
     /// ID of the Trade's Stop Loss Order, only provided if such an Order
     /// exists.
@@ -2299,7 +2356,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The date/time when the Trade was opened.
     /// format: The RFC 3339 representation is a string conforming to
@@ -2325,7 +2382,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// ID of the Trade's Trailing Stop Loss Order, only provided if such an
     /// Order exists.
@@ -2348,7 +2405,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The unrealized profit/loss on the open portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -2359,7 +2416,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// The total profit/loss realized on the closed portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -2370,7 +2427,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub realized_pl: Option<f32>,
+    pub realized_pl: Option<f64>,
 
     /// The date/time when the Trade was fully closed. Only provided for
     /// Trades whose state is CLOSED.
@@ -2420,7 +2477,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub initial_margin_required: Option<f32>,
+    pub initial_margin_required: Option<f64>,
 
     /// The initial size of the Trade. Negative values indicate a short Trade,
     /// and positive values indicate a long Trade.
@@ -2432,7 +2489,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub initial_units: Option<f32>,
+    pub initial_units: Option<f64>,
 
     /// The average closing price of the Trade. Only present if the Trade has
     /// been closed or reduced at least once.
@@ -2444,7 +2501,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub average_close_price: Option<f32>,
+    pub average_close_price: Option<f64>,
 
     /// The number of units currently open for the Trade. This value is
     /// reduced to 0.0 as the Trade is closed.
@@ -2456,7 +2513,7 @@ pub struct TradeSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub current_units: Option<f32>,
+    pub current_units: Option<f64>,
 
     /// The IDs of the Transactions that have closed portions of this Trade.
     #[serde(default)]
@@ -2521,9 +2578,9 @@ impl TradeSummary {
     /// The financing paid/collected for this Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -2545,9 +2602,9 @@ impl TradeSummary {
     /// Margin currently used by the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -2567,9 +2624,9 @@ impl TradeSummary {
     /// The execution price of the Trade.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -2577,9 +2634,9 @@ impl TradeSummary {
     /// The unrealized profit/loss on the open portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -2587,9 +2644,9 @@ impl TradeSummary {
     /// The total profit/loss realized on the closed portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_realized_pl(mut self, x: f32) -> Self {
+    pub fn with_realized_pl(mut self, x: f64) -> Self {
         self.realized_pl = Some(x);
         self
     }
@@ -2644,9 +2701,9 @@ impl TradeSummary {
     /// factors in the trade risk if a GSLO is attached to the trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_initial_margin_required(mut self, x: f32) -> Self {
+    pub fn with_initial_margin_required(mut self, x: f64) -> Self {
         self.initial_margin_required = Some(x);
         self
     }
@@ -2655,9 +2712,9 @@ impl TradeSummary {
     /// and positive values indicate a long Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_initial_units(mut self, x: f32) -> Self {
+    pub fn with_initial_units(mut self, x: f64) -> Self {
         self.initial_units = Some(x);
         self
     }
@@ -2666,9 +2723,9 @@ impl TradeSummary {
     /// been closed or reduced at least once.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_average_close_price(mut self, x: f32) -> Self {
+    pub fn with_average_close_price(mut self, x: f64) -> Self {
         self.average_close_price = Some(x);
         self
     }
@@ -2677,9 +2734,9 @@ impl TradeSummary {
     /// reduced to 0.0 as the Trade is closed.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TradeSummary
-    pub fn with_current_units(mut self, x: f32) -> Self {
+    pub fn with_current_units(mut self, x: f64) -> Self {
         self.current_units = Some(x);
         self
     }
@@ -2714,7 +2771,7 @@ impl TradeSummary {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopLossOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -2751,7 +2808,7 @@ pub struct StopLossOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_premium: Option<f32>,
+    pub guaranteed_execution_premium: Option<f64>,
 
     /// The time-in-force requested for the StopLoss Order. Restricted to
     /// "GTC", "GFD" and "GTD" for StopLoss Orders.
@@ -2797,7 +2854,7 @@ pub struct StopLossOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// Trade ID of Trade opened when the Order was filled (only provided when
     /// the Order's state is FILLED and a Trade was opened as a result of the
@@ -2878,7 +2935,7 @@ pub struct StopLossOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -3019,9 +3076,9 @@ impl StopLossOrder {
     /// and is charged for each unit of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrder
-    pub fn with_guaranteed_execution_premium(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_premium(mut self, x: f64) -> Self {
         self.guaranteed_execution_premium = Some(x);
         self
     }
@@ -3035,6 +3092,14 @@ impl StopLossOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return StopLossOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -3073,15 +3138,23 @@ impl StopLossOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return StopLossOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// The price threshold specified for the Stop Loss Order. If the
     /// guaranteed flag is false, the associated Trade will be closed by a
     /// market price that is equal to or worse than this threshold. If the
     /// flag is true the associated Trade will be closed at this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrder
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -3166,9 +3239,9 @@ impl StopLossOrder {
     /// Instrument's bid price is used, and for long Trades the ask is used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrder
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -3193,8 +3266,8 @@ impl StopLossOrder {
     /// valid.
     /// - param String
     /// - return StopLossOrder
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -3269,7 +3342,7 @@ impl StopLossOrder {
 
 /// The financing mode of an Account
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AccountFinancingMode {
     #[serde(rename = "NO_FINANCING")]
     NoFinancing,
@@ -3297,7 +3370,7 @@ impl std::fmt::Display for AccountFinancingMode {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountChangesState {
     /// The price-dependent state for each open Position in the Account.
     #[serde(default)]
@@ -3318,7 +3391,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_nav: Option<f32>,
+    pub margin_closeout_nav: Option<f64>,
 
     /// Margin currently used for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -3329,7 +3402,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// Margin available for Account currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -3340,7 +3413,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_available: Option<f32>,
+    pub margin_available: Option<f64>,
 
     /// The current WithdrawalLimit for the account which will be zero or a
     /// positive value indicating how much can be withdrawn from the account.
@@ -3352,7 +3425,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub withdrawal_limit: Option<f32>,
+    pub withdrawal_limit: Option<f64>,
 
     /// The total unrealized profit/loss for all Trades currently open in the
     /// Account.
@@ -3364,7 +3437,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// The Account's margin closeout margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -3375,7 +3448,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_margin_used: Option<f32>,
+    pub margin_closeout_margin_used: Option<f64>,
 
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -3386,7 +3459,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_margin_used: Option<f32>,
+    pub margin_call_margin_used: Option<f64>,
 
     /// The Account's margin call percentage. When this value is 1.0 or above
     /// the Account is in a margin call situation.
@@ -3398,7 +3471,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_percent: Option<f32>,
+    pub margin_call_percent: Option<f64>,
 
     /// The Account's margin closeout percentage. When this value is 1.0 or
     /// above the Account is in a margin closeout situation.
@@ -3410,7 +3483,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_percent: Option<f32>,
+    pub margin_closeout_percent: Option<f64>,
 
     /// The net asset value of the Account. Equal to Account balance +
     /// unrealizedPL.
@@ -3422,7 +3495,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub nav: Option<f32>,
+    pub nav: Option<f64>,
 
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -3433,7 +3506,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_unrealized_pl: Option<f32>,
+    pub margin_closeout_unrealized_pl: Option<f64>,
 
     /// The price-dependent state of each pending Order in the Account.
     #[serde(default)]
@@ -3450,7 +3523,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_position_value: Option<f32>,
+    pub margin_closeout_position_value: Option<f64>,
 
     /// The value of the Account's open positions represented in the Account's
     /// home currency.
@@ -3462,7 +3535,7 @@ pub struct AccountChangesState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub position_value: Option<f32>,
+    pub position_value: Option<f64>,
 }
 impl AccountChangesState {
     pub fn new() -> AccountChangesState {
@@ -3505,9 +3578,9 @@ impl AccountChangesState {
     /// The Account's margin closeout NAV.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_closeout_nav(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_nav(mut self, x: f64) -> Self {
         self.margin_closeout_nav = Some(x);
         self
     }
@@ -3515,9 +3588,9 @@ impl AccountChangesState {
     /// Margin currently used for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -3525,9 +3598,9 @@ impl AccountChangesState {
     /// Margin available for Account currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_available(mut self, x: f32) -> Self {
+    pub fn with_margin_available(mut self, x: f64) -> Self {
         self.margin_available = Some(x);
         self
     }
@@ -3536,9 +3609,9 @@ impl AccountChangesState {
     /// positive value indicating how much can be withdrawn from the account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_withdrawal_limit(mut self, x: f32) -> Self {
+    pub fn with_withdrawal_limit(mut self, x: f64) -> Self {
         self.withdrawal_limit = Some(x);
         self
     }
@@ -3547,9 +3620,9 @@ impl AccountChangesState {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -3557,9 +3630,9 @@ impl AccountChangesState {
     /// The Account's margin closeout margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_closeout_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_margin_used(mut self, x: f64) -> Self {
         self.margin_closeout_margin_used = Some(x);
         self
     }
@@ -3567,9 +3640,9 @@ impl AccountChangesState {
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_call_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_call_margin_used(mut self, x: f64) -> Self {
         self.margin_call_margin_used = Some(x);
         self
     }
@@ -3578,9 +3651,9 @@ impl AccountChangesState {
     /// the Account is in a margin call situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_call_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_call_percent(mut self, x: f64) -> Self {
         self.margin_call_percent = Some(x);
         self
     }
@@ -3589,9 +3662,9 @@ impl AccountChangesState {
     /// above the Account is in a margin closeout situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_closeout_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_percent(mut self, x: f64) -> Self {
         self.margin_closeout_percent = Some(x);
         self
     }
@@ -3600,9 +3673,9 @@ impl AccountChangesState {
     /// unrealizedPL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_nav(mut self, x: f32) -> Self {
+    pub fn with_nav(mut self, x: f64) -> Self {
         self.nav = Some(x);
         self
     }
@@ -3610,9 +3683,9 @@ impl AccountChangesState {
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_closeout_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_unrealized_pl(mut self, x: f64) -> Self {
         self.margin_closeout_unrealized_pl = Some(x);
         self
     }
@@ -3629,9 +3702,9 @@ impl AccountChangesState {
     /// calculations represented in the Account's home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_margin_closeout_position_value(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_position_value(mut self, x: f64) -> Self {
         self.margin_closeout_position_value = Some(x);
         self
     }
@@ -3640,9 +3713,9 @@ impl AccountChangesState {
     /// home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountChangesState
-    pub fn with_position_value(mut self, x: f32) -> Self {
+    pub fn with_position_value(mut self, x: f64) -> Self {
         self.position_value = Some(x);
         self
     }
@@ -3650,7 +3723,7 @@ impl AccountChangesState {
 
 /// The reason that an Account is being funded.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FundingReason {
     #[serde(rename = "CLIENT_FUNDING")]
     ClientFunding,
@@ -3684,12 +3757,16 @@ impl std::fmt::Display for FundingReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfigureTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The margin rate override for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -3700,7 +3777,7 @@ pub struct ClientConfigureTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_rate: Option<f32>,
+    pub margin_rate: Option<f64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -3769,9 +3846,9 @@ impl ClientConfigureTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return ClientConfigureTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -3779,9 +3856,9 @@ impl ClientConfigureTransaction {
     /// The margin rate override for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return ClientConfigureTransaction
-    pub fn with_margin_rate(mut self, x: f32) -> Self {
+    pub fn with_margin_rate(mut self, x: f64) -> Self {
         self.margin_rate = Some(x);
         self
     }
@@ -3855,7 +3932,7 @@ impl ClientConfigureTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Account {
     /// The details all Account Positions.
     #[serde(default)]
@@ -3891,7 +3968,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// The value of the Account's open positions as used for margin closeout
     /// calculations represented in the Account's home currency.
@@ -3903,7 +3980,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_position_value: Option<f32>,
+    pub margin_closeout_position_value: Option<f64>,
 
     /// The number of times that the Account's current margin call was
     /// extended.
@@ -3931,7 +4008,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub resettable_pl: Option<f32>,
+    pub resettable_pl: Option<f64>,
 
     /// The net asset value of the Account. Equal to Account balance +
     /// unrealizedPL.
@@ -3943,7 +4020,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub nav: Option<f32>,
+    pub nav: Option<f64>,
 
     /// The date/time of the Account's last margin call extension.
     /// format: The RFC 3339 representation is a string conforming to
@@ -3969,7 +4046,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_margin_used: Option<f32>,
+    pub margin_closeout_margin_used: Option<f64>,
 
     /// The number of Trades currently open in the Account.
     #[serde(default)]
@@ -3997,7 +4074,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_nav: Option<f32>,
+    pub margin_closeout_nav: Option<f64>,
 
     /// The Account's margin closeout percentage. When this value is 1.0 or
     /// above the Account is in a margin closeout situation.
@@ -4009,7 +4086,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_percent: Option<f32>,
+    pub margin_closeout_percent: Option<f64>,
 
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -4020,7 +4097,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_margin_used: Option<f32>,
+    pub margin_call_margin_used: Option<f64>,
 
     /// The total amount of commission paid over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -4031,7 +4108,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub commission: Option<f32>,
+    pub commission: Option<f64>,
 
     /// Flag indicating that the Account has hedging enabled.
     #[serde(default)]
@@ -4048,7 +4125,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub position_value: Option<f32>,
+    pub position_value: Option<f64>,
 
     /// The total profit/loss realized over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -4059,7 +4136,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub pl: Option<f32>,
+    pub pl: Option<f64>,
 
     /// The current guaranteed Stop Loss Order mode of the Account.
     #[serde(default)]
@@ -4084,7 +4161,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_available: Option<f32>,
+    pub margin_available: Option<f64>,
 
     /// Client-provided margin rate override for the Account. The effective
     /// margin rate of the Account is the lesser of this value and the OANDA
@@ -4098,7 +4175,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_rate: Option<f32>,
+    pub margin_rate: Option<f64>,
 
     /// The Account's margin call percentage. When this value is 1.0 or above
     /// the Account is in a margin call situation.
@@ -4110,7 +4187,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_percent: Option<f32>,
+    pub margin_call_percent: Option<f64>,
 
     /// The date/time when the Account entered a margin call state. Only
     /// provided if the Account is in a margin call.
@@ -4138,7 +4215,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fees: Option<f32>,
+    pub guaranteed_execution_fees: Option<f64>,
 
     /// The total amount of financing paid/collected over the lifetime of the
     /// Account.
@@ -4150,7 +4227,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The current balance of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -4161,7 +4238,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub balance: Option<f32>,
+    pub balance: Option<f64>,
 
     /// The number of Orders currently pending in the Account.
     #[serde(default)]
@@ -4178,7 +4255,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub withdrawal_limit: Option<f32>,
+    pub withdrawal_limit: Option<f64>,
 
     /// The total unrealized profit/loss for all Trades currently open in the
     /// Account.
@@ -4190,7 +4267,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// Client-assigned alias for the Account. Only provided if the Account
     /// has an alias set
@@ -4200,8 +4277,12 @@ pub struct Account {
 
     /// ID of the user that created the Account.
     #[serde(default)]
-    #[serde(rename = "createdByUserID", skip_serializing_if = "Option::is_none")]
-    pub created_by_user_id: Option<i32>,
+    #[serde(
+        rename = "createdByUserID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub created_by_user_id: Option<i64>,
 
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -4212,7 +4293,7 @@ pub struct Account {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_unrealized_pl: Option<f32>,
+    pub margin_closeout_unrealized_pl: Option<f64>,
 
     /// The date/time when the Account was created.
     /// format: The RFC 3339 representation is a string conforming to
@@ -4327,9 +4408,9 @@ impl Account {
     /// Margin currently used for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -4338,9 +4419,9 @@ impl Account {
     /// calculations represented in the Account's home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_closeout_position_value(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_position_value(mut self, x: f64) -> Self {
         self.margin_closeout_position_value = Some(x);
         self
     }
@@ -4368,9 +4449,9 @@ impl Account {
     /// by the client.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_resettable_pl(mut self, x: f32) -> Self {
+    pub fn with_resettable_pl(mut self, x: f64) -> Self {
         self.resettable_pl = Some(x);
         self
     }
@@ -4379,9 +4460,9 @@ impl Account {
     /// unrealizedPL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_nav(mut self, x: f32) -> Self {
+    pub fn with_nav(mut self, x: f64) -> Self {
         self.nav = Some(x);
         self
     }
@@ -4403,9 +4484,9 @@ impl Account {
     /// The Account's margin closeout margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_closeout_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_margin_used(mut self, x: f64) -> Self {
         self.margin_closeout_margin_used = Some(x);
         self
     }
@@ -4439,9 +4520,9 @@ impl Account {
     /// The Account's margin closeout NAV.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_closeout_nav(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_nav(mut self, x: f64) -> Self {
         self.margin_closeout_nav = Some(x);
         self
     }
@@ -4450,9 +4531,9 @@ impl Account {
     /// above the Account is in a margin closeout situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_closeout_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_percent(mut self, x: f64) -> Self {
         self.margin_closeout_percent = Some(x);
         self
     }
@@ -4460,9 +4541,9 @@ impl Account {
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_call_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_call_margin_used(mut self, x: f64) -> Self {
         self.margin_call_margin_used = Some(x);
         self
     }
@@ -4470,9 +4551,9 @@ impl Account {
     /// The total amount of commission paid over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_commission(mut self, x: f32) -> Self {
+    pub fn with_commission(mut self, x: f64) -> Self {
         self.commission = Some(x);
         self
     }
@@ -4489,9 +4570,9 @@ impl Account {
     /// home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_position_value(mut self, x: f32) -> Self {
+    pub fn with_position_value(mut self, x: f64) -> Self {
         self.position_value = Some(x);
         self
     }
@@ -4499,9 +4580,9 @@ impl Account {
     /// The total profit/loss realized over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_pl(mut self, x: f32) -> Self {
+    pub fn with_pl(mut self, x: f64) -> Self {
         self.pl = Some(x);
         self
     }
@@ -4526,9 +4607,9 @@ impl Account {
     /// Margin available for Account currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_available(mut self, x: f32) -> Self {
+    pub fn with_margin_available(mut self, x: f64) -> Self {
         self.margin_available = Some(x);
         self
     }
@@ -4539,9 +4620,9 @@ impl Account {
     /// a margin rate override exists for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_rate(mut self, x: f32) -> Self {
+    pub fn with_margin_rate(mut self, x: f64) -> Self {
         self.margin_rate = Some(x);
         self
     }
@@ -4550,9 +4631,9 @@ impl Account {
     /// the Account is in a margin call situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_call_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_call_percent(mut self, x: f64) -> Self {
         self.margin_call_percent = Some(x);
         self
     }
@@ -4576,9 +4657,9 @@ impl Account {
     /// the execution of guaranteed Stop Loss Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_guaranteed_execution_fees(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fees(mut self, x: f64) -> Self {
         self.guaranteed_execution_fees = Some(x);
         self
     }
@@ -4587,9 +4668,9 @@ impl Account {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -4597,9 +4678,9 @@ impl Account {
     /// The current balance of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_balance(mut self, x: f32) -> Self {
+    pub fn with_balance(mut self, x: f64) -> Self {
         self.balance = Some(x);
         self
     }
@@ -4616,9 +4697,9 @@ impl Account {
     /// positive value indicating how much can be withdrawn from the account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_withdrawal_limit(mut self, x: f32) -> Self {
+    pub fn with_withdrawal_limit(mut self, x: f64) -> Self {
         self.withdrawal_limit = Some(x);
         self
     }
@@ -4627,9 +4708,9 @@ impl Account {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -4644,9 +4725,9 @@ impl Account {
     }
 
     /// ID of the user that created the Account.
-    /// - param i32
+    /// - param i64
     /// - return Account
-    pub fn with_created_by_user_id(mut self, x: i32) -> Self {
+    pub fn with_created_by_user_id(mut self, x: i64) -> Self {
         self.created_by_user_id = Some(x);
         self
     }
@@ -4654,9 +4735,9 @@ impl Account {
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Account
-    pub fn with_margin_closeout_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_unrealized_pl(mut self, x: f64) -> Self {
         self.margin_closeout_unrealized_pl = Some(x);
         self
     }
@@ -4698,7 +4779,7 @@ impl Account {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeOpen {
     /// The ID of the Trade that was opened
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -4718,7 +4799,7 @@ pub struct TradeOpen {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fee: Option<f32>,
+    pub guaranteed_execution_fee: Option<f64>,
 
     /// The average price that the units were opened at.
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -4729,7 +4810,7 @@ pub struct TradeOpen {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The margin required at the time the Trade was created. Note, this is
     /// the 'pure' margin required, it is not the 'effective' margin used that
@@ -4742,7 +4823,7 @@ pub struct TradeOpen {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub initial_margin_required: Option<f32>,
+    pub initial_margin_required: Option<f64>,
 
     /// The number of units opened by the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -4753,7 +4834,7 @@ pub struct TradeOpen {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
@@ -4772,7 +4853,7 @@ pub struct TradeOpen {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub half_spread_cost: Option<f32>,
+    pub half_spread_cost: Option<f64>,
 }
 impl TradeOpen {
     pub fn new() -> TradeOpen {
@@ -4802,9 +4883,9 @@ impl TradeOpen {
     /// Stop Loss Order attached to it.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeOpen
-    pub fn with_guaranteed_execution_fee(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fee(mut self, x: f64) -> Self {
         self.guaranteed_execution_fee = Some(x);
         self
     }
@@ -4812,9 +4893,9 @@ impl TradeOpen {
     /// The average price that the units were opened at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TradeOpen
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -4824,9 +4905,9 @@ impl TradeOpen {
     /// factors in the trade risk if a GSLO is attached to the trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeOpen
-    pub fn with_initial_margin_required(mut self, x: f32) -> Self {
+    pub fn with_initial_margin_required(mut self, x: f64) -> Self {
         self.initial_margin_required = Some(x);
         self
     }
@@ -4834,9 +4915,9 @@ impl TradeOpen {
     /// The number of units opened by the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TradeOpen
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -4855,15 +4936,15 @@ impl TradeOpen {
     /// negative value and is represented in the home currency of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeOpen
-    pub fn with_half_spread_cost(mut self, x: f32) -> Self {
+    pub fn with_half_spread_cost(mut self, x: f64) -> Self {
         self.half_spread_cost = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MT4TransactionHeartbeat {
     /// The string "HEARTBEAT"
     #[serde(default)]
@@ -4918,7 +4999,7 @@ impl MT4TransactionHeartbeat {
 
 /// The reason that an Order was filled
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OrderFillReason {
     #[serde(rename = "LIMIT_ORDER")]
     LimitOrder,
@@ -4970,7 +5051,7 @@ impl std::fmt::Display for OrderFillReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderDelayedTradeClose {
     /// The Transaction ID of the DelayedTradeClosure transaction to which
     /// this Delayed Trade Close belongs to
@@ -5040,7 +5121,7 @@ impl MarketOrderDelayedTradeClose {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Candlestick {
     /// A flag indicating if the candlestick is complete. A complete
     /// candlestick is one whose ending time is not in the future.
@@ -5159,7 +5240,7 @@ impl Candlestick {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionSide {
     /// The total amount of financing paid/collected for this PositionSide
     /// over the lifetime of the Account.
@@ -5171,7 +5252,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The unrealized profit/loss of all open Trades that contribute to this
     /// PositionSide.
@@ -5183,7 +5264,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// List of the open Trade IDs which contribute to the open Position.
     #[serde(default)]
@@ -5200,7 +5281,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub resettable_pl: Option<f32>,
+    pub resettable_pl: Option<f64>,
 
     /// The total amount of fees charged over the lifetime of the Account for
     /// the execution of guaranteed Stop Loss Orders attached to Trades for
@@ -5213,7 +5294,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fees: Option<f32>,
+    pub guaranteed_execution_fees: Option<f64>,
 
     /// Number of units in the position (negative value indicates short
     /// position, positive indicates long position).
@@ -5225,7 +5306,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// Volume-weighted average of the underlying Trade open prices for the
     /// Position.
@@ -5237,7 +5318,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub average_price: Option<f32>,
+    pub average_price: Option<f64>,
 
     /// Profit/loss realized by the PositionSide over the lifetime of the
     /// Account.
@@ -5249,7 +5330,7 @@ pub struct PositionSide {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub pl: Option<f32>,
+    pub pl: Option<f64>,
 }
 impl PositionSide {
     pub fn new() -> PositionSide {
@@ -5269,9 +5350,9 @@ impl PositionSide {
     /// over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -5280,9 +5361,9 @@ impl PositionSide {
     /// PositionSide.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -5299,9 +5380,9 @@ impl PositionSide {
     /// resettablePL was last reset by the client.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_resettable_pl(mut self, x: f32) -> Self {
+    pub fn with_resettable_pl(mut self, x: f64) -> Self {
         self.resettable_pl = Some(x);
         self
     }
@@ -5311,9 +5392,9 @@ impl PositionSide {
     /// this PositionSide.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_guaranteed_execution_fees(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fees(mut self, x: f64) -> Self {
         self.guaranteed_execution_fees = Some(x);
         self
     }
@@ -5322,9 +5403,9 @@ impl PositionSide {
     /// position, positive indicates long position).
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -5333,9 +5414,9 @@ impl PositionSide {
     /// Position.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_average_price(mut self, x: f32) -> Self {
+    pub fn with_average_price(mut self, x: f64) -> Self {
         self.average_price = Some(x);
         self
     }
@@ -5344,15 +5425,15 @@ impl PositionSide {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return PositionSide
-    pub fn with_pl(mut self, x: f32) -> Self {
+    pub fn with_pl(mut self, x: f64) -> Self {
         self.pl = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     /// The total amount of financing paid/collected for this instrument over
     /// the lifetime of the Account.
@@ -5364,7 +5445,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The representation of a Position for a single direction (long or
     /// short).
@@ -5381,7 +5462,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// The unrealized profit/loss of all open Trades that contribute to this
     /// Position.
@@ -5393,7 +5474,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// The total amount of commission paid for this instrument over the
     /// lifetime of the Account.
@@ -5405,7 +5486,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub commission: Option<f32>,
+    pub commission: Option<f64>,
 
     /// The representation of a Position for a single direction (long or
     /// short).
@@ -5430,7 +5511,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub resettable_pl: Option<f32>,
+    pub resettable_pl: Option<f64>,
 
     /// The total amount of fees charged over the lifetime of the Account for
     /// the execution of guaranteed Stop Loss Orders for this instrument.
@@ -5442,7 +5523,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fees: Option<f32>,
+    pub guaranteed_execution_fees: Option<f64>,
 
     /// Profit/loss realized by the Position over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -5453,7 +5534,7 @@ pub struct Position {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub pl: Option<f32>,
+    pub pl: Option<f64>,
 }
 impl Position {
     pub fn new() -> Position {
@@ -5475,9 +5556,9 @@ impl Position {
     /// the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -5494,9 +5575,9 @@ impl Position {
     /// Margin currently used by the Position.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -5505,9 +5586,9 @@ impl Position {
     /// Position.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -5516,9 +5597,9 @@ impl Position {
     /// lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_commission(mut self, x: f32) -> Self {
+    pub fn with_commission(mut self, x: f64) -> Self {
         self.commission = Some(x);
         self
     }
@@ -5546,9 +5627,9 @@ impl Position {
     /// was last reset by the client.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_resettable_pl(mut self, x: f32) -> Self {
+    pub fn with_resettable_pl(mut self, x: f64) -> Self {
         self.resettable_pl = Some(x);
         self
     }
@@ -5557,9 +5638,9 @@ impl Position {
     /// the execution of guaranteed Stop Loss Orders for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_guaranteed_execution_fees(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fees(mut self, x: f64) -> Self {
         self.guaranteed_execution_fees = Some(x);
         self
     }
@@ -5567,9 +5648,9 @@ impl Position {
     /// Profit/loss realized by the Position over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Position
-    pub fn with_pl(mut self, x: f32) -> Self {
+    pub fn with_pl(mut self, x: f64) -> Self {
         self.pl = Some(x);
         self
     }
@@ -5577,7 +5658,7 @@ impl Position {
 
 /// A filter that can be used when fetching Transactions
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionFilter {
     #[serde(rename = "ORDER")]
     Order,
@@ -5722,7 +5803,7 @@ impl std::fmt::Display for TransactionFilter {
 
 /// The status of the Price.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PriceStatus {
     #[serde(rename = "tradeable")]
     Tradeable,
@@ -5750,7 +5831,7 @@ impl std::fmt::Display for PriceStatus {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DynamicOrderState {
     /// The distance between the Trailing Stop Loss Order's trailingStopValue
     /// and the current Market Price. This represents the distance (in price
@@ -5764,7 +5845,7 @@ pub struct DynamicOrderState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub trigger_distance: Option<f32>,
+    pub trigger_distance: Option<f64>,
 
     /// True if an exact trigger distance could be calculated. If false, it
     /// means the provided trigger distance is a best estimate. If the
@@ -5793,7 +5874,7 @@ pub struct DynamicOrderState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub trailing_stop_value: Option<f32>,
+    pub trailing_stop_value: Option<f64>,
 }
 impl DynamicOrderState {
     pub fn new() -> DynamicOrderState {
@@ -5811,9 +5892,9 @@ impl DynamicOrderState {
     /// be determined, this value will not be set.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return DynamicOrderState
-    pub fn with_trigger_distance(mut self, x: f32) -> Self {
+    pub fn with_trigger_distance(mut self, x: f64) -> Self {
         self.trigger_distance = Some(x);
         self
     }
@@ -5842,20 +5923,24 @@ impl DynamicOrderState {
     /// The Order's calculated trailing stop value.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return DynamicOrderState
-    pub fn with_trailing_stop_value(mut self, x: f32) -> Self {
+    pub fn with_trailing_stop_value(mut self, x: f64) -> Self {
         self.trailing_stop_value = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResetResettablePLTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -5917,9 +6002,9 @@ impl ResetResettablePLTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return ResetResettablePLTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -5985,7 +6070,7 @@ impl ResetResettablePLTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DailyFinancingTransaction {
     /// The amount of financing paid/collected for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -5996,7 +6081,7 @@ pub struct DailyFinancingTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The financing paid/collected for each Position in the Account.
     #[serde(default)]
@@ -6005,8 +6090,12 @@ pub struct DailyFinancingTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -6024,7 +6113,7 @@ pub struct DailyFinancingTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub account_balance: Option<f32>,
+    pub account_balance: Option<f64>,
 
     /// The Request ID of the request which generated the transaction.
     #[serde(default)]
@@ -6093,9 +6182,9 @@ impl DailyFinancingTransaction {
     /// The amount of financing paid/collected for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return DailyFinancingTransaction
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -6109,9 +6198,9 @@ impl DailyFinancingTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return DailyFinancingTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -6129,9 +6218,9 @@ impl DailyFinancingTransaction {
     /// The Account's balance after daily financing.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return DailyFinancingTransaction
-    pub fn with_account_balance(mut self, x: f32) -> Self {
+    pub fn with_account_balance(mut self, x: f64) -> Self {
         self.account_balance = Some(x);
         self
     }
@@ -6195,7 +6284,7 @@ impl DailyFinancingTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderLevelRestriction {
     /// Applies to Trades with a guaranteed Stop Loss Order attached for the
     /// specified Instrument. This is the total allowed Trade volume that can
@@ -6209,7 +6298,7 @@ pub struct GuaranteedStopLossOrderLevelRestriction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub volume: Option<f32>,
+    pub volume: Option<f64>,
 
     /// The price range the volume applies to. This value is in price units.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -6220,7 +6309,7 @@ pub struct GuaranteedStopLossOrderLevelRestriction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_range: Option<f32>,
+    pub price_range: Option<f64>,
 }
 impl GuaranteedStopLossOrderLevelRestriction {
     pub fn new() -> GuaranteedStopLossOrderLevelRestriction {
@@ -6236,9 +6325,9 @@ impl GuaranteedStopLossOrderLevelRestriction {
     /// guaranteed Stop Loss Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return GuaranteedStopLossOrderLevelRestriction
-    pub fn with_volume(mut self, x: f32) -> Self {
+    pub fn with_volume(mut self, x: f64) -> Self {
         self.volume = Some(x);
         self
     }
@@ -6246,15 +6335,15 @@ impl GuaranteedStopLossOrderLevelRestriction {
     /// The price range the volume applies to. This value is in price units.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return GuaranteedStopLossOrderLevelRestriction
-    pub fn with_price_range(mut self, x: f32) -> Self {
+    pub fn with_price_range(mut self, x: f64) -> Self {
         self.price_range = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderClientExtensionsModifyRejectTransaction {
     /// The ID of the Order who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -6272,8 +6361,12 @@ pub struct OrderClientExtensionsModifyRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -6386,9 +6479,9 @@ impl OrderClientExtensionsModifyRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return OrderClientExtensionsModifyRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -6483,7 +6576,7 @@ impl OrderClientExtensionsModifyRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HomeConversions {
     /// The currency to be converted into the home currency.
     /// format: A string containing an ISO 4217 currency
@@ -6504,7 +6597,7 @@ pub struct HomeConversions {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub account_gain: Option<f32>,
+    pub account_gain: Option<f64>,
 
     /// The factor used to convert a Position or Trade Value in the specified
     /// currency into the Account's home currency. Conversion is performed by
@@ -6517,7 +6610,7 @@ pub struct HomeConversions {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub position_value: Option<f32>,
+    pub position_value: Option<f64>,
 
     /// The string representation of a decimal number.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -6528,7 +6621,7 @@ pub struct HomeConversions {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub account_loss: Option<f32>,
+    pub account_loss: Option<f64>,
 }
 impl HomeConversions {
     pub fn new() -> HomeConversions {
@@ -6556,9 +6649,9 @@ impl HomeConversions {
     /// by multiplying the positive P/L by the conversion factor.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return HomeConversions
-    pub fn with_account_gain(mut self, x: f32) -> Self {
+    pub fn with_account_gain(mut self, x: f64) -> Self {
         self.account_gain = Some(x);
         self
     }
@@ -6568,9 +6661,9 @@ impl HomeConversions {
     /// multiplying the Position or Trade Value by the conversion factor.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return HomeConversions
-    pub fn with_position_value(mut self, x: f32) -> Self {
+    pub fn with_position_value(mut self, x: f64) -> Self {
         self.position_value = Some(x);
         self
     }
@@ -6578,15 +6671,15 @@ impl HomeConversions {
     /// The string representation of a decimal number.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return HomeConversions
-    pub fn with_account_loss(mut self, x: f32) -> Self {
+    pub fn with_account_loss(mut self, x: f64) -> Self {
         self.account_loss = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderMarginCloseout {
     /// The reason the Market Order was created to perform a margin closeout
     #[serde(default)]
@@ -6607,7 +6700,7 @@ impl MarketOrderMarginCloseout {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TakeProfitOrderTransaction {
     /// The time-in-force requested for the TakeProfit Order. Restricted to
     /// "GTC", "GFD" and "GTD" for TakeProfit Orders.
@@ -6665,7 +6758,7 @@ pub struct TakeProfitOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The client ID of the Trade to be closed when the price threshold is
     /// breached.
@@ -6675,8 +6768,12 @@ pub struct TakeProfitOrderTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -6826,8 +6923,8 @@ impl TakeProfitOrderTransaction {
     /// valid.
     /// - param String
     /// - return TakeProfitOrderTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -6859,9 +6956,9 @@ impl TakeProfitOrderTransaction {
     /// this threshold.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TakeProfitOrderTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -6876,9 +6973,9 @@ impl TakeProfitOrderTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TakeProfitOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -6998,7 +7095,7 @@ impl TakeProfitOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderCancelTransaction {
     /// The ID of the Order cancelled
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -7028,8 +7125,12 @@ pub struct OrderCancelTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -7135,9 +7236,9 @@ impl OrderCancelTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return OrderCancelTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -7211,7 +7312,7 @@ impl OrderCancelTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountSummary {
     /// The date/time that the Account's resettablePL was last reset.
     /// format: The RFC 3339 representation is a string conforming to
@@ -7237,7 +7338,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// The value of the Account's open positions as used for margin closeout
     /// calculations represented in the Account's home currency.
@@ -7249,7 +7350,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_position_value: Option<f32>,
+    pub margin_closeout_position_value: Option<f64>,
 
     /// The number of times that the Account's current margin call was
     /// extended.
@@ -7277,7 +7378,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub resettable_pl: Option<f32>,
+    pub resettable_pl: Option<f64>,
 
     /// The net asset value of the Account. Equal to Account balance +
     /// unrealizedPL.
@@ -7289,7 +7390,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub nav: Option<f32>,
+    pub nav: Option<f64>,
 
     /// The date/time of the Account's last margin call extension.
     /// format: The RFC 3339 representation is a string conforming to
@@ -7315,7 +7416,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_margin_used: Option<f32>,
+    pub margin_closeout_margin_used: Option<f64>,
 
     /// The number of Trades currently open in the Account.
     #[serde(default)]
@@ -7343,7 +7444,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_nav: Option<f32>,
+    pub margin_closeout_nav: Option<f64>,
 
     /// The Account's margin closeout percentage. When this value is 1.0 or
     /// above the Account is in a margin closeout situation.
@@ -7355,7 +7456,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_percent: Option<f32>,
+    pub margin_closeout_percent: Option<f64>,
 
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -7366,7 +7467,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_margin_used: Option<f32>,
+    pub margin_call_margin_used: Option<f64>,
 
     /// The total amount of commission paid over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -7377,7 +7478,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub commission: Option<f32>,
+    pub commission: Option<f64>,
 
     /// Flag indicating that the Account has hedging enabled.
     #[serde(default)]
@@ -7394,7 +7495,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub position_value: Option<f32>,
+    pub position_value: Option<f64>,
 
     /// The total profit/loss realized over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -7405,7 +7506,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub pl: Option<f32>,
+    pub pl: Option<f64>,
 
     /// The current guaranteed Stop Loss Order mode of the Account.
     #[serde(default)]
@@ -7430,7 +7531,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_available: Option<f32>,
+    pub margin_available: Option<f64>,
 
     /// Client-provided margin rate override for the Account. The effective
     /// margin rate of the Account is the lesser of this value and the OANDA
@@ -7444,7 +7545,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_rate: Option<f32>,
+    pub margin_rate: Option<f64>,
 
     /// The Account's margin call percentage. When this value is 1.0 or above
     /// the Account is in a margin call situation.
@@ -7456,7 +7557,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_percent: Option<f32>,
+    pub margin_call_percent: Option<f64>,
 
     /// The date/time when the Account entered a margin call state. Only
     /// provided if the Account is in a margin call.
@@ -7484,7 +7585,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fees: Option<f32>,
+    pub guaranteed_execution_fees: Option<f64>,
 
     /// The total amount of financing paid/collected over the lifetime of the
     /// Account.
@@ -7496,7 +7597,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The current balance of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -7507,7 +7608,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub balance: Option<f32>,
+    pub balance: Option<f64>,
 
     /// The number of Orders currently pending in the Account.
     #[serde(default)]
@@ -7524,7 +7625,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub withdrawal_limit: Option<f32>,
+    pub withdrawal_limit: Option<f64>,
 
     /// The total unrealized profit/loss for all Trades currently open in the
     /// Account.
@@ -7536,7 +7637,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// Client-assigned alias for the Account. Only provided if the Account
     /// has an alias set
@@ -7546,8 +7647,12 @@ pub struct AccountSummary {
 
     /// ID of the user that created the Account.
     #[serde(default)]
-    #[serde(rename = "createdByUserID", skip_serializing_if = "Option::is_none")]
-    pub created_by_user_id: Option<i32>,
+    #[serde(
+        rename = "createdByUserID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub created_by_user_id: Option<i64>,
 
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -7558,7 +7663,7 @@ pub struct AccountSummary {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_unrealized_pl: Option<f32>,
+    pub margin_closeout_unrealized_pl: Option<f64>,
 
     /// The date/time when the Account was created.
     /// format: The RFC 3339 representation is a string conforming to
@@ -7649,9 +7754,9 @@ impl AccountSummary {
     /// Margin currently used for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -7660,9 +7765,9 @@ impl AccountSummary {
     /// calculations represented in the Account's home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_closeout_position_value(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_position_value(mut self, x: f64) -> Self {
         self.margin_closeout_position_value = Some(x);
         self
     }
@@ -7690,9 +7795,9 @@ impl AccountSummary {
     /// by the client.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_resettable_pl(mut self, x: f32) -> Self {
+    pub fn with_resettable_pl(mut self, x: f64) -> Self {
         self.resettable_pl = Some(x);
         self
     }
@@ -7701,9 +7806,9 @@ impl AccountSummary {
     /// unrealizedPL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_nav(mut self, x: f32) -> Self {
+    pub fn with_nav(mut self, x: f64) -> Self {
         self.nav = Some(x);
         self
     }
@@ -7725,9 +7830,9 @@ impl AccountSummary {
     /// The Account's margin closeout margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_closeout_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_margin_used(mut self, x: f64) -> Self {
         self.margin_closeout_margin_used = Some(x);
         self
     }
@@ -7761,9 +7866,9 @@ impl AccountSummary {
     /// The Account's margin closeout NAV.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_closeout_nav(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_nav(mut self, x: f64) -> Self {
         self.margin_closeout_nav = Some(x);
         self
     }
@@ -7772,9 +7877,9 @@ impl AccountSummary {
     /// above the Account is in a margin closeout situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_closeout_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_percent(mut self, x: f64) -> Self {
         self.margin_closeout_percent = Some(x);
         self
     }
@@ -7782,9 +7887,9 @@ impl AccountSummary {
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_call_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_call_margin_used(mut self, x: f64) -> Self {
         self.margin_call_margin_used = Some(x);
         self
     }
@@ -7792,9 +7897,9 @@ impl AccountSummary {
     /// The total amount of commission paid over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_commission(mut self, x: f32) -> Self {
+    pub fn with_commission(mut self, x: f64) -> Self {
         self.commission = Some(x);
         self
     }
@@ -7811,9 +7916,9 @@ impl AccountSummary {
     /// home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_position_value(mut self, x: f32) -> Self {
+    pub fn with_position_value(mut self, x: f64) -> Self {
         self.position_value = Some(x);
         self
     }
@@ -7821,9 +7926,9 @@ impl AccountSummary {
     /// The total profit/loss realized over the lifetime of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_pl(mut self, x: f32) -> Self {
+    pub fn with_pl(mut self, x: f64) -> Self {
         self.pl = Some(x);
         self
     }
@@ -7848,9 +7953,9 @@ impl AccountSummary {
     /// Margin available for Account currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_available(mut self, x: f32) -> Self {
+    pub fn with_margin_available(mut self, x: f64) -> Self {
         self.margin_available = Some(x);
         self
     }
@@ -7861,9 +7966,9 @@ impl AccountSummary {
     /// a margin rate override exists for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_rate(mut self, x: f32) -> Self {
+    pub fn with_margin_rate(mut self, x: f64) -> Self {
         self.margin_rate = Some(x);
         self
     }
@@ -7872,9 +7977,9 @@ impl AccountSummary {
     /// the Account is in a margin call situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_call_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_call_percent(mut self, x: f64) -> Self {
         self.margin_call_percent = Some(x);
         self
     }
@@ -7898,9 +8003,9 @@ impl AccountSummary {
     /// the execution of guaranteed Stop Loss Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_guaranteed_execution_fees(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fees(mut self, x: f64) -> Self {
         self.guaranteed_execution_fees = Some(x);
         self
     }
@@ -7909,9 +8014,9 @@ impl AccountSummary {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -7919,9 +8024,9 @@ impl AccountSummary {
     /// The current balance of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_balance(mut self, x: f32) -> Self {
+    pub fn with_balance(mut self, x: f64) -> Self {
         self.balance = Some(x);
         self
     }
@@ -7938,9 +8043,9 @@ impl AccountSummary {
     /// positive value indicating how much can be withdrawn from the account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_withdrawal_limit(mut self, x: f32) -> Self {
+    pub fn with_withdrawal_limit(mut self, x: f64) -> Self {
         self.withdrawal_limit = Some(x);
         self
     }
@@ -7949,9 +8054,9 @@ impl AccountSummary {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -7966,9 +8071,9 @@ impl AccountSummary {
     }
 
     /// ID of the user that created the Account.
-    /// - param i32
+    /// - param i64
     /// - return AccountSummary
-    pub fn with_created_by_user_id(mut self, x: i32) -> Self {
+    pub fn with_created_by_user_id(mut self, x: i64) -> Self {
         self.created_by_user_id = Some(x);
         self
     }
@@ -7976,9 +8081,9 @@ impl AccountSummary {
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return AccountSummary
-    pub fn with_margin_closeout_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_unrealized_pl(mut self, x: f64) -> Self {
         self.margin_closeout_unrealized_pl = Some(x);
         self
     }
@@ -8012,7 +8117,7 @@ impl AccountSummary {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -8050,7 +8155,7 @@ pub struct MarketIfTouchedOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The worst market price that may be used to fill this MarketIfTouched
     /// Order.
@@ -8062,7 +8167,7 @@ pub struct MarketIfTouchedOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// TakeProfitDetails specifies the details of a Take Profit Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -8125,7 +8230,7 @@ pub struct MarketIfTouchedOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -8203,8 +8308,8 @@ impl MarketIfTouchedOrderRequest {
     /// valid.
     /// - param String
     /// - return MarketIfTouchedOrderRequest
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -8216,9 +8321,9 @@ impl MarketIfTouchedOrderRequest {
     /// MarketIfTouchedOrder will behave like a Limit or a Stop Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderRequest
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -8227,9 +8332,9 @@ impl MarketIfTouchedOrderRequest {
     /// Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderRequest
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
@@ -8254,6 +8359,14 @@ impl MarketIfTouchedOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return MarketIfTouchedOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The MarketIfTouched Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
     /// a "_".
@@ -8301,9 +8414,9 @@ impl MarketIfTouchedOrderRequest {
     /// number of units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderRequest
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -8343,17 +8456,25 @@ impl MarketIfTouchedOrderRequest {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return MarketIfTouchedOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// Specification of how Positions in the Account are modified when the
     /// Order is filled.
     /// - param String
     /// - return MarketIfTouchedOrderRequest
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountChanges {
     /// The Trades reduced.
     #[serde(default)]
@@ -8490,7 +8611,7 @@ impl AccountChanges {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TakeProfitOrderRejectTransaction {
     /// The time-in-force requested for the TakeProfit Order. Restricted to
     /// "GTC", "GFD" and "GTD" for TakeProfit Orders.
@@ -8551,7 +8672,7 @@ pub struct TakeProfitOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The client ID of the Trade to be closed when the price threshold is
     /// breached.
@@ -8561,8 +8682,12 @@ pub struct TakeProfitOrderRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -8707,8 +8832,8 @@ impl TakeProfitOrderRejectTransaction {
     /// valid.
     /// - param String
     /// - return TakeProfitOrderRejectTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -8740,9 +8865,9 @@ impl TakeProfitOrderRejectTransaction {
     /// this threshold.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TakeProfitOrderRejectTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -8757,9 +8882,9 @@ impl TakeProfitOrderRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TakeProfitOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -8877,7 +9002,7 @@ impl TakeProfitOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TakeProfitOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -8920,7 +9045,7 @@ pub struct TakeProfitOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The time-in-force requested for the TakeProfit Order. Restricted to
     /// "GTC", "GFD" and "GTD" for TakeProfit Orders.
@@ -8997,8 +9122,8 @@ impl TakeProfitOrderRequest {
     /// valid.
     /// - param String
     /// - return TakeProfitOrderRequest
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -9018,9 +9143,9 @@ impl TakeProfitOrderRequest {
     /// this threshold.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TakeProfitOrderRequest
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -9034,6 +9159,14 @@ impl TakeProfitOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return TakeProfitOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
     /// modify, or delete this field if your account is associated with MT4.
@@ -9076,19 +9209,35 @@ impl TakeProfitOrderRequest {
         self.otype = Some(x);
         self
     }
+
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return TakeProfitOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTransaction {
     /// The ID of the user that the Account was created for
     #[serde(default)]
-    #[serde(rename = "accountUserID", skip_serializing_if = "Option::is_none")]
-    pub account_user_id: Option<i32>,
+    #[serde(
+        rename = "accountUserID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub account_user_id: Option<i64>,
 
     /// The number of the Account within the site/division/user
     #[serde(default)]
-    #[serde(rename = "accountNumber", skip_serializing_if = "Option::is_none")]
-    pub account_number: Option<i32>,
+    #[serde(
+        rename = "accountNumber",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub account_number: Option<i64>,
 
     /// The home currency of the Account
     /// format: A string containing an ISO 4217 currency
@@ -9099,8 +9248,12 @@ pub struct CreateTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -9111,8 +9264,12 @@ pub struct CreateTransaction {
 
     /// The ID of the Site that the Account was created at
     #[serde(default)]
-    #[serde(rename = "siteID", skip_serializing_if = "Option::is_none")]
-    pub site_id: Option<i32>,
+    #[serde(
+        rename = "siteID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub site_id: Option<i64>,
 
     /// The Request ID of the request which generated the transaction.
     #[serde(default)]
@@ -9136,8 +9293,12 @@ pub struct CreateTransaction {
 
     /// The ID of the Division that the Account is in
     #[serde(default)]
-    #[serde(rename = "divisionID", skip_serializing_if = "Option::is_none")]
-    pub division_id: Option<i32>,
+    #[serde(
+        rename = "divisionID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub division_id: Option<i64>,
 
     /// The Type of the Transaction. Always set to "CREATE" in a
     /// CreateTransaction.
@@ -9177,17 +9338,17 @@ impl CreateTransaction {
     }
 
     /// The ID of the user that the Account was created for
-    /// - param i32
+    /// - param i64
     /// - return CreateTransaction
-    pub fn with_account_user_id(mut self, x: i32) -> Self {
+    pub fn with_account_user_id(mut self, x: i64) -> Self {
         self.account_user_id = Some(x);
         self
     }
 
     /// The number of the Account within the site/division/user
-    /// - param i32
+    /// - param i64
     /// - return CreateTransaction
-    pub fn with_account_number(mut self, x: i32) -> Self {
+    pub fn with_account_number(mut self, x: i64) -> Self {
         self.account_number = Some(x);
         self
     }
@@ -9203,9 +9364,9 @@ impl CreateTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return CreateTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -9221,9 +9382,9 @@ impl CreateTransaction {
     }
 
     /// The ID of the Site that the Account was created at
-    /// - param i32
+    /// - param i64
     /// - return CreateTransaction
-    pub fn with_site_id(mut self, x: i32) -> Self {
+    pub fn with_site_id(mut self, x: i64) -> Self {
         self.site_id = Some(x);
         self
     }
@@ -9251,9 +9412,9 @@ impl CreateTransaction {
     }
 
     /// The ID of the Division that the Account is in
-    /// - param i32
+    /// - param i64
     /// - return CreateTransaction
-    pub fn with_division_id(mut self, x: i32) -> Self {
+    pub fn with_division_id(mut self, x: i64) -> Self {
         self.division_id = Some(x);
         self
     }
@@ -9287,7 +9448,7 @@ impl CreateTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopLossDetails {
     /// Specifies the distance (in price units) from the Trade's open price to
     /// use as the Stop Loss Order price. Only one of the distance and price
@@ -9300,7 +9461,7 @@ pub struct StopLossDetails {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
@@ -9319,7 +9480,7 @@ pub struct StopLossDetails {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// Flag indicating that the price for the Stop Loss Order is guaranteed.
     /// The default value depends on the GuaranteedStopLossOrderMode of the
@@ -9368,9 +9529,9 @@ impl StopLossDetails {
     /// fields may be specified.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossDetails
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -9389,9 +9550,9 @@ impl StopLossDetails {
     /// the price and distance fields may be specified.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopLossDetails
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -9432,12 +9593,16 @@ impl StopLossDetails {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarginCallExitTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -9499,9 +9664,9 @@ impl MarginCallExitTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarginCallExitTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -9569,7 +9734,7 @@ impl MarginCallExitTransaction {
 
 /// The reason that the Take Profit Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TakeProfitOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -9600,7 +9765,7 @@ impl std::fmt::Display for TakeProfitOrderReason {
 /// The overall behaviour of the Account regarding guaranteed Stop Loss
 /// Orders.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GuaranteedStopLossOrderMode {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -9630,7 +9795,7 @@ impl std::fmt::Display for GuaranteedStopLossOrderMode {
 
 /// The state to filter the requested Orders by.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OrderStateFilter {
     #[serde(rename = "PENDING")]
     Pending,
@@ -9664,7 +9829,7 @@ impl std::fmt::Display for OrderStateFilter {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderTransaction {
     /// A MarketOrderPositionCloseout specifies the extensions to a Market
     /// Order when it has been created to closeout a specific Position.
@@ -9717,12 +9882,16 @@ pub struct MarketOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The Market Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -9742,7 +9911,7 @@ pub struct MarketOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "MARKET_ORDER" in a
     /// MarketOrderTransaction.
@@ -9927,8 +10096,8 @@ impl MarketOrderTransaction {
     /// Order is filled.
     /// - param String
     /// - return MarketOrderTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -9936,17 +10105,17 @@ impl MarketOrderTransaction {
     /// filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrderTransaction
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarketOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -9966,9 +10135,9 @@ impl MarketOrderTransaction {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrderTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -10109,7 +10278,7 @@ impl MarketOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderRequest {}
 impl OrderRequest {
     pub fn new() -> OrderRequest {
@@ -10119,7 +10288,7 @@ impl OrderRequest {
 
 /// The reason that the Limit Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LimitOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -10146,7 +10315,7 @@ impl std::fmt::Display for LimitOrderReason {
 
 /// The current state of the Trade.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TradeState {
     #[serde(rename = "OPEN")]
     Open,
@@ -10177,7 +10346,7 @@ impl std::fmt::Display for TradeState {
 /// The reason that the Market Order was created to perform a margin
 /// closeout
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MarketOrderMarginCloseoutReason {
     #[serde(rename = "MARGIN_CHECK_VIOLATION")]
     MarginCheckViolation,
@@ -10209,12 +10378,16 @@ impl std::fmt::Display for MarketOrderMarginCloseoutReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -10255,6 +10428,11 @@ pub struct Transaction {
     #[serde(default)]
     #[serde(rename = "accountID", skip_serializing_if = "Option::is_none")]
     pub account_id: Option<String>,
+
+    /// The Type of the Transaction, such as "ORDER_FILL" or "DAILY_FINANCING".
+    #[serde(default)]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub otype: Option<String>,
 }
 impl Transaction {
     pub fn new() -> Transaction {
@@ -10265,13 +10443,14 @@ impl Transaction {
             time: None,
             id: None,
             account_id: None,
+            otype: None,
         }
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return Transaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -10326,9 +10505,17 @@ impl Transaction {
         self.account_id = Some(x);
         self
     }
+
+    /// The Type of the Transaction, such as "ORDER_FILL" or "DAILY_FINANCING".
+    /// - param String
+    /// - return Transaction
+    pub fn with_otype(mut self, x: String) -> Self {
+        self.otype = Some(x);
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuoteHomeConversionFactors {
     /// The factor used to convert a negative amount of the Price's
     /// Instrument's quote currency into a negative amount of the Account's
@@ -10342,7 +10529,7 @@ pub struct QuoteHomeConversionFactors {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub negative_units: Option<f32>,
+    pub negative_units: Option<f64>,
 
     /// The factor used to convert a positive amount of the Price's
     /// Instrument's quote currency into a positive amount of the Account's
@@ -10356,7 +10543,7 @@ pub struct QuoteHomeConversionFactors {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub positive_units: Option<f32>,
+    pub positive_units: Option<f64>,
 }
 impl QuoteHomeConversionFactors {
     pub fn new() -> QuoteHomeConversionFactors {
@@ -10372,9 +10559,9 @@ impl QuoteHomeConversionFactors {
     /// by the conversion factor.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return QuoteHomeConversionFactors
-    pub fn with_negative_units(mut self, x: f32) -> Self {
+    pub fn with_negative_units(mut self, x: f64) -> Self {
         self.negative_units = Some(x);
         self
     }
@@ -10385,15 +10572,15 @@ impl QuoteHomeConversionFactors {
     /// by the conversion factor.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return QuoteHomeConversionFactors
-    pub fn with_positive_units(mut self, x: f32) -> Self {
+    pub fn with_positive_units(mut self, x: f64) -> Self {
         self.positive_units = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBookBucket {
     /// The lowest price (inclusive) covered by the bucket. The bucket covers
     /// the price range from the price to price + the order book's
@@ -10406,7 +10593,7 @@ pub struct OrderBookBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The percentage of the total number of orders represented by the short
     /// orders found in this bucket.
@@ -10418,7 +10605,7 @@ pub struct OrderBookBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub short_count_percent: Option<f32>,
+    pub short_count_percent: Option<f64>,
 
     /// The percentage of the total number of orders represented by the long
     /// orders found in this bucket.
@@ -10430,7 +10617,7 @@ pub struct OrderBookBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub long_count_percent: Option<f32>,
+    pub long_count_percent: Option<f64>,
 }
 impl OrderBookBucket {
     pub fn new() -> OrderBookBucket {
@@ -10446,9 +10633,9 @@ impl OrderBookBucket {
     /// bucketWidth.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return OrderBookBucket
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -10457,9 +10644,9 @@ impl OrderBookBucket {
     /// orders found in this bucket.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return OrderBookBucket
-    pub fn with_short_count_percent(mut self, x: f32) -> Self {
+    pub fn with_short_count_percent(mut self, x: f64) -> Self {
         self.short_count_percent = Some(x);
         self
     }
@@ -10468,9 +10655,9 @@ impl OrderBookBucket {
     /// orders found in this bucket.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return OrderBookBucket
-    pub fn with_long_count_percent(mut self, x: f32) -> Self {
+    pub fn with_long_count_percent(mut self, x: f64) -> Self {
         self.long_count_percent = Some(x);
         self
     }
@@ -10478,7 +10665,7 @@ impl OrderBookBucket {
 
 /// The reason that the Fixed Price Order was created
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FixedPriceOrderReason {
     #[serde(rename = "PLATFORM_ACCOUNT_MIGRATION")]
     PlatformAccountMigration,
@@ -10500,7 +10687,7 @@ impl std::fmt::Display for FixedPriceOrderReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferFundsTransaction {
     /// An optional comment that may be attached to a fund transfer for audit
     /// purposes
@@ -10515,8 +10702,12 @@ pub struct TransferFundsTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -10534,7 +10725,7 @@ pub struct TransferFundsTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub account_balance: Option<f32>,
+    pub account_balance: Option<f64>,
 
     /// The amount to deposit/withdraw from the Account in the Account's home
     /// currency. A positive value indicates a deposit, a negative value
@@ -10547,7 +10738,7 @@ pub struct TransferFundsTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub amount: Option<f32>,
+    pub amount: Option<f64>,
 
     /// The Request ID of the request which generated the transaction.
     #[serde(default)]
@@ -10623,9 +10814,9 @@ impl TransferFundsTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TransferFundsTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -10643,9 +10834,9 @@ impl TransferFundsTransaction {
     /// The Account's balance after funds are transferred.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TransferFundsTransaction
-    pub fn with_account_balance(mut self, x: f32) -> Self {
+    pub fn with_account_balance(mut self, x: f64) -> Self {
         self.account_balance = Some(x);
         self
     }
@@ -10655,9 +10846,9 @@ impl TransferFundsTransaction {
     /// indicates a withdrawal.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TransferFundsTransaction
-    pub fn with_amount(mut self, x: f32) -> Self {
+    pub fn with_amount(mut self, x: f64) -> Self {
         self.amount = Some(x);
         self
     }
@@ -10713,7 +10904,7 @@ impl TransferFundsTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuaranteedStopLossOrderEntryData {
     /// A GuaranteedStopLossOrderLevelRestriction represents the total
     /// position size that can exist within a given price window for Trades
@@ -10733,7 +10924,7 @@ pub struct GuaranteedStopLossOrderEntryData {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub minimum_distance: Option<f32>,
+    pub minimum_distance: Option<f64>,
 
     /// The amount that is charged to the account if a guaranteed Stop Loss
     /// Order is triggered and filled. The value is in price units and is
@@ -10746,7 +10937,7 @@ pub struct GuaranteedStopLossOrderEntryData {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub premium: Option<f32>,
+    pub premium: Option<f64>,
 }
 impl GuaranteedStopLossOrderEntryData {
     pub fn new() -> GuaranteedStopLossOrderEntryData {
@@ -10772,9 +10963,9 @@ impl GuaranteedStopLossOrderEntryData {
     /// instrument. Specified in price units.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return GuaranteedStopLossOrderEntryData
-    pub fn with_minimum_distance(mut self, x: f32) -> Self {
+    pub fn with_minimum_distance(mut self, x: f64) -> Self {
         self.minimum_distance = Some(x);
         self
     }
@@ -10784,15 +10975,15 @@ impl GuaranteedStopLossOrderEntryData {
     /// charged for each unit of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return GuaranteedStopLossOrderEntryData
-    pub fn with_premium(mut self, x: f32) -> Self {
+    pub fn with_premium(mut self, x: f64) -> Self {
         self.premium = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopLossOrderRequest {
     /// Specifies the distance (in price units) from the Account's current
     /// price to use as the Stop Loss Order price. If the Trade is short the
@@ -10805,7 +10996,7 @@ pub struct StopLossOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -10849,7 +11040,7 @@ pub struct StopLossOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// Flag indicating that the Stop Loss Order is guaranteed. The default
     /// value depends on the GuaranteedStopLossOrderMode of the account, if it
@@ -10921,9 +11112,9 @@ impl StopLossOrderRequest {
     /// Instrument's bid price is used, and for long Trades the ask is used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderRequest
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -10948,8 +11139,8 @@ impl StopLossOrderRequest {
     /// valid.
     /// - param String
     /// - return StopLossOrderRequest
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -10970,9 +11161,9 @@ impl StopLossOrderRequest {
     /// flag is true the associated Trade will be closed at this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderRequest
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -10997,6 +11188,14 @@ impl StopLossOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return StopLossOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
     /// modify, or delete this field if your account is associated with MT4.
@@ -11039,9 +11238,17 @@ impl StopLossOrderRequest {
         self.otype = Some(x);
         self
     }
+
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return StopLossOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBook {
     /// The partitioned order book, divided into buckets using a default
     /// bucket width. These buckets are only provided for price ranges which
@@ -11067,7 +11274,7 @@ pub struct OrderBook {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The price width for each bucket. Each bucket covers the price range
     /// from the bucket's price to the bucket's price + bucketWidth.
@@ -11079,7 +11286,7 @@ pub struct OrderBook {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub bucket_width: Option<f32>,
+    pub bucket_width: Option<f64>,
 
     /// The time when the order book snapshot was created.
     /// format: The RFC 3339 representation is a string conforming to
@@ -11131,9 +11338,9 @@ impl OrderBook {
     /// the order book snapshot
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return OrderBook
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -11142,9 +11349,9 @@ impl OrderBook {
     /// from the bucket's price to the bucket's price + bucketWidth.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return OrderBook
-    pub fn with_bucket_width(mut self, x: f32) -> Self {
+    pub fn with_bucket_width(mut self, x: f64) -> Self {
         self.bucket_width = Some(x);
         self
     }
@@ -11164,7 +11371,7 @@ impl OrderBook {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalculatedPositionState {
     /// The unrealized profit/loss of the Position's short open Trades
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11175,7 +11382,7 @@ pub struct CalculatedPositionState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub short_unrealized_pl: Option<f32>,
+    pub short_unrealized_pl: Option<f64>,
 
     /// The Position's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -11193,7 +11400,7 @@ pub struct CalculatedPositionState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// The Position's net unrealized profit/loss
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11204,7 +11411,7 @@ pub struct CalculatedPositionState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub net_unrealized_pl: Option<f32>,
+    pub net_unrealized_pl: Option<f64>,
 
     /// The unrealized profit/loss of the Position's long open Trades
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11215,7 +11422,7 @@ pub struct CalculatedPositionState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub long_unrealized_pl: Option<f32>,
+    pub long_unrealized_pl: Option<f64>,
 }
 impl CalculatedPositionState {
     pub fn new() -> CalculatedPositionState {
@@ -11231,9 +11438,9 @@ impl CalculatedPositionState {
     /// The unrealized profit/loss of the Position's short open Trades
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedPositionState
-    pub fn with_short_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_short_unrealized_pl(mut self, x: f64) -> Self {
         self.short_unrealized_pl = Some(x);
         self
     }
@@ -11251,9 +11458,9 @@ impl CalculatedPositionState {
     /// Margin currently used by the Position.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedPositionState
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -11261,9 +11468,9 @@ impl CalculatedPositionState {
     /// The Position's net unrealized profit/loss
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedPositionState
-    pub fn with_net_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_net_unrealized_pl(mut self, x: f64) -> Self {
         self.net_unrealized_pl = Some(x);
         self
     }
@@ -11271,9 +11478,9 @@ impl CalculatedPositionState {
     /// The unrealized profit/loss of the Position's long open Trades
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedPositionState
-    pub fn with_long_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_long_unrealized_pl(mut self, x: f64) -> Self {
         self.long_unrealized_pl = Some(x);
         self
     }
@@ -11281,7 +11488,7 @@ impl CalculatedPositionState {
 
 /// The type of an Instrument.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum InstrumentType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -11309,7 +11516,7 @@ impl std::fmt::Display for InstrumentType {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnitsAvailable {
     /// Representation of many units of an Instrument are available to be
     /// traded for both long and short Orders.
@@ -11382,7 +11589,7 @@ impl UnitsAvailable {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceBucket {
     /// The Price offered by the PriceBucket
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -11393,7 +11600,7 @@ pub struct PriceBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The amount of liquidity offered by the PriceBucket
     #[serde(default)]
@@ -11411,9 +11618,9 @@ impl PriceBucket {
     /// The Price offered by the PriceBucket
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return PriceBucket
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -11427,7 +11634,7 @@ impl PriceBucket {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
     /// The financing paid/collected for this Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11438,7 +11645,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The date/time when the Trade was opened.
     /// format: The RFC 3339 representation is a string conforming to
@@ -11472,7 +11679,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// The execution price of the Trade.
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -11483,7 +11690,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The unrealized profit/loss on the open portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11494,7 +11701,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// The total profit/loss realized on the closed portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -11505,7 +11712,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub realized_pl: Option<f32>,
+    pub realized_pl: Option<f64>,
 
     /// A TrailingStopLossOrder is an order that is linked to an open Trade
     /// and created with a price distance. The price distance is used to
@@ -11561,7 +11768,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub initial_margin_required: Option<f32>,
+    pub initial_margin_required: Option<f64>,
 
     /// The initial size of the Trade. Negative values indicate a short Trade,
     /// and positive values indicate a long Trade.
@@ -11573,7 +11780,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub initial_units: Option<f32>,
+    pub initial_units: Option<f64>,
 
     /// The average closing price of the Trade. Only present if the Trade has
     /// been closed or reduced at least once.
@@ -11585,7 +11792,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub average_close_price: Option<f32>,
+    pub average_close_price: Option<f64>,
 
     /// The number of units currently open for the Trade. This value is
     /// reduced to 0.0 as the Trade is closed.
@@ -11597,7 +11804,7 @@ pub struct Trade {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub current_units: Option<f32>,
+    pub current_units: Option<f64>,
 
     /// The IDs of the Transactions that have closed portions of this Trade.
     #[serde(default)]
@@ -11658,9 +11865,9 @@ impl Trade {
     /// The financing paid/collected for this Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -11693,9 +11900,9 @@ impl Trade {
     /// Margin currently used by the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -11703,9 +11910,9 @@ impl Trade {
     /// The execution price of the Trade.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -11713,9 +11920,9 @@ impl Trade {
     /// The unrealized profit/loss on the open portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -11723,9 +11930,9 @@ impl Trade {
     /// The total profit/loss realized on the closed portion of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_realized_pl(mut self, x: f32) -> Self {
+    pub fn with_realized_pl(mut self, x: f64) -> Self {
         self.realized_pl = Some(x);
         self
     }
@@ -11783,9 +11990,9 @@ impl Trade {
     /// factors in the trade risk if a GSLO is attached to the trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_initial_margin_required(mut self, x: f32) -> Self {
+    pub fn with_initial_margin_required(mut self, x: f64) -> Self {
         self.initial_margin_required = Some(x);
         self
     }
@@ -11794,9 +12001,9 @@ impl Trade {
     /// and positive values indicate a long Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_initial_units(mut self, x: f32) -> Self {
+    pub fn with_initial_units(mut self, x: f64) -> Self {
         self.initial_units = Some(x);
         self
     }
@@ -11805,9 +12012,9 @@ impl Trade {
     /// been closed or reduced at least once.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_average_close_price(mut self, x: f32) -> Self {
+    pub fn with_average_close_price(mut self, x: f64) -> Self {
         self.average_close_price = Some(x);
         self
     }
@@ -11816,9 +12023,9 @@ impl Trade {
     /// reduced to 0.0 as the Trade is closed.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Trade
-    pub fn with_current_units(mut self, x: f32) -> Self {
+    pub fn with_current_units(mut self, x: f64) -> Self {
         self.current_units = Some(x);
         self
     }
@@ -11864,7 +12071,7 @@ impl Trade {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CandlestickData {
     /// The highest price in the time-range represented by the candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -11875,7 +12082,7 @@ pub struct CandlestickData {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub h: Option<f32>,
+    pub h: Option<f64>,
 
     /// The last (closing) price in the time-range represented by the
     /// candlestick.
@@ -11887,7 +12094,7 @@ pub struct CandlestickData {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub c: Option<f32>,
+    pub c: Option<f64>,
 
     /// The lowest price in the time-range represented by the candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
@@ -11898,7 +12105,7 @@ pub struct CandlestickData {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub l: Option<f32>,
+    pub l: Option<f64>,
 
     /// The first (open) price in the time-range represented by the
     /// candlestick.
@@ -11910,7 +12117,7 @@ pub struct CandlestickData {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub o: Option<f32>,
+    pub o: Option<f64>,
 }
 impl CandlestickData {
     pub fn new() -> CandlestickData {
@@ -11925,9 +12132,9 @@ impl CandlestickData {
     /// The highest price in the time-range represented by the candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return CandlestickData
-    pub fn with_h(mut self, x: f32) -> Self {
+    pub fn with_h(mut self, x: f64) -> Self {
         self.h = Some(x);
         self
     }
@@ -11936,9 +12143,9 @@ impl CandlestickData {
     /// candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return CandlestickData
-    pub fn with_c(mut self, x: f32) -> Self {
+    pub fn with_c(mut self, x: f64) -> Self {
         self.c = Some(x);
         self
     }
@@ -11946,9 +12153,9 @@ impl CandlestickData {
     /// The lowest price in the time-range represented by the candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return CandlestickData
-    pub fn with_l(mut self, x: f32) -> Self {
+    pub fn with_l(mut self, x: f64) -> Self {
         self.l = Some(x);
         self
     }
@@ -11957,15 +12164,15 @@ impl CandlestickData {
     /// candlestick.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return CandlestickData
-    pub fn with_o(mut self, x: f32) -> Self {
+    pub fn with_o(mut self, x: f64) -> Self {
         self.o = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixedPriceOrderTransaction {
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
@@ -11996,7 +12203,7 @@ pub struct FixedPriceOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -12008,8 +12215,12 @@ pub struct FixedPriceOrderTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// TakeProfitDetails specifies the details of a Take Profit Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -12085,7 +12296,7 @@ pub struct FixedPriceOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The state that the trade resulting from the Fixed Price Order should
     /// be set to.
@@ -12162,9 +12373,9 @@ impl FixedPriceOrderTransaction {
     /// price that the Fixed Price Order will be filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return FixedPriceOrderTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -12181,9 +12392,9 @@ impl FixedPriceOrderTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return FixedPriceOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -12231,8 +12442,8 @@ impl FixedPriceOrderTransaction {
     /// Order is filled.
     /// - param String
     /// - return FixedPriceOrderTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -12273,9 +12484,9 @@ impl FixedPriceOrderTransaction {
     /// number of units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return FixedPriceOrderTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -12318,7 +12529,7 @@ impl FixedPriceOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarginCallExtendTransaction {
     /// The number of the extensions to the Account's current margin call that
     /// have been applied. This value will be set to 1 for the first
@@ -12329,8 +12540,12 @@ pub struct MarginCallExtendTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -12403,9 +12618,9 @@ impl MarginCallExtendTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarginCallExtendTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -12471,7 +12686,7 @@ impl MarginCallExtendTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderTradeClose {
     /// Indication of how much of the Trade to close. Either "ALL", or a
     /// DecimalNumber reflection a partial close of the Trade.
@@ -12530,12 +12745,16 @@ impl MarketOrderTradeClose {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CloseTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -12597,9 +12816,9 @@ impl CloseTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return CloseTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -12665,7 +12884,7 @@ impl CloseTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionFinancing {
     /// The instrument of the Position that financing is being paid/collected
     /// for.
@@ -12684,7 +12903,7 @@ pub struct PositionFinancing {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The financing paid/collecte for each open Trade within the Position.
     #[serde(default)]
@@ -12717,9 +12936,9 @@ impl PositionFinancing {
     /// The amount of financing paid/collected for the Position.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return PositionFinancing
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -12735,7 +12954,7 @@ impl PositionFinancing {
 
 /// DateTime header
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AcceptDatetimeFormat {
     #[serde(rename = "UNIX")]
     Unix,
@@ -12762,7 +12981,7 @@ impl std::fmt::Display for AcceptDatetimeFormat {
 
 /// The reason that the Market Order was created
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MarketOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -12796,7 +13015,7 @@ impl std::fmt::Display for MarketOrderReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalculatedAccountState {
     /// The Account's margin closeout NAV.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12807,7 +13026,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_nav: Option<f32>,
+    pub margin_closeout_nav: Option<f64>,
 
     /// Margin currently used for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12818,7 +13037,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 
     /// Margin available for Account currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12829,7 +13048,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_available: Option<f32>,
+    pub margin_available: Option<f64>,
 
     /// The current WithdrawalLimit for the account which will be zero or a
     /// positive value indicating how much can be withdrawn from the account.
@@ -12841,7 +13060,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub withdrawal_limit: Option<f32>,
+    pub withdrawal_limit: Option<f64>,
 
     /// The total unrealized profit/loss for all Trades currently open in the
     /// Account.
@@ -12853,7 +13072,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// The Account's margin closeout margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12864,7 +13083,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_margin_used: Option<f32>,
+    pub margin_closeout_margin_used: Option<f64>,
 
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12875,7 +13094,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_margin_used: Option<f32>,
+    pub margin_call_margin_used: Option<f64>,
 
     /// The Account's margin call percentage. When this value is 1.0 or above
     /// the Account is in a margin call situation.
@@ -12887,7 +13106,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_call_percent: Option<f32>,
+    pub margin_call_percent: Option<f64>,
 
     /// The Account's margin closeout percentage. When this value is 1.0 or
     /// above the Account is in a margin closeout situation.
@@ -12899,7 +13118,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_percent: Option<f32>,
+    pub margin_closeout_percent: Option<f64>,
 
     /// The net asset value of the Account. Equal to Account balance +
     /// unrealizedPL.
@@ -12911,7 +13130,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub nav: Option<f32>,
+    pub nav: Option<f64>,
 
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -12922,7 +13141,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_unrealized_pl: Option<f32>,
+    pub margin_closeout_unrealized_pl: Option<f64>,
 
     /// The value of the Account's open positions as used for margin closeout
     /// calculations represented in the Account's home currency.
@@ -12934,7 +13153,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_closeout_position_value: Option<f32>,
+    pub margin_closeout_position_value: Option<f64>,
 
     /// The value of the Account's open positions represented in the Account's
     /// home currency.
@@ -12946,7 +13165,7 @@ pub struct CalculatedAccountState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub position_value: Option<f32>,
+    pub position_value: Option<f64>,
 }
 impl CalculatedAccountState {
     pub fn new() -> CalculatedAccountState {
@@ -12970,9 +13189,9 @@ impl CalculatedAccountState {
     /// The Account's margin closeout NAV.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_closeout_nav(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_nav(mut self, x: f64) -> Self {
         self.margin_closeout_nav = Some(x);
         self
     }
@@ -12980,9 +13199,9 @@ impl CalculatedAccountState {
     /// Margin currently used for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
@@ -12990,9 +13209,9 @@ impl CalculatedAccountState {
     /// Margin available for Account currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_available(mut self, x: f32) -> Self {
+    pub fn with_margin_available(mut self, x: f64) -> Self {
         self.margin_available = Some(x);
         self
     }
@@ -13001,9 +13220,9 @@ impl CalculatedAccountState {
     /// positive value indicating how much can be withdrawn from the account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_withdrawal_limit(mut self, x: f32) -> Self {
+    pub fn with_withdrawal_limit(mut self, x: f64) -> Self {
         self.withdrawal_limit = Some(x);
         self
     }
@@ -13012,9 +13231,9 @@ impl CalculatedAccountState {
     /// Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -13022,9 +13241,9 @@ impl CalculatedAccountState {
     /// The Account's margin closeout margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_closeout_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_margin_used(mut self, x: f64) -> Self {
         self.margin_closeout_margin_used = Some(x);
         self
     }
@@ -13032,9 +13251,9 @@ impl CalculatedAccountState {
     /// The Account's margin call margin used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_call_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_call_margin_used(mut self, x: f64) -> Self {
         self.margin_call_margin_used = Some(x);
         self
     }
@@ -13043,9 +13262,9 @@ impl CalculatedAccountState {
     /// the Account is in a margin call situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_call_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_call_percent(mut self, x: f64) -> Self {
         self.margin_call_percent = Some(x);
         self
     }
@@ -13054,9 +13273,9 @@ impl CalculatedAccountState {
     /// above the Account is in a margin closeout situation.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_closeout_percent(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_percent(mut self, x: f64) -> Self {
         self.margin_closeout_percent = Some(x);
         self
     }
@@ -13065,9 +13284,9 @@ impl CalculatedAccountState {
     /// unrealizedPL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_nav(mut self, x: f32) -> Self {
+    pub fn with_nav(mut self, x: f64) -> Self {
         self.nav = Some(x);
         self
     }
@@ -13075,9 +13294,9 @@ impl CalculatedAccountState {
     /// The Account's margin closeout unrealized PL.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_closeout_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_unrealized_pl(mut self, x: f64) -> Self {
         self.margin_closeout_unrealized_pl = Some(x);
         self
     }
@@ -13086,9 +13305,9 @@ impl CalculatedAccountState {
     /// calculations represented in the Account's home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_margin_closeout_position_value(mut self, x: f32) -> Self {
+    pub fn with_margin_closeout_position_value(mut self, x: f64) -> Self {
         self.margin_closeout_position_value = Some(x);
         self
     }
@@ -13097,15 +13316,15 @@ impl CalculatedAccountState {
     /// home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedAccountState
-    pub fn with_position_value(mut self, x: f32) -> Self {
+    pub fn with_position_value(mut self, x: f64) -> Self {
         self.position_value = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderFillTransaction {
     /// The total guaranteed execution fees charged for all Trades opened,
     /// closed or reduced with guaranteed Stop Loss Orders.
@@ -13117,7 +13336,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fee: Option<f32>,
+    pub guaranteed_execution_fee: Option<f64>,
 
     /// The price that all of the units of the OrderFill should have been
     /// filled at, in the absence of guaranteed price execution. This factors
@@ -13134,7 +13353,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub full_vwap: Option<f32>,
+    pub full_vwap: Option<f64>,
 
     /// A TradeReduce object represents a Trade for an instrument that was
     /// reduced (either partially or fully) in an Account. It is found
@@ -13153,7 +13372,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub account_balance: Option<f32>,
+    pub account_balance: Option<f64>,
 
     /// The Request ID of the request which generated the transaction.
     #[serde(default)]
@@ -13184,12 +13403,16 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub commission: Option<f32>,
+    pub commission: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The name of the filled Order's instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -13209,7 +13432,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub loss_quote_home_conversion_factor: Option<f32>,
+    pub loss_quote_home_conversion_factor: Option<f64>,
 
     /// The number of units filled by the OrderFill.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -13220,7 +13443,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "ORDER_FILL" for an
     /// OrderFillTransaction.
@@ -13237,7 +13460,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub pl: Option<f32>,
+    pub pl: Option<f64>,
 
     /// The ID of the Account the Transaction was created for.
     /// format: "-"-delimited string with format
@@ -13262,7 +13485,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// This is the conversion factor in effect for the Account at the time of
     /// the OrderFill for converting any gains realized in Instrument quote
@@ -13275,7 +13498,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub gain_quote_home_conversion_factor: Option<f32>,
+    pub gain_quote_home_conversion_factor: Option<f64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -13301,7 +13524,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub half_spread_cost: Option<f32>,
+    pub half_spread_cost: Option<f64>,
 
     /// The ID of the Order filled.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -13320,7 +13543,7 @@ pub struct OrderFillTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The date/time when the Transaction was created.
     /// format: The RFC 3339 representation is a string conforming to
@@ -13387,9 +13610,9 @@ impl OrderFillTransaction {
     /// closed or reduced with guaranteed Stop Loss Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_guaranteed_execution_fee(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fee(mut self, x: f64) -> Self {
         self.guaranteed_execution_fee = Some(x);
         self
     }
@@ -13403,9 +13626,9 @@ impl OrderFillTransaction {
     /// all be the exact same.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_full_vwap(mut self, x: f32) -> Self {
+    pub fn with_full_vwap(mut self, x: f64) -> Self {
         self.full_vwap = Some(x);
         self
     }
@@ -13424,9 +13647,9 @@ impl OrderFillTransaction {
     /// The Account's balance after the Order was filled.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_account_balance(mut self, x: f32) -> Self {
+    pub fn with_account_balance(mut self, x: f64) -> Self {
         self.account_balance = Some(x);
         self
     }
@@ -13463,17 +13686,17 @@ impl OrderFillTransaction {
     /// balance in the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_commission(mut self, x: f32) -> Self {
+    pub fn with_commission(mut self, x: f64) -> Self {
         self.commission = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return OrderFillTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -13493,9 +13716,9 @@ impl OrderFillTransaction {
     /// units into units of the Account's home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_loss_quote_home_conversion_factor(mut self, x: f32) -> Self {
+    pub fn with_loss_quote_home_conversion_factor(mut self, x: f64) -> Self {
         self.loss_quote_home_conversion_factor = Some(x);
         self
     }
@@ -13503,9 +13726,9 @@ impl OrderFillTransaction {
     /// The number of units filled by the OrderFill.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -13522,9 +13745,9 @@ impl OrderFillTransaction {
     /// The profit or loss incurred when the Order was filled.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_pl(mut self, x: f32) -> Self {
+    pub fn with_pl(mut self, x: f64) -> Self {
         self.pl = Some(x);
         self
     }
@@ -13552,9 +13775,9 @@ impl OrderFillTransaction {
     /// the exact/official price each unit was filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -13564,9 +13787,9 @@ impl OrderFillTransaction {
     /// units into units of the Account's home currency.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_gain_quote_home_conversion_factor(mut self, x: f32) -> Self {
+    pub fn with_gain_quote_home_conversion_factor(mut self, x: f64) -> Self {
         self.gain_quote_home_conversion_factor = Some(x);
         self
     }
@@ -13595,9 +13818,9 @@ impl OrderFillTransaction {
     /// represented in the home currency of the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_half_spread_cost(mut self, x: f32) -> Self {
+    pub fn with_half_spread_cost(mut self, x: f64) -> Self {
         self.half_spread_cost = Some(x);
         self
     }
@@ -13616,9 +13839,9 @@ impl OrderFillTransaction {
     /// The financing paid or collected when the Order was filled.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OrderFillTransaction
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -13658,7 +13881,7 @@ impl OrderFillTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopLossOrderRejectTransaction {
     /// Specifies the distance (in price units) from the Account's current
     /// price to use as the Stop Loss Order price. If the Trade is short the
@@ -13671,7 +13894,7 @@ pub struct StopLossOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// The time-in-force requested for the StopLoss Order. Restricted to
     /// "GTC", "GFD" and "GTD" for StopLoss Orders.
@@ -13741,7 +13964,7 @@ pub struct StopLossOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The client ID of the Trade to be closed when the price threshold is
     /// breached.
@@ -13751,8 +13974,12 @@ pub struct StopLossOrderRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -13875,9 +14102,9 @@ impl StopLossOrderRejectTransaction {
     /// Instrument's bid price is used, and for long Trades the ask is used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderRejectTransaction
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -13911,8 +14138,8 @@ impl StopLossOrderRejectTransaction {
     /// valid.
     /// - param String
     /// - return StopLossOrderRejectTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -13956,9 +14183,9 @@ impl StopLossOrderRejectTransaction {
     /// flag is true the associated Trade will be closed at this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderRejectTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -13973,9 +14200,9 @@ impl StopLossOrderRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return StopLossOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -14093,7 +14320,7 @@ impl StopLossOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrailingStopLossOrderRequest {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -14105,7 +14332,7 @@ pub struct TrailingStopLossOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -14196,9 +14423,9 @@ impl TrailingStopLossOrderRequest {
     /// Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TrailingStopLossOrderRequest
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -14223,8 +14450,8 @@ impl TrailingStopLossOrderRequest {
     /// valid.
     /// - param String
     /// - return TrailingStopLossOrderRequest
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -14248,6 +14475,14 @@ impl TrailingStopLossOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return TrailingStopLossOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
     /// modify, or delete this field if your account is associated with MT4.
@@ -14290,9 +14525,17 @@ impl TrailingStopLossOrderRequest {
         self.otype = Some(x);
         self
     }
+
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return TrailingStopLossOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionHeartbeat {
     /// The string "HEARTBEAT"
     #[serde(default)]
@@ -14361,7 +14604,7 @@ impl TransactionHeartbeat {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Order {
     /// The current state of the Order.
     #[serde(default)]
@@ -14452,7 +14695,7 @@ impl Order {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfigureRejectTransaction {
     /// The reason that the Reject Transaction was created
     #[serde(default)]
@@ -14461,8 +14704,12 @@ pub struct ClientConfigureRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The margin rate override for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -14473,7 +14720,7 @@ pub struct ClientConfigureRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_rate: Option<f32>,
+    pub margin_rate: Option<f64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -14551,9 +14798,9 @@ impl ClientConfigureRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return ClientConfigureRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -14561,9 +14808,9 @@ impl ClientConfigureRejectTransaction {
     /// The margin rate override for the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return ClientConfigureRejectTransaction
-    pub fn with_margin_rate(mut self, x: f32) -> Self {
+    pub fn with_margin_rate(mut self, x: f64) -> Self {
         self.margin_rate = Some(x);
         self
     }
@@ -14637,7 +14884,7 @@ impl ClientConfigureRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstrumentCommission {
     /// The commission amount (in the Account's home currency) charged per
     /// unitsTraded of the instrument
@@ -14649,7 +14896,7 @@ pub struct InstrumentCommission {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub commission: Option<f32>,
+    pub commission: Option<f64>,
 
     /// The number of units traded that the commission amount is based on.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -14660,7 +14907,7 @@ pub struct InstrumentCommission {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units_traded: Option<f32>,
+    pub units_traded: Option<f64>,
 
     /// The minimum commission amount (in the Account's home currency) that is
     /// charged when an Order is filled for this instrument.
@@ -14672,7 +14919,7 @@ pub struct InstrumentCommission {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub minimum_commission: Option<f32>,
+    pub minimum_commission: Option<f64>,
 }
 impl InstrumentCommission {
     pub fn new() -> InstrumentCommission {
@@ -14687,9 +14934,9 @@ impl InstrumentCommission {
     /// unitsTraded of the instrument
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return InstrumentCommission
-    pub fn with_commission(mut self, x: f32) -> Self {
+    pub fn with_commission(mut self, x: f64) -> Self {
         self.commission = Some(x);
         self
     }
@@ -14697,9 +14944,9 @@ impl InstrumentCommission {
     /// The number of units traded that the commission amount is based on.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return InstrumentCommission
-    pub fn with_units_traded(mut self, x: f32) -> Self {
+    pub fn with_units_traded(mut self, x: f64) -> Self {
         self.units_traded = Some(x);
         self
     }
@@ -14708,9 +14955,9 @@ impl InstrumentCommission {
     /// charged when an Order is filled for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return InstrumentCommission
-    pub fn with_minimum_commission(mut self, x: f32) -> Self {
+    pub fn with_minimum_commission(mut self, x: f64) -> Self {
         self.minimum_commission = Some(x);
         self
     }
@@ -14718,7 +14965,7 @@ impl InstrumentCommission {
 
 /// The current state of the Order.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OrderState {
     #[serde(rename = "PENDING")]
     Pending,
@@ -14749,12 +14996,16 @@ impl std::fmt::Display for OrderState {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReopenTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -14816,9 +15067,9 @@ impl ReopenTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return ReopenTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -14884,7 +15135,7 @@ impl ReopenTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrailingStopLossOrder {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -14896,7 +15147,7 @@ pub struct TrailingStopLossOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// The date/time when the StopLoss Order will be cancelled if its
     /// timeInForce is "GTD".
@@ -14994,7 +15245,7 @@ pub struct TrailingStopLossOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub trailing_stop_value: Option<f32>,
+    pub trailing_stop_value: Option<f64>,
 
     /// The time when the Order was created.
     /// format: The RFC 3339 representation is a string conforming to
@@ -15141,9 +15392,9 @@ impl TrailingStopLossOrder {
     /// Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TrailingStopLossOrder
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -15247,9 +15498,9 @@ impl TrailingStopLossOrder {
     /// Trade will be closed.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TrailingStopLossOrder
-    pub fn with_trailing_stop_value(mut self, x: f32) -> Self {
+    pub fn with_trailing_stop_value(mut self, x: f64) -> Self {
         self.trailing_stop_value = Some(x);
         self
     }
@@ -15277,6 +15528,14 @@ impl TrailingStopLossOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return TrailingStopLossOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// Trade ID of Trade reduced when the Order was filled (only provided
     /// when the Order's state is FILLED and a Trade was reduced as a result
     /// of the fill)
@@ -15318,8 +15577,8 @@ impl TrailingStopLossOrder {
     /// valid.
     /// - param String
     /// - return TrailingStopLossOrder
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -15371,6 +15630,14 @@ impl TrailingStopLossOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return TrailingStopLossOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -15397,7 +15664,7 @@ impl TrailingStopLossOrder {
 
 /// The type of the Order.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderType {
     #[serde(rename = "MARKET")]
     Market,
@@ -15442,7 +15709,7 @@ impl std::fmt::Display for OrderType {
 
 /// The reason that the Market-if-touched Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MarketIfTouchedOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -15469,7 +15736,7 @@ impl std::fmt::Display for MarketIfTouchedOrderReason {
 
 /// The type of the Order.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CancellableOrderType {
     #[serde(rename = "LIMIT")]
     Limit,
@@ -15509,7 +15776,7 @@ impl std::fmt::Display for CancellableOrderType {
 /// The way that position values for an Account are calculated and
 /// aggregated.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PositionAggregationMode {
     #[serde(rename = "ABSOLUTE_SUM")]
     AbsoluteSum,
@@ -15537,7 +15804,7 @@ impl std::fmt::Display for PositionAggregationMode {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrailingStopLossOrderTransaction {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -15549,7 +15816,7 @@ pub struct TrailingStopLossOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// The time-in-force requested for the TrailingStopLoss Order. Restricted
     /// to "GTC", "GFD" and "GTD" for TrailingStopLoss Orders.
@@ -15604,8 +15871,12 @@ pub struct TrailingStopLossOrderTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -15730,9 +16001,9 @@ impl TrailingStopLossOrderTransaction {
     /// Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TrailingStopLossOrderTransaction
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -15766,8 +16037,8 @@ impl TrailingStopLossOrderTransaction {
     /// valid.
     /// - param String
     /// - return TrailingStopLossOrderTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -15804,9 +16075,9 @@ impl TrailingStopLossOrderTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TrailingStopLossOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -15926,7 +16197,7 @@ impl TrailingStopLossOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionBookBucket {
     /// The lowest price (inclusive) covered by the bucket. The bucket covers
     /// the price range from the price to price + the position book's
@@ -15939,7 +16210,7 @@ pub struct PositionBookBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The percentage of the total number of positions represented by the
     /// short positions found in this bucket.
@@ -15951,7 +16222,7 @@ pub struct PositionBookBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub short_count_percent: Option<f32>,
+    pub short_count_percent: Option<f64>,
 
     /// The percentage of the total number of positions represented by the
     /// long positions found in this bucket.
@@ -15963,7 +16234,7 @@ pub struct PositionBookBucket {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub long_count_percent: Option<f32>,
+    pub long_count_percent: Option<f64>,
 }
 impl PositionBookBucket {
     pub fn new() -> PositionBookBucket {
@@ -15979,9 +16250,9 @@ impl PositionBookBucket {
     /// bucketWidth.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return PositionBookBucket
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -15990,9 +16261,9 @@ impl PositionBookBucket {
     /// short positions found in this bucket.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return PositionBookBucket
-    pub fn with_short_count_percent(mut self, x: f32) -> Self {
+    pub fn with_short_count_percent(mut self, x: f64) -> Self {
         self.short_count_percent = Some(x);
         self
     }
@@ -16001,9 +16272,9 @@ impl PositionBookBucket {
     /// long positions found in this bucket.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return PositionBookBucket
-    pub fn with_long_count_percent(mut self, x: f32) -> Self {
+    pub fn with_long_count_percent(mut self, x: f64) -> Self {
         self.long_count_percent = Some(x);
         self
     }
@@ -16011,7 +16282,7 @@ impl PositionBookBucket {
 
 /// The possible types of a Transaction
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionType {
     #[serde(rename = "CREATE")]
     Create,
@@ -16139,7 +16410,7 @@ impl std::fmt::Display for TransactionType {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderTransaction {
     /// The time-in-force requested for the MarketIfTouched Order. Restricted
     /// to "GTC", "GFD" and "GTD" for MarketIfTouched Orders.
@@ -16174,12 +16445,16 @@ pub struct MarketIfTouchedOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The MarketIfTouched Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -16199,7 +16474,7 @@ pub struct MarketIfTouchedOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "MARKET_IF_TOUCHED_ORDER"
     /// in a MarketIfTouchedOrderTransaction.
@@ -16228,7 +16503,7 @@ pub struct MarketIfTouchedOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -16418,8 +16693,8 @@ impl MarketIfTouchedOrderTransaction {
     /// Order is filled.
     /// - param String
     /// - return MarketIfTouchedOrderTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -16427,17 +16702,17 @@ impl MarketIfTouchedOrderTransaction {
     /// Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderTransaction
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarketIfTouchedOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -16457,9 +16732,9 @@ impl MarketIfTouchedOrderTransaction {
     /// number of units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -16491,9 +16766,9 @@ impl MarketIfTouchedOrderTransaction {
     /// MarketIfTouchedOrder will behave like a Limit or a Stop Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -16569,8 +16844,8 @@ impl MarketIfTouchedOrderTransaction {
     /// valid.
     /// - param String
     /// - return MarketIfTouchedOrderTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -16647,7 +16922,7 @@ impl MarketIfTouchedOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PricingHeartbeat {
     /// The string "HEARTBEAT"
     #[serde(default)]
@@ -16700,7 +16975,7 @@ impl PricingHeartbeat {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -16748,7 +17023,7 @@ pub struct StopOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The Stop Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -16773,7 +17048,7 @@ pub struct StopOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The type of the Order. Always set to "STOP" for Stop Orders.
     #[serde(default)]
@@ -16799,7 +17074,7 @@ pub struct StopOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// Trade ID of Trade opened when the Order was filled (only provided when
     /// the Order's state is FILLED and a Trade was opened as a result of the
@@ -17027,6 +17302,14 @@ impl StopOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return StopOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -17042,8 +17325,8 @@ impl StopOrder {
     /// Order is filled.
     /// - param String
     /// - return StopOrder
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -17052,9 +17335,9 @@ impl StopOrder {
     /// the Stop Order will be cancelled instead of being filled.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrder
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
@@ -17082,9 +17365,9 @@ impl StopOrder {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopOrder
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -17097,6 +17380,14 @@ impl StopOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return StopOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
     /// that opens a Trade requiring a Stop Loss, or when a Trade's dependent
@@ -17113,9 +17404,9 @@ impl StopOrder {
     /// price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrder
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -17227,8 +17518,8 @@ impl StopOrder {
     /// valid.
     /// - param String
     /// - return StopOrder
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -17315,7 +17606,7 @@ impl StopOrder {
 
 /// The granularity of a candlestick
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CandlestickGranularity {
     #[serde(rename = "S5")]
     S5,
@@ -17362,8 +17653,8 @@ pub enum CandlestickGranularity {
 }
 
 impl FromStr for CandlestickGranularity {
-    type Err = ();
-    fn from_str(s: &str) -> Result<CandlestickGranularity, ()> {
+    type Err = ParseGranularityError;
+    fn from_str(s: &str) -> Result<CandlestickGranularity, ParseGranularityError> {
         match s {
             "S5" => Ok(CandlestickGranularity::S5),
             "S10" => Ok(CandlestickGranularity::S10),
@@ -17386,18 +17677,18 @@ impl FromStr for CandlestickGranularity {
             "D" => Ok(CandlestickGranularity::D),
             "W" => Ok(CandlestickGranularity::W),
             "M" => Ok(CandlestickGranularity::M),
-            _ => Err(()),
+            _ => Err(ParseGranularityError(s.to_string())),
         }
     }
 }
 
 impl std::fmt::Display for CandlestickGranularity {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        f.write_str(self.as_str())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TakeProfitDetails {
     /// The time in force for the created Take Profit Order. This may only be
     /// GTC, GTD or GFD.
@@ -17415,7 +17706,7 @@ pub struct TakeProfitDetails {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The date when the Take Profit Order will be cancelled on if
     /// timeInForce is GTD.
@@ -17463,9 +17754,9 @@ impl TakeProfitDetails {
     /// the price and distance fields may be specified.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TakeProfitDetails
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -17496,7 +17787,7 @@ impl TakeProfitDetails {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientExtensions {
     /// A comment associated with the Order/Trade
     #[serde(default)]
@@ -17549,7 +17840,7 @@ impl ClientExtensions {
 
 /// The state to filter the Trades by
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TradeStateFilter {
     #[serde(rename = "OPEN")]
     Open,
@@ -17582,7 +17873,7 @@ impl std::fmt::Display for TradeStateFilter {
 
 /// The classification of TradePLs.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TradePL {
     #[serde(rename = "POSITIVE")]
     Positive,
@@ -17612,7 +17903,7 @@ impl std::fmt::Display for TradePL {
 
 /// The reason that the Stop Loss Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StopLossOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -17640,7 +17931,7 @@ impl std::fmt::Display for StopLossOrderReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TakeProfitOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -17718,7 +18009,7 @@ pub struct TakeProfitOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// Trade ID of Trade opened when the Order was filled (only provided when
     /// the Order's state is FILLED and a Trade was opened as a result of the
@@ -17950,9 +18241,9 @@ impl TakeProfitOrder {
     /// this threshold.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TakeProfitOrder
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -18003,6 +18294,14 @@ impl TakeProfitOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return TakeProfitOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// Trade ID of Trade reduced when the Order was filled (only provided
     /// when the Order's state is FILLED and a Trade was reduced as a result
     /// of the fill)
@@ -18044,8 +18343,8 @@ impl TakeProfitOrder {
     /// valid.
     /// - param String
     /// - return TakeProfitOrder
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -18097,6 +18396,14 @@ impl TakeProfitOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return TakeProfitOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -18123,7 +18430,7 @@ impl TakeProfitOrder {
 
 /// The reason that the Trailing Stop Loss Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TrailingStopLossOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -18151,7 +18458,7 @@ impl std::fmt::Display for TrailingStopLossOrderReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LimitOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -18186,7 +18493,7 @@ pub struct LimitOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -18256,7 +18563,7 @@ pub struct LimitOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The date/time when the Limit Order will be cancelled if its
     /// timeInForce is "GTD".
@@ -18325,8 +18632,8 @@ impl LimitOrderRequest {
     /// valid.
     /// - param String
     /// - return LimitOrderRequest
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -18335,9 +18642,9 @@ impl LimitOrderRequest {
     /// this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrderRequest
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -18372,6 +18679,14 @@ impl LimitOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return LimitOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Limit Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
     /// a "_".
@@ -18419,9 +18734,9 @@ impl LimitOrderRequest {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrderRequest
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -18450,17 +18765,25 @@ impl LimitOrderRequest {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return LimitOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// Specification of how Positions in the Account are modified when the
     /// Order is filled.
     /// - param String
     /// - return LimitOrderRequest
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Instrument {
     /// The smallest number of units allowed to be traded for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -18471,7 +18794,7 @@ pub struct Instrument {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub minimum_trade_size: Option<f32>,
+    pub minimum_trade_size: Option<f64>,
 
     /// The display name of the Instrument
     #[serde(default)]
@@ -18502,7 +18825,7 @@ pub struct Instrument {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub maximum_trailing_stop_distance: Option<f32>,
+    pub maximum_trailing_stop_distance: Option<f64>,
 
     /// The minimum trailing stop distance allowed for a trailing stop loss
     /// created for this instrument. Specified in price units.
@@ -18514,7 +18837,20 @@ pub struct Instrument {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub minimum_trailing_stop_distance: Option<f32>,
+    pub minimum_trailing_stop_distance: Option<f64>,
+
+    /// The minimum distance allowed between the Trade's fill price and the
+    /// configured price for guaranteed Stop Loss Orders created for this
+    /// instrument. Specified in price units.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    #[serde(default)]
+    #[serde(
+        rename = "minimumGuaranteedStopLossDistance",
+        skip_serializing_if = "Option::is_none",
+        with = "serfloats"
+    )]
+    pub minimum_guaranteed_stop_loss_distance: Option<f64>,
 
     /// The margin rate for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -18525,7 +18861,7 @@ pub struct Instrument {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_rate: Option<f32>,
+    pub margin_rate: Option<f64>,
 
     /// An InstrumentCommission represents an instrument-specific commission
     #[serde(default)]
@@ -18559,7 +18895,7 @@ pub struct Instrument {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub maximum_order_units: Option<f32>,
+    pub maximum_order_units: Option<f64>,
 
     /// The maximum position size allowed for this instrument. Specified in
     /// units.
@@ -18571,12 +18907,17 @@ pub struct Instrument {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub maximum_position_size: Option<f32>,
+    pub maximum_position_size: Option<f64>,
 
     /// The type of the Instrument
     #[serde(default)]
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub otype: Option<String>,
+
+    /// Financing data for the Instrument
+    #[serde(default)]
+    #[serde(rename = "financing", skip_serializing_if = "Option::is_none")]
+    pub financing: Option<InstrumentFinancingTerms>,
 }
 impl Instrument {
     pub fn new() -> Instrument {
@@ -18587,6 +18928,7 @@ impl Instrument {
             display_precision: None,
             maximum_trailing_stop_distance: None,
             minimum_trailing_stop_distance: None,
+            minimum_guaranteed_stop_loss_distance: None,
             margin_rate: None,
             commission: None,
             trade_units_precision: None,
@@ -18594,15 +18936,16 @@ impl Instrument {
             maximum_order_units: None,
             maximum_position_size: None,
             otype: None,
+            financing: None,
         }
     }
 
     /// The smallest number of units allowed to be traded for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Instrument
-    pub fn with_minimum_trade_size(mut self, x: f32) -> Self {
+    pub fn with_minimum_trade_size(mut self, x: f64) -> Self {
         self.minimum_trade_size = Some(x);
         self
     }
@@ -18639,9 +18982,9 @@ impl Instrument {
     /// created for this instrument. Specified in price units.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Instrument
-    pub fn with_maximum_trailing_stop_distance(mut self, x: f32) -> Self {
+    pub fn with_maximum_trailing_stop_distance(mut self, x: f64) -> Self {
         self.maximum_trailing_stop_distance = Some(x);
         self
     }
@@ -18650,19 +18993,31 @@ impl Instrument {
     /// created for this instrument. Specified in price units.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Instrument
-    pub fn with_minimum_trailing_stop_distance(mut self, x: f32) -> Self {
+    pub fn with_minimum_trailing_stop_distance(mut self, x: f64) -> Self {
         self.minimum_trailing_stop_distance = Some(x);
         self
     }
 
+    /// The minimum distance allowed between the Trade's fill price and the
+    /// configured price for guaranteed Stop Loss Orders created for this
+    /// instrument. Specified in price units.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    /// - param f64
+    /// - return Instrument
+    pub fn with_minimum_guaranteed_stop_loss_distance(mut self, x: f64) -> Self {
+        self.minimum_guaranteed_stop_loss_distance = Some(x);
+        self
+    }
+
     /// The margin rate for this instrument.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Instrument
-    pub fn with_margin_rate(mut self, x: f32) -> Self {
+    pub fn with_margin_rate(mut self, x: f64) -> Self {
         self.margin_rate = Some(x);
         self
     }
@@ -18699,9 +19054,9 @@ impl Instrument {
     /// Specified in units.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Instrument
-    pub fn with_maximum_order_units(mut self, x: f32) -> Self {
+    pub fn with_maximum_order_units(mut self, x: f64) -> Self {
         self.maximum_order_units = Some(x);
         self
     }
@@ -18710,9 +19065,9 @@ impl Instrument {
     /// units.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return Instrument
-    pub fn with_maximum_position_size(mut self, x: f32) -> Self {
+    pub fn with_maximum_position_size(mut self, x: f64) -> Self {
         self.maximum_position_size = Some(x);
         self
     }
@@ -18724,9 +19079,131 @@ impl Instrument {
         self.otype = Some(x);
         self
     }
+
+    /// Financing data for the Instrument
+    /// - param InstrumentFinancingTerms
+    /// - return Instrument
+    pub fn with_financing(mut self, x: InstrumentFinancingTerms) -> Self {
+        self.financing = Some(x);
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Financing data for an Instrument. OANDA calls this type
+/// InstrumentFinancing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstrumentFinancingTerms {
+    /// The financing rate to be used for a long position for the instrument.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    #[serde(default)]
+    #[serde(
+        rename = "longRate",
+        skip_serializing_if = "Option::is_none",
+        with = "serfloats"
+    )]
+    pub long_rate: Option<f64>,
+
+    /// The financing rate to be used for a short position for the instrument.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    #[serde(default)]
+    #[serde(
+        rename = "shortRate",
+        skip_serializing_if = "Option::is_none",
+        with = "serfloats"
+    )]
+    pub short_rate: Option<f64>,
+
+    /// The days of the week to debit or credit financing charges; the exact
+    /// time of day at which to charge the financing is set in the
+    /// DivisionTradingGroup for the client's account.
+    #[serde(default)]
+    #[serde(
+        rename = "financingDaysOfWeek",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub financing_days_of_week: Option<Vec<FinancingDayOfWeek>>,
+}
+impl InstrumentFinancingTerms {
+    pub fn new() -> InstrumentFinancingTerms {
+        InstrumentFinancingTerms {
+            long_rate: None,
+            short_rate: None,
+            financing_days_of_week: None,
+        }
+    }
+
+    /// The financing rate to be used for a long position for the instrument.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    /// - param f64
+    /// - return InstrumentFinancingTerms
+    pub fn with_long_rate(mut self, x: f64) -> Self {
+        self.long_rate = Some(x);
+        self
+    }
+
+    /// The financing rate to be used for a short position for the instrument.
+    /// format: A decimal number encoded as a string. The amount of precision provided
+    /// depends on what the number represents.
+    /// - param f64
+    /// - return InstrumentFinancingTerms
+    pub fn with_short_rate(mut self, x: f64) -> Self {
+        self.short_rate = Some(x);
+        self
+    }
+
+    /// The days of the week to debit or credit financing charges.
+    /// - param Vec<FinancingDayOfWeek>
+    /// - return InstrumentFinancingTerms
+    pub fn with_financing_days_of_week(mut self, x: Vec<FinancingDayOfWeek>) -> Self {
+        self.financing_days_of_week = Some(x);
+        self
+    }
+}
+
+/// A FinancingDayOfWeek message defines a day of the week when financing
+/// charges are debited or credited.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancingDayOfWeek {
+    /// The day of the week to charge the financing.
+    /// format: One of MONDAY through SUNDAY
+    #[serde(default)]
+    #[serde(rename = "dayOfWeek", skip_serializing_if = "Option::is_none")]
+    pub day_of_week: Option<String>,
+
+    /// The number of days worth of financing to be charged on dayOfWeek.
+    #[serde(default)]
+    #[serde(rename = "daysCharged", skip_serializing_if = "Option::is_none")]
+    pub days_charged: Option<i32>,
+}
+impl FinancingDayOfWeek {
+    pub fn new() -> FinancingDayOfWeek {
+        FinancingDayOfWeek {
+            day_of_week: None,
+            days_charged: None,
+        }
+    }
+
+    /// The day of the week to charge the financing.
+    /// - param String
+    /// - return FinancingDayOfWeek
+    pub fn with_day_of_week(mut self, x: String) -> Self {
+        self.day_of_week = Some(x);
+        self
+    }
+
+    /// The number of days worth of financing to be charged on dayOfWeek.
+    /// - param i32
+    /// - return FinancingDayOfWeek
+    pub fn with_days_charged(mut self, x: i32) -> Self {
+        self.days_charged = Some(x);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenTradeFinancing {
     /// The amount of financing paid/collected for the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -18737,7 +19214,7 @@ pub struct OpenTradeFinancing {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The ID of the Trade that financing is being paid/collected for.
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -18758,9 +19235,9 @@ impl OpenTradeFinancing {
     /// The amount of financing paid/collected for the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return OpenTradeFinancing
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -18779,7 +19256,7 @@ impl OpenTradeFinancing {
 
 /// The reason that an Order was cancelled.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OrderCancelReason {
     #[serde(rename = "INTERNAL_SERVER_ERROR")]
     InternalServerError,
@@ -18977,7 +19454,7 @@ impl std::fmt::Display for OrderCancelReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrailingStopLossDetails {
     /// The time in force for the created Trailing Stop Loss Order. This may
     /// only be GTC, GTD or GFD.
@@ -18995,7 +19472,7 @@ pub struct TrailingStopLossDetails {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// The date when the Trailing Stop Loss Order will be cancelled on if
     /// timeInForce is GTD.
@@ -19043,9 +19520,9 @@ impl TrailingStopLossDetails {
     /// Trailing Stop Loss Order will be triggered at.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TrailingStopLossDetails
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -19076,7 +19553,7 @@ impl TrailingStopLossDetails {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiquidityRegenerationSchedule {
     /// The steps in the Liquidity Regeneration Schedule
     #[serde(default)]
@@ -19097,7 +19574,7 @@ impl LiquidityRegenerationSchedule {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -19163,7 +19640,7 @@ pub struct MarketOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The Market Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -19188,7 +19665,7 @@ pub struct MarketOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The type of the Order. Always set to "MARKET" for Market Orders.
     #[serde(default)]
@@ -19413,6 +19890,14 @@ impl MarketOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return MarketOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -19428,8 +19913,8 @@ impl MarketOrder {
     /// Order is filled.
     /// - param String
     /// - return MarketOrder
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -19437,9 +19922,9 @@ impl MarketOrder {
     /// filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrder
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
@@ -19467,9 +19952,9 @@ impl MarketOrder {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrder
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -19482,6 +19967,14 @@ impl MarketOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return MarketOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// Trade ID of Trade opened when the Order was filled (only provided when
     /// the Order's state is FILLED and a Trade was opened as a result of the
     /// fill)
@@ -19652,7 +20145,7 @@ impl MarketOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderCancelRejectTransaction {
     /// The ID of the Order intended to be cancelled
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -19673,8 +20166,12 @@ pub struct OrderCancelRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -19767,9 +20264,9 @@ impl OrderCancelRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return OrderCancelRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -19843,7 +20340,7 @@ impl OrderCancelRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopLossOrderTransaction {
     /// Specifies the distance (in price units) from the Account's current
     /// price to use as the Stop Loss Order price. If the Trade is short the
@@ -19856,7 +20353,7 @@ pub struct StopLossOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// The time-in-force requested for the StopLoss Order. Restricted to
     /// "GTC", "GFD" and "GTD" for StopLoss Orders.
@@ -19923,7 +20420,7 @@ pub struct StopLossOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The client ID of the Trade to be closed when the price threshold is
     /// breached.
@@ -19933,8 +20430,12 @@ pub struct StopLossOrderTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -19960,7 +20461,7 @@ pub struct StopLossOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_premium: Option<f32>,
+    pub guaranteed_execution_premium: Option<f64>,
 
     /// The Request ID of the request which generated the transaction.
     #[serde(default)]
@@ -20077,9 +20578,9 @@ impl StopLossOrderTransaction {
     /// Instrument's bid price is used, and for long Trades the ask is used.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderTransaction
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -20113,8 +20614,8 @@ impl StopLossOrderTransaction {
     /// valid.
     /// - param String
     /// - return StopLossOrderTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -20158,9 +20659,9 @@ impl StopLossOrderTransaction {
     /// flag is true the associated Trade will be closed at this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -20175,9 +20676,9 @@ impl StopLossOrderTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return StopLossOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -20206,9 +20707,9 @@ impl StopLossOrderTransaction {
     /// unit of the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopLossOrderTransaction
-    pub fn with_guaranteed_execution_premium(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_premium(mut self, x: f64) -> Self {
         self.guaranteed_execution_premium = Some(x);
         self
     }
@@ -20310,7 +20811,7 @@ impl StopLossOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Price {
     /// The list of prices and liquidity available on the Instrument's ask
     /// side. It is possible for this list to be empty if there is no ask
@@ -20328,7 +20829,7 @@ pub struct Price {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub base_ask: Option<f32>,
+    pub base_ask: Option<f64>,
 
     /// The date/time when the Price was created.
     /// format: The RFC 3339 representation is a string conforming to
@@ -20356,7 +20857,7 @@ pub struct Price {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub closeout_bid: Option<f32>,
+    pub closeout_bid: Option<f64>,
 
     /// The list of prices and liquidity available on the Instrument's bid
     /// side. It is possible for this list to be empty if there is no bid
@@ -20381,7 +20882,7 @@ pub struct Price {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub base_bid: Option<f32>,
+    pub base_bid: Option<f64>,
 
     /// The closeout ask price. This price is used when an ask is required to
     /// closeout a Position (margin closeout or manual) yet there is no ask
@@ -20394,7 +20895,7 @@ pub struct Price {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub closeout_ask: Option<f32>,
+    pub closeout_ask: Option<f64>,
 
     /// Flag indicating if the Price is tradeable or not
     #[serde(default)]
@@ -20429,9 +20930,9 @@ impl Price {
     /// The base ask price as calculated by pricing.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return Price
-    pub fn with_base_ask(mut self, x: f32) -> Self {
+    pub fn with_base_ask(mut self, x: f64) -> Self {
         self.base_ask = Some(x);
         self
     }
@@ -20455,9 +20956,9 @@ impl Price {
     /// liquidity. The closeout bid is never used to open a new position.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return Price
-    pub fn with_closeout_bid(mut self, x: f32) -> Self {
+    pub fn with_closeout_bid(mut self, x: f64) -> Self {
         self.closeout_bid = Some(x);
         self
     }
@@ -20485,9 +20986,9 @@ impl Price {
     /// The base bid price as calculated by pricing.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return Price
-    pub fn with_base_bid(mut self, x: f32) -> Self {
+    pub fn with_base_bid(mut self, x: f64) -> Self {
         self.base_bid = Some(x);
         self
     }
@@ -20497,9 +20998,9 @@ impl Price {
     /// liquidity. The closeout ask is never used to open a new position.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return Price
-    pub fn with_closeout_ask(mut self, x: f32) -> Self {
+    pub fn with_closeout_ask(mut self, x: f64) -> Self {
         self.closeout_ask = Some(x);
         self
     }
@@ -20513,7 +21014,7 @@ impl Price {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LimitOrder {
     /// Date/time when the Order was filled (only provided when the Order's
     /// state is FILLED)
@@ -20573,7 +21074,7 @@ pub struct LimitOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The type of the Order. Always set to "LIMIT" for Limit Orders.
     #[serde(default)]
@@ -20601,7 +21102,7 @@ pub struct LimitOrder {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -20826,6 +21327,14 @@ impl LimitOrder {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return LimitOrder
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Order's identifier, unique within the Order's Account.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
     /// assigned OrderIDs are positive integers, and are derived from the
@@ -20841,8 +21350,8 @@ impl LimitOrder {
     /// Order is filled.
     /// - param String
     /// - return LimitOrder
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -20869,9 +21378,9 @@ impl LimitOrder {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrder
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -20884,6 +21393,14 @@ impl LimitOrder {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return LimitOrder
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// Trade ID of Trade opened when the Order was filled (only provided when
     /// the Order's state is FILLED and a Trade was opened as a result of the
     /// fill)
@@ -20902,9 +21419,9 @@ impl LimitOrder {
     /// this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrder
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -21014,8 +21531,8 @@ impl LimitOrder {
     /// valid.
     /// - param String
     /// - return LimitOrder
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -21100,7 +21617,7 @@ impl LimitOrder {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrailingStopLossOrderRejectTransaction {
     /// The price distance (in price units) specified for the TrailingStopLoss
     /// Order.
@@ -21112,7 +21629,7 @@ pub struct TrailingStopLossOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub distance: Option<f32>,
+    pub distance: Option<f64>,
 
     /// The time-in-force requested for the TrailingStopLoss Order. Restricted
     /// to "GTC", "GFD" and "GTD" for TrailingStopLoss Orders.
@@ -21170,8 +21687,12 @@ pub struct TrailingStopLossOrderRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -21292,9 +21813,9 @@ impl TrailingStopLossOrderRejectTransaction {
     /// Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TrailingStopLossOrderRejectTransaction
-    pub fn with_distance(mut self, x: f32) -> Self {
+    pub fn with_distance(mut self, x: f64) -> Self {
         self.distance = Some(x);
         self
     }
@@ -21328,8 +21849,8 @@ impl TrailingStopLossOrderRejectTransaction {
     /// valid.
     /// - param String
     /// - return TrailingStopLossOrderRejectTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -21366,9 +21887,9 @@ impl TrailingStopLossOrderRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TrailingStopLossOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -21487,7 +22008,7 @@ impl TrailingStopLossOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderClientExtensionsModifyTransaction {
     /// The ID of the Order who's client extensions are to be modified.
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -21505,8 +22026,12 @@ pub struct OrderClientExtensionsModifyTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -21613,9 +22138,9 @@ impl OrderClientExtensionsModifyTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return OrderClientExtensionsModifyTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -21702,7 +22227,7 @@ impl OrderClientExtensionsModifyTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderRequest {
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -21728,7 +22253,7 @@ pub struct MarketOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// TakeProfitDetails specifies the details of a Take Profit Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -21784,7 +22309,7 @@ pub struct MarketOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// A ClientExtensions object allows a client to attach a clientID, tag
     /// and comment to Orders and Trades in their Account.  Do not set,
@@ -21831,8 +22356,8 @@ impl MarketOrderRequest {
     /// Order is filled.
     /// - param String
     /// - return MarketOrderRequest
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -21840,9 +22365,9 @@ impl MarketOrderRequest {
     /// filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrderRequest
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
@@ -21867,6 +22392,14 @@ impl MarketOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return MarketOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Market Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
     /// a "_".
@@ -21904,9 +22437,9 @@ impl MarketOrderRequest {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrderRequest
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -21929,9 +22462,17 @@ impl MarketOrderRequest {
         self.otype = Some(x);
         self
     }
+
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return MarketOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserInfo {
     /// The user-provided username.
     #[serde(default)]
@@ -21950,8 +22491,12 @@ pub struct UserInfo {
 
     /// The user's OANDA-assigned user ID.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 }
 impl UserInfo {
     pub fn new() -> UserInfo {
@@ -21988,15 +22533,15 @@ impl UserInfo {
     }
 
     /// The user's OANDA-assigned user ID.
-    /// - param i32
+    /// - param i64
     /// - return UserInfo
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferFundsRejectTransaction {
     /// An optional comment that may be attached to a fund transfer for audit
     /// purposes
@@ -22011,8 +22556,12 @@ pub struct TransferFundsRejectTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -22037,7 +22586,7 @@ pub struct TransferFundsRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub amount: Option<f32>,
+    pub amount: Option<f64>,
 
     /// The Request ID of the request which generated the transaction.
     #[serde(default)]
@@ -22113,9 +22662,9 @@ impl TransferFundsRejectTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return TransferFundsRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -22143,9 +22692,9 @@ impl TransferFundsRejectTransaction {
     /// indicates a withdrawal.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TransferFundsRejectTransaction
-    pub fn with_amount(mut self, x: f32) -> Self {
+    pub fn with_amount(mut self, x: f64) -> Self {
         self.amount = Some(x);
         self
     }
@@ -22201,7 +22750,7 @@ impl TransferFundsRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiquidityRegenerationScheduleStep {
     /// The amount of bid liquidity used at this step in the schedule.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -22212,7 +22761,7 @@ pub struct LiquidityRegenerationScheduleStep {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub bid_liquidity_used: Option<f32>,
+    pub bid_liquidity_used: Option<f64>,
 
     /// The timestamp of the schedule step.
     /// format: The RFC 3339 representation is a string conforming to
@@ -22238,7 +22787,7 @@ pub struct LiquidityRegenerationScheduleStep {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub ask_liquidity_used: Option<f32>,
+    pub ask_liquidity_used: Option<f64>,
 }
 impl LiquidityRegenerationScheduleStep {
     pub fn new() -> LiquidityRegenerationScheduleStep {
@@ -22252,9 +22801,9 @@ impl LiquidityRegenerationScheduleStep {
     /// The amount of bid liquidity used at this step in the schedule.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return LiquidityRegenerationScheduleStep
-    pub fn with_bid_liquidity_used(mut self, x: f32) -> Self {
+    pub fn with_bid_liquidity_used(mut self, x: f64) -> Self {
         self.bid_liquidity_used = Some(x);
         self
     }
@@ -22276,15 +22825,15 @@ impl LiquidityRegenerationScheduleStep {
     /// The amount of ask liquidity used at this step in the schedule.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return LiquidityRegenerationScheduleStep
-    pub fn with_ask_liquidity_used(mut self, x: f32) -> Self {
+    pub fn with_ask_liquidity_used(mut self, x: f64) -> Self {
         self.ask_liquidity_used = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderIdentifier {
     /// The OANDA-assigned Order ID
     /// format: The string representation of the OANDA-assigned OrderID. OANDA-
@@ -22327,7 +22876,7 @@ impl OrderIdentifier {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketOrderRejectTransaction {
     /// A MarketOrderPositionCloseout specifies the extensions to a Market
     /// Order when it has been created to closeout a specific Position.
@@ -22380,12 +22929,16 @@ pub struct MarketOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The Market Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -22405,7 +22958,7 @@ pub struct MarketOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "MARKET_ORDER_REJECT" in a
     /// MarketOrderRejectTransaction.
@@ -22596,8 +23149,8 @@ impl MarketOrderRejectTransaction {
     /// Order is filled.
     /// - param String
     /// - return MarketOrderRejectTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -22605,17 +23158,17 @@ impl MarketOrderRejectTransaction {
     /// filled at.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrderRejectTransaction
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarketOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -22635,9 +23188,9 @@ impl MarketOrderRejectTransaction {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketOrderRejectTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -22789,7 +23342,7 @@ impl MarketOrderRejectTransaction {
 /// Specification of how Positions in the Account are modified when the
 /// Order is filled.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderPositionFill {
     #[serde(rename = "OPEN_ONLY")]
     OpenOnly,
@@ -22820,7 +23373,7 @@ impl std::fmt::Display for OrderPositionFill {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountProperties {
     /// The Account's associated MT4 Account ID. This field will not be
     /// present if the Account is not an MT4 account.
@@ -22879,7 +23432,7 @@ impl AccountProperties {
 
 /// The reason that the Stop Order was initiated
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StopOrderReason {
     #[serde(rename = "CLIENT_ORDER")]
     ClientOrder,
@@ -22904,7 +23457,7 @@ impl std::fmt::Display for StopOrderReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopOrderRejectTransaction {
     /// The time-in-force requested for the Stop Order.
     #[serde(default)]
@@ -22939,12 +23492,16 @@ pub struct StopOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The Stop Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -22964,7 +23521,7 @@ pub struct StopOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "STOP_ORDER_REJECT" in a
     /// StopOrderRejectTransaction.
@@ -22990,7 +23547,7 @@ pub struct StopOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -23177,8 +23734,8 @@ impl StopOrderRejectTransaction {
     /// Order is filled.
     /// - param String
     /// - return StopOrderRejectTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -23187,17 +23744,17 @@ impl StopOrderRejectTransaction {
     /// the Stop Order will be cancelled instead of being filled.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderRejectTransaction
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return StopOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -23217,9 +23774,9 @@ impl StopOrderRejectTransaction {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderRejectTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -23248,9 +23805,9 @@ impl StopOrderRejectTransaction {
     /// price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderRejectTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -23326,8 +23883,8 @@ impl StopOrderRejectTransaction {
     /// valid.
     /// - param String
     /// - return StopOrderRejectTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -23402,12 +23959,16 @@ impl StopOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarginCallEnterTransaction {
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The ID of the "batch" that the Transaction belongs to. Transactions in
     /// the same batch are applied to the Account simultaneously.
@@ -23469,9 +24030,9 @@ impl MarginCallEnterTransaction {
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarginCallEnterTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -23537,7 +24098,7 @@ impl MarginCallEnterTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketIfTouchedOrderRejectTransaction {
     /// The time-in-force requested for the MarketIfTouched Order. Restricted
     /// to "GTC", "GFD" and "GTD" for MarketIfTouched Orders.
@@ -23572,12 +24133,16 @@ pub struct MarketIfTouchedOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The MarketIfTouched Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -23597,7 +24162,7 @@ pub struct MarketIfTouchedOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to
     /// "MARKET_IF_TOUCHED_ORDER_REJECT" in a
@@ -23627,7 +24192,7 @@ pub struct MarketIfTouchedOrderRejectTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -23815,8 +24380,8 @@ impl MarketIfTouchedOrderRejectTransaction {
     /// Order is filled.
     /// - param String
     /// - return MarketIfTouchedOrderRejectTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -23824,17 +24389,17 @@ impl MarketIfTouchedOrderRejectTransaction {
     /// Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderRejectTransaction
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return MarketIfTouchedOrderRejectTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -23854,9 +24419,9 @@ impl MarketIfTouchedOrderRejectTransaction {
     /// number of units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderRejectTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -23889,9 +24454,9 @@ impl MarketIfTouchedOrderRejectTransaction {
     /// MarketIfTouchedOrder will behave like a Limit or a Stop Order.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return MarketIfTouchedOrderRejectTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -23967,8 +24532,8 @@ impl MarketIfTouchedOrderRejectTransaction {
     /// valid.
     /// - param String
     /// - return MarketIfTouchedOrderRejectTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -24043,7 +24608,7 @@ impl MarketIfTouchedOrderRejectTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnitsAvailableDetails {
     /// The units available for short Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -24054,7 +24619,7 @@ pub struct UnitsAvailableDetails {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub short: Option<f32>,
+    pub short: Option<f64>,
 
     /// The units available for long Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -24065,7 +24630,7 @@ pub struct UnitsAvailableDetails {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub long: Option<f32>,
+    pub long: Option<f64>,
 }
 impl UnitsAvailableDetails {
     pub fn new() -> UnitsAvailableDetails {
@@ -24078,9 +24643,9 @@ impl UnitsAvailableDetails {
     /// The units available for short Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return UnitsAvailableDetails
-    pub fn with_short(mut self, x: f32) -> Self {
+    pub fn with_short(mut self, x: f64) -> Self {
         self.short = Some(x);
         self
     }
@@ -24088,9 +24653,9 @@ impl UnitsAvailableDetails {
     /// The units available for long Orders.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return UnitsAvailableDetails
-    pub fn with_long(mut self, x: f32) -> Self {
+    pub fn with_long(mut self, x: f64) -> Self {
         self.long = Some(x);
         self
     }
@@ -24098,7 +24663,7 @@ impl UnitsAvailableDetails {
 
 /// The reason that a Transaction was rejected.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionRejectReason {
     #[serde(rename = "INTERNAL_SERVER_ERROR")]
     InternalServerError,
@@ -24742,7 +25307,7 @@ impl std::fmt::Display for TransactionRejectReason {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopOrderRequest {
     /// Specification of which price component should be used when determining
     /// if an Order should be triggered and filled. This allows Orders to be
@@ -24777,7 +25342,7 @@ pub struct StopOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The worst market price that may be used to fill this Stop Order. If
     /// the market gaps and crosses through both the price and the priceBound,
@@ -24790,7 +25355,7 @@ pub struct StopOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// TakeProfitDetails specifies the details of a Take Profit Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -24852,7 +25417,7 @@ pub struct StopOrderRequest {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -24930,8 +25495,8 @@ impl StopOrderRequest {
     /// valid.
     /// - param String
     /// - return StopOrderRequest
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -24940,9 +25505,9 @@ impl StopOrderRequest {
     /// price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderRequest
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -24952,9 +25517,9 @@ impl StopOrderRequest {
     /// the Stop Order will be cancelled instead of being filled.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderRequest
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
@@ -24978,6 +25543,14 @@ impl StopOrderRequest {
         self
     }
 
+    /// The time-in-force requested for the Order, as a typed `TimeInForce`.
+    /// - param TimeInForce
+    /// - return StopOrderRequest
+    pub fn with_time_in_force_typed(mut self, x: TimeInForce) -> Self {
+        self.time_in_force = Some(x.as_str().to_string());
+        self
+    }
+
     /// The Stop Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
     /// a "_".
@@ -25025,9 +25598,9 @@ impl StopOrderRequest {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderRequest
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -25067,17 +25640,25 @@ impl StopOrderRequest {
         self
     }
 
+    /// The type of the Order, as a typed `OrderType`.
+    /// - param OrderType
+    /// - return StopOrderRequest
+    pub fn with_type_typed(mut self, x: OrderType) -> Self {
+        self.otype = Some(x.as_str().to_string());
+        self
+    }
+
     /// Specification of how Positions in the Account are modified when the
     /// Order is filled.
     /// - param String
     /// - return StopOrderRequest
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserInfoExternal {
     /// The country that the user is based in.
     #[serde(default)]
@@ -25086,8 +25667,12 @@ pub struct UserInfoExternal {
 
     /// The user's OANDA-assigned user ID.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// Flag indicating if the the user's Accounts adhere to FIFO execution
     /// rules.
@@ -25113,9 +25698,9 @@ impl UserInfoExternal {
     }
 
     /// The user's OANDA-assigned user ID.
-    /// - param i32
+    /// - param i64
     /// - return UserInfoExternal
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -25130,7 +25715,7 @@ impl UserInfoExternal {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalculatedTradeState {
     /// The Trade's unrealized profit/loss.
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -25141,7 +25726,7 @@ pub struct CalculatedTradeState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub unrealized_pl: Option<f32>,
+    pub unrealized_pl: Option<f64>,
 
     /// The Trade's ID.
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -25160,7 +25745,7 @@ pub struct CalculatedTradeState {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub margin_used: Option<f32>,
+    pub margin_used: Option<f64>,
 }
 impl CalculatedTradeState {
     pub fn new() -> CalculatedTradeState {
@@ -25174,9 +25759,9 @@ impl CalculatedTradeState {
     /// The Trade's unrealized profit/loss.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedTradeState
-    pub fn with_unrealized_pl(mut self, x: f32) -> Self {
+    pub fn with_unrealized_pl(mut self, x: f64) -> Self {
         self.unrealized_pl = Some(x);
         self
     }
@@ -25195,15 +25780,15 @@ impl CalculatedTradeState {
     /// Margin currently used by the Trade.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return CalculatedTradeState
-    pub fn with_margin_used(mut self, x: f32) -> Self {
+    pub fn with_margin_used(mut self, x: f64) -> Self {
         self.margin_used = Some(x);
         self
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeReduce {
     /// The financing paid/collected when reducing the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -25214,7 +25799,7 @@ pub struct TradeReduce {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub financing: Option<f32>,
+    pub financing: Option<f64>,
 
     /// The ID of the Trade that was reduced or closed
     /// format: The string representation of the OANDA-assigned TradeID. OANDA-
@@ -25234,7 +25819,7 @@ pub struct TradeReduce {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub guaranteed_execution_fee: Option<f32>,
+    pub guaranteed_execution_fee: Option<f64>,
 
     /// The average price that the units were closed at. This price may be
     /// clamped for guaranteed Stop Loss Orders.
@@ -25246,7 +25831,7 @@ pub struct TradeReduce {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The PL realized when reducing the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -25257,7 +25842,7 @@ pub struct TradeReduce {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub realized_pl: Option<f32>,
+    pub realized_pl: Option<f64>,
 
     /// The number of units that the Trade was reduced by
     /// format: A decimal number encoded as a string. The amount of precision provided
@@ -25268,7 +25853,7 @@ pub struct TradeReduce {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The half spread cost for the trade reduce/close. This can be a
     /// positive or negative value and is represented in the home currency of
@@ -25281,7 +25866,7 @@ pub struct TradeReduce {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub half_spread_cost: Option<f32>,
+    pub half_spread_cost: Option<f64>,
 }
 impl TradeReduce {
     pub fn new() -> TradeReduce {
@@ -25299,9 +25884,9 @@ impl TradeReduce {
     /// The financing paid/collected when reducing the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeReduce
-    pub fn with_financing(mut self, x: f32) -> Self {
+    pub fn with_financing(mut self, x: f64) -> Self {
         self.financing = Some(x);
         self
     }
@@ -25321,9 +25906,9 @@ impl TradeReduce {
     /// guaranteed Stop Loss Order attached to it.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeReduce
-    pub fn with_guaranteed_execution_fee(mut self, x: f32) -> Self {
+    pub fn with_guaranteed_execution_fee(mut self, x: f64) -> Self {
         self.guaranteed_execution_fee = Some(x);
         self
     }
@@ -25332,9 +25917,9 @@ impl TradeReduce {
     /// clamped for guaranteed Stop Loss Orders.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return TradeReduce
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -25342,9 +25927,9 @@ impl TradeReduce {
     /// The PL realized when reducing the Trade
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeReduce
-    pub fn with_realized_pl(mut self, x: f32) -> Self {
+    pub fn with_realized_pl(mut self, x: f64) -> Self {
         self.realized_pl = Some(x);
         self
     }
@@ -25352,9 +25937,9 @@ impl TradeReduce {
     /// The number of units that the Trade was reduced by
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return TradeReduce
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -25364,9 +25949,9 @@ impl TradeReduce {
     /// the Account.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on the Account's home currency.
-    /// - param f32
+    /// - param f64
     /// - return TradeReduce
-    pub fn with_half_spread_cost(mut self, x: f32) -> Self {
+    pub fn with_half_spread_cost(mut self, x: f64) -> Self {
         self.half_spread_cost = Some(x);
         self
     }
@@ -25391,7 +25976,7 @@ impl TradeReduce {
 /// "DEFAULT" and "BID", and for short trades "DEFAULT" and "ASK" are
 /// valid.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderTriggerCondition {
     #[serde(rename = "DEFAULT")]
     Default,
@@ -25429,7 +26014,7 @@ impl std::fmt::Display for OrderTriggerCondition {
 /// should remain pending before being automatically cancelled by the
 /// execution system.
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TimeInForce {
     #[serde(rename = "GTC")]
     Gtc,
@@ -25463,7 +26048,7 @@ impl std::fmt::Display for TimeInForce {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientPrice {
     /// The status of the Price.
     #[serde(default)]
@@ -25504,7 +26089,7 @@ pub struct ClientPrice {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub closeout_bid: Option<f32>,
+    pub closeout_bid: Option<f64>,
 
     /// The list of prices and liquidity available on the Instrument's bid
     /// side. It is possible for this list to be empty if there is no bid
@@ -25546,7 +26131,7 @@ pub struct ClientPrice {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub closeout_ask: Option<f32>,
+    pub closeout_ask: Option<f64>,
 
     /// The string "PRICE". Used to identify the a Price object when found in
     /// a stream.
@@ -25618,9 +26203,9 @@ impl ClientPrice {
     /// liquidity. The closeout bid is never used to open a new position.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return ClientPrice
-    pub fn with_closeout_bid(mut self, x: f32) -> Self {
+    pub fn with_closeout_bid(mut self, x: f64) -> Self {
         self.closeout_bid = Some(x);
         self
     }
@@ -25664,9 +26249,9 @@ impl ClientPrice {
     /// liquidity. The closeout ask is never used to open a new position.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return ClientPrice
-    pub fn with_closeout_ask(mut self, x: f32) -> Self {
+    pub fn with_closeout_ask(mut self, x: f64) -> Self {
         self.closeout_ask = Some(x);
         self
     }
@@ -25689,7 +26274,7 @@ impl ClientPrice {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LimitOrderTransaction {
     /// The time-in-force requested for the Limit Order.
     #[serde(default)]
@@ -25715,8 +26300,12 @@ pub struct LimitOrderTransaction {
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The Limit Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -25736,7 +26325,7 @@ pub struct LimitOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "LIMIT_ORDER" in a
     /// LimitOrderTransaction.
@@ -25762,7 +26351,7 @@ pub struct LimitOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -25950,15 +26539,15 @@ impl LimitOrderTransaction {
     /// Order is filled.
     /// - param String
     /// - return LimitOrderTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return LimitOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -25978,9 +26567,9 @@ impl LimitOrderTransaction {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrderTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -26009,9 +26598,9 @@ impl LimitOrderTransaction {
     /// this price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return LimitOrderTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -26087,8 +26676,8 @@ impl LimitOrderTransaction {
     /// valid.
     /// - param String
     /// - return LimitOrderTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -26165,7 +26754,7 @@ impl LimitOrderTransaction {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionBook {
     /// The partitioned position book, divided into buckets using a default
     /// bucket width. These buckets are only provided for price ranges which
@@ -26191,7 +26780,7 @@ pub struct PositionBook {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// The price width for each bucket. Each bucket covers the price range
     /// from the bucket's price to the bucket's price + bucketWidth.
@@ -26203,7 +26792,7 @@ pub struct PositionBook {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub bucket_width: Option<f32>,
+    pub bucket_width: Option<f64>,
 
     /// The time when the position book snapshot was created
     /// format: The RFC 3339 representation is a string conforming to
@@ -26255,9 +26844,9 @@ impl PositionBook {
     /// the position book snapshot
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return PositionBook
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -26266,9 +26855,9 @@ impl PositionBook {
     /// from the bucket's price to the bucket's price + bucketWidth.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return PositionBook
-    pub fn with_bucket_width(mut self, x: f32) -> Self {
+    pub fn with_bucket_width(mut self, x: f64) -> Self {
         self.bucket_width = Some(x);
         self
     }
@@ -26288,7 +26877,7 @@ impl PositionBook {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StopOrderTransaction {
     /// The time-in-force requested for the Stop Order.
     #[serde(default)]
@@ -26323,12 +26912,16 @@ pub struct StopOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price_bound: Option<f32>,
+    pub price_bound: Option<f64>,
 
     /// The ID of the user that initiated the creation of the Transaction.
     #[serde(default)]
-    #[serde(rename = "userID", skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<i32>,
+    #[serde(
+        rename = "userID",
+        skip_serializing_if = "Option::is_none",
+        with = "serints64"
+    )]
+    pub user_id: Option<i64>,
 
     /// The Stop Order's Instrument.
     /// format: A string containing the base currency and quote currency delimited by
@@ -26348,7 +26941,7 @@ pub struct StopOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub units: Option<f32>,
+    pub units: Option<f64>,
 
     /// The Type of the Transaction. Always set to "STOP_ORDER" in a
     /// StopOrderTransaction.
@@ -26374,7 +26967,7 @@ pub struct StopOrderTransaction {
         skip_serializing_if = "Option::is_none",
         with = "serfloats"
     )]
-    pub price: Option<f32>,
+    pub price: Option<f64>,
 
     /// StopLossDetails specifies the details of a Stop Loss Order to be
     /// created on behalf of a client. This may happen when an Order is filled
@@ -26563,8 +27156,8 @@ impl StopOrderTransaction {
     /// Order is filled.
     /// - param String
     /// - return StopOrderTransaction
-    pub fn with_position_fill(mut self, x: String) -> Self {
-        self.position_fill = Some(x);
+    pub fn with_position_fill(mut self, x: impl Into<String>) -> Self {
+        self.position_fill = Some(x.into());
         self
     }
 
@@ -26573,17 +27166,17 @@ impl StopOrderTransaction {
     /// the Stop Order will be cancelled instead of being filled.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderTransaction
-    pub fn with_price_bound(mut self, x: f32) -> Self {
+    pub fn with_price_bound(mut self, x: f64) -> Self {
         self.price_bound = Some(x);
         self
     }
 
     /// The ID of the user that initiated the creation of the Transaction.
-    /// - param i32
+    /// - param i64
     /// - return StopOrderTransaction
-    pub fn with_user_id(mut self, x: i32) -> Self {
+    pub fn with_user_id(mut self, x: i64) -> Self {
         self.user_id = Some(x);
         self
     }
@@ -26603,9 +27196,9 @@ impl StopOrderTransaction {
     /// units results in a short Order.
     /// format: A decimal number encoded as a string. The amount of precision provided
     /// depends on what the number represents.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderTransaction
-    pub fn with_units(mut self, x: f32) -> Self {
+    pub fn with_units(mut self, x: f64) -> Self {
         self.units = Some(x);
         self
     }
@@ -26634,9 +27227,9 @@ impl StopOrderTransaction {
     /// price.
     /// format: A decimal number encodes as a string. The amount of precision provided
     /// depends on the Instrument.
-    /// - param f32
+    /// - param f64
     /// - return StopOrderTransaction
-    pub fn with_price(mut self, x: f32) -> Self {
+    pub fn with_price(mut self, x: f64) -> Self {
         self.price = Some(x);
         self
     }
@@ -26712,8 +27305,8 @@ impl StopOrderTransaction {
     /// valid.
     /// - param String
     /// - return StopOrderTransaction
-    pub fn with_trigger_condition(mut self, x: String) -> Self {
-        self.trigger_condition = Some(x);
+    pub fn with_trigger_condition(mut self, x: impl Into<String>) -> Self {
+        self.trigger_condition = Some(x.into());
         self
     }
 
@@ -0,0 +1,128 @@
+use crate::{LimitOrder, MarketOrder, OrderPositionFill, OrderTriggerCondition, OrderType, StopOrder, TimeInForce};
+
+/// Which price an order's trigger is compared against. See [`OrderTriggerCondition`].
+pub type TriggerCondition = OrderTriggerCondition;
+
+impl OrderTriggerCondition {
+    /// The value OANDA expects in an order's `triggerCondition`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderTriggerCondition::Default => "DEFAULT",
+            OrderTriggerCondition::Inverse => "INVERSE",
+            OrderTriggerCondition::Bid => "BID",
+            OrderTriggerCondition::Ask => "ASK",
+            OrderTriggerCondition::Mid => "MID",
+        }
+    }
+}
+
+/// Lets the typed condition be passed to the `with_trigger_condition` builders.
+impl From<OrderTriggerCondition> for String {
+    fn from(condition: OrderTriggerCondition) -> Self {
+        condition.as_str().to_string()
+    }
+}
+
+/// How an order's fill changes the account's positions. See [`OrderPositionFill`].
+/// `ReduceOnly` orders can close exposure but never open it.
+pub type PositionFill = OrderPositionFill;
+
+impl OrderPositionFill {
+    /// The value OANDA expects in an order's `positionFill`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderPositionFill::OpenOnly => "OPEN_ONLY",
+            OrderPositionFill::ReduceFirst => "REDUCE_FIRST",
+            OrderPositionFill::ReduceOnly => "REDUCE_ONLY",
+            OrderPositionFill::Default => "DEFAULT",
+        }
+    }
+}
+
+/// Lets the typed fill be passed to the `with_position_fill` builders.
+impl From<OrderPositionFill> for String {
+    fn from(fill: OrderPositionFill) -> Self {
+        fill.as_str().to_string()
+    }
+}
+
+impl TimeInForce {
+    /// The value OANDA expects in an order's `timeInForce`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeInForce::Gtc => "GTC",
+            TimeInForce::Gtd => "GTD",
+            TimeInForce::Gfd => "GFD",
+            TimeInForce::Fok => "FOK",
+            TimeInForce::Ioc => "IOC",
+        }
+    }
+}
+
+/// The string form stored in an order's `time_in_force`.
+impl From<TimeInForce> for String {
+    fn from(time_in_force: TimeInForce) -> Self {
+        time_in_force.as_str().to_string()
+    }
+}
+
+impl OrderType {
+    /// The value OANDA expects in an order's `type`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Market => "MARKET",
+            OrderType::Limit => "LIMIT",
+            OrderType::Stop => "STOP",
+            OrderType::MarketIfTouched => "MARKET_IF_TOUCHED",
+            OrderType::TakeProfit => "TAKE_PROFIT",
+            OrderType::StopLoss => "STOP_LOSS",
+            OrderType::TrailingStopLoss => "TRAILING_STOP_LOSS",
+            OrderType::FixedPrice => "FIXED_PRICE",
+        }
+    }
+}
+
+/// The string form stored in an order's `otype`.
+impl From<OrderType> for String {
+    fn from(order_type: OrderType) -> Self {
+        order_type.as_str().to_string()
+    }
+}
+
+impl MarketOrder {
+    /// A market order for `units` of `instrument` (negative to sell), filled in full
+    /// or cancelled (`FOK`), OANDA's default for market orders.
+    pub fn market(instrument: impl Into<String>, units: f64) -> Self {
+        MarketOrder::new()
+            .with_type_typed(OrderType::Market)
+            .with_instrument(instrument.into())
+            .with_units(units)
+            .with_time_in_force_typed(TimeInForce::Fok)
+    }
+}
+
+impl LimitOrder {
+    /// A limit order for `units` of `instrument` (negative to sell) at `price` or
+    /// better, standing until cancelled (`GTC`).
+    pub fn limit(instrument: impl Into<String>, units: f64, price: f64) -> Self {
+        LimitOrder::new()
+            .with_type_typed(OrderType::Limit)
+            .with_instrument(instrument.into())
+            .with_units(units)
+            .with_price(price)
+            .with_time_in_force_typed(TimeInForce::Gtc)
+    }
+}
+
+impl StopOrder {
+    /// A stop order for `units` of `instrument` (negative to sell), filled once the
+    /// market reaches `price`, standing until cancelled (`GTC`).
+    pub fn stop(instrument: impl Into<String>, units: f64, price: f64) -> Self {
+        StopOrder::new()
+            .with_type_typed(OrderType::Stop)
+            .with_instrument(instrument.into())
+            .with_units(units)
+            .with_price(price)
+            .with_time_in_force_typed(TimeInForce::Gtc)
+    }
+}
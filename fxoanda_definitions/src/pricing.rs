@@ -0,0 +1,47 @@
+use crate::{ClientPrice, PriceBucket};
+
+impl ClientPrice {
+    /// The best bid, from the first bid bucket. `None` if no bids are quoted.
+    pub fn best_bid(&self) -> Option<f64> {
+        first_price(&self.bids)
+    }
+
+    /// The best ask, from the first ask bucket. `None` if no asks are quoted.
+    pub fn best_ask(&self) -> Option<f64> {
+        first_price(&self.asks)
+    }
+
+    /// The best ask less the best bid; `None` unless both sides are quoted.
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()? - self.best_bid()?)
+    }
+
+    /// Halfway between the best bid and the best ask; `None` unless both sides are quoted.
+    pub fn mid(&self) -> Option<f64> {
+        Some((self.best_bid()? + self.best_ask()?) / 2.0)
+    }
+
+    /// The value in the account's home currency of buying (`is_long`) or selling
+    /// `units` of the instrument at this price: positive for a buy, negative for a
+    /// sell.
+    ///
+    /// The units are priced at the best ask for a buy and the best bid for a sell,
+    /// giving an amount of the quote currency, which is converted with the
+    /// `quoteHomeConversionFactors` for a positive or negative amount respectively.
+    /// The factors differ by the conversion spread. `None` without that side's
+    /// price or conversion factor.
+    pub fn home_conversion(&self, units: f64, is_long: bool) -> Option<f64> {
+        let factors = self.quote_home_conversion_factors.as_ref()?;
+        let quote_amount = if is_long {
+            units.abs() * self.best_ask()?
+        } else {
+            -units.abs() * self.best_bid()?
+        };
+        let factor = if is_long { factors.positive_units? } else { factors.negative_units? };
+        Some(quote_amount * factor)
+    }
+}
+
+fn first_price(buckets: &Option<Vec<PriceBucket>>) -> Option<f64> {
+    buckets.as_ref()?.first()?.price
+}
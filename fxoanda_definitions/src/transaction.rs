@@ -0,0 +1,113 @@
+use chrono::prelude::*;
+use crate::{OrderFillTransaction, Transaction, TransactionType};
+use std::str::FromStr;
+
+impl OrderFillTransaction {
+    /// When the quote the order filled against was generated, from `fullPrice`.
+    /// This is distinct from `time`, when OANDA recorded the fill.
+    pub fn quote_time(&self) -> Option<DateTime<Utc>> {
+        self.full_price.as_ref()?.time
+    }
+
+    /// How old the quote was when the fill was recorded: `time` less `quote_time`.
+    /// `None` unless both timestamps are present.
+    pub fn quote_age(&self) -> Option<chrono::Duration> {
+        Some(self.time? - self.quote_time()?)
+    }
+}
+
+/// A [`Transaction`] told apart by its `type`, for matching on the kind of
+/// transaction instead of comparing type strings. Each variant borrows the
+/// transaction it came from.
+///
+/// `Transaction` holds only the fields every transaction shares; deserialize the
+/// raw JSON into the typed struct, such as [`OrderFillTransaction`], for the rest.
+#[derive(Debug, Clone, Copy)]
+pub enum TransactionKind<'a> {
+    Create(&'a Transaction),
+    Close(&'a Transaction),
+    Reopen(&'a Transaction),
+    ClientConfigure(&'a Transaction),
+    ClientConfigureReject(&'a Transaction),
+    TransferFunds(&'a Transaction),
+    TransferFundsReject(&'a Transaction),
+    MarketOrder(&'a Transaction),
+    MarketOrderReject(&'a Transaction),
+    FixedPriceOrder(&'a Transaction),
+    LimitOrder(&'a Transaction),
+    LimitOrderReject(&'a Transaction),
+    StopOrder(&'a Transaction),
+    StopOrderReject(&'a Transaction),
+    MarketIfTouchedOrder(&'a Transaction),
+    MarketIfTouchedOrderReject(&'a Transaction),
+    TakeProfitOrder(&'a Transaction),
+    TakeProfitOrderReject(&'a Transaction),
+    StopLossOrder(&'a Transaction),
+    StopLossOrderReject(&'a Transaction),
+    TrailingStopLossOrder(&'a Transaction),
+    TrailingStopLossOrderReject(&'a Transaction),
+    OrderFill(&'a Transaction),
+    OrderCancel(&'a Transaction),
+    OrderCancelReject(&'a Transaction),
+    OrderClientExtensionsModify(&'a Transaction),
+    OrderClientExtensionsModifyReject(&'a Transaction),
+    TradeClientExtensionsModify(&'a Transaction),
+    TradeClientExtensionsModifyReject(&'a Transaction),
+    MarginCallEnter(&'a Transaction),
+    MarginCallExtend(&'a Transaction),
+    MarginCallExit(&'a Transaction),
+    DelayedTradeClosure(&'a Transaction),
+    DailyFinancing(&'a Transaction),
+    ResetResettablePl(&'a Transaction),
+    /// A type this crate doesn't know, such as `DIVIDEND_ADJUSTMENT`, or no type at all.
+    Other(&'a Transaction),
+}
+
+impl Transaction {
+    /// The transaction's `type`, or `None` if it's missing or not one this crate knows.
+    pub fn transaction_type(&self) -> Option<TransactionType> {
+        TransactionType::from_str(self.otype.as_deref()?).ok()
+    }
+
+    /// The transaction as a [`TransactionKind`], chosen by its `type`.
+    pub fn as_kind(&self) -> TransactionKind<'_> {
+        match self.transaction_type() {
+            Some(TransactionType::Create) => TransactionKind::Create(self),
+            Some(TransactionType::Close) => TransactionKind::Close(self),
+            Some(TransactionType::Reopen) => TransactionKind::Reopen(self),
+            Some(TransactionType::ClientConfigure) => TransactionKind::ClientConfigure(self),
+            Some(TransactionType::ClientConfigureReject) => TransactionKind::ClientConfigureReject(self),
+            Some(TransactionType::TransferFunds) => TransactionKind::TransferFunds(self),
+            Some(TransactionType::TransferFundsReject) => TransactionKind::TransferFundsReject(self),
+            Some(TransactionType::MarketOrder) => TransactionKind::MarketOrder(self),
+            Some(TransactionType::MarketOrderReject) => TransactionKind::MarketOrderReject(self),
+            Some(TransactionType::FixedPriceOrder) => TransactionKind::FixedPriceOrder(self),
+            Some(TransactionType::LimitOrder) => TransactionKind::LimitOrder(self),
+            Some(TransactionType::LimitOrderReject) => TransactionKind::LimitOrderReject(self),
+            Some(TransactionType::StopOrder) => TransactionKind::StopOrder(self),
+            Some(TransactionType::StopOrderReject) => TransactionKind::StopOrderReject(self),
+            Some(TransactionType::MarketIfTouchedOrder) => TransactionKind::MarketIfTouchedOrder(self),
+            Some(TransactionType::MarketIfTouchedOrderReject) => TransactionKind::MarketIfTouchedOrderReject(self),
+            Some(TransactionType::TakeProfitOrder) => TransactionKind::TakeProfitOrder(self),
+            Some(TransactionType::TakeProfitOrderReject) => TransactionKind::TakeProfitOrderReject(self),
+            Some(TransactionType::StopLossOrder) => TransactionKind::StopLossOrder(self),
+            Some(TransactionType::StopLossOrderReject) => TransactionKind::StopLossOrderReject(self),
+            Some(TransactionType::TrailingStopLossOrder) => TransactionKind::TrailingStopLossOrder(self),
+            Some(TransactionType::TrailingStopLossOrderReject) => TransactionKind::TrailingStopLossOrderReject(self),
+            Some(TransactionType::OrderFill) => TransactionKind::OrderFill(self),
+            Some(TransactionType::OrderCancel) => TransactionKind::OrderCancel(self),
+            Some(TransactionType::OrderCancelReject) => TransactionKind::OrderCancelReject(self),
+            Some(TransactionType::OrderClientExtensionsModify) => TransactionKind::OrderClientExtensionsModify(self),
+            Some(TransactionType::OrderClientExtensionsModifyReject) => TransactionKind::OrderClientExtensionsModifyReject(self),
+            Some(TransactionType::TradeClientExtensionsModify) => TransactionKind::TradeClientExtensionsModify(self),
+            Some(TransactionType::TradeClientExtensionsModifyReject) => TransactionKind::TradeClientExtensionsModifyReject(self),
+            Some(TransactionType::MarginCallEnter) => TransactionKind::MarginCallEnter(self),
+            Some(TransactionType::MarginCallExtend) => TransactionKind::MarginCallExtend(self),
+            Some(TransactionType::MarginCallExit) => TransactionKind::MarginCallExit(self),
+            Some(TransactionType::DelayedTradeClosure) => TransactionKind::DelayedTradeClosure(self),
+            Some(TransactionType::DailyFinancing) => TransactionKind::DailyFinancing(self),
+            Some(TransactionType::ResetResettablePl) => TransactionKind::ResetResettablePl(self),
+            None => TransactionKind::Other(self),
+        }
+    }
+}
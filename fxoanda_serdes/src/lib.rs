@@ -1,7 +1,12 @@
 
 pub mod serfloats;
 pub mod serdates;
+pub mod serdates_unix;
 pub mod serints;
+pub mod serints64;
+pub mod sercsv;
+#[cfg(feature = "decimal")]
+pub mod serdecimal;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize an Option<Vec<String>> as a single comma-separated string (if Some), or None.
+/// OANDA takes multi-valued query parameters in this form, and url-encoded
+/// query strings can't represent a sequence directly.
+pub fn serialize<S>(value: &Option<Vec<String>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(ref v) = *value {
+        serializer.collect_str(&v.join(","))
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserialize an Option<Vec<String>> from a comma-separated string or a list.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ListOrString {
+        List(Vec<String>),
+        Str(String),
+    }
+
+    let opt = Option::<ListOrString>::deserialize(deserializer)?;
+    match opt {
+        None => Ok(None),
+        Some(ListOrString::List(v)) => Ok(Some(v)),
+        Some(ListOrString::Str(s)) => Ok(Some(s.split(',').map(String::from).collect())),
+    }
+}
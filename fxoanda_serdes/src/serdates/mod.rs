@@ -10,20 +10,34 @@ where
     serializer.serialize_none()
 }
 
+/// Deserialize an Option<DateTime<Utc>> from either format OANDA sends, depending
+/// on the request's `Accept-Datetime-Format`: RFC3339, or UNIX seconds with a
+/// fractional part (e.g. `"1516240000.000000000"`). `"0"` is None.
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s: Option<String> = Option::deserialize(deserializer)?;
-    if let Some(s) = s {
-        if s == "0" {
-            return Ok(None);
-        }else{
-            let d = DateTime::parse_from_rfc3339(&s).unwrap().with_timezone(&Utc);
-            return Ok(Some(d));
-            //return Ok(Some(Utc::now()));
-        }
+    match s.as_deref() {
+        None | Some("0") => Ok(None),
+        Some(s) => parse(s).map(Some).ok_or_else(|| serde::de::Error::custom(format!("invalid datetime: {}", s))),
     }
-	Ok(None)
 }
 
+fn parse(s: &str) -> Option<DateTime<Utc>> {
+    if s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        parse_unix(s)
+    } else {
+        DateTime::parse_from_rfc3339(s).ok().map(|d| d.with_timezone(&Utc))
+    }
+}
+
+/// Parses UNIX seconds with up to nine fractional digits, keeping every digit.
+pub(crate) fn parse_unix(s: &str) -> Option<DateTime<Utc>> {
+    let (seconds, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = if fraction.is_empty() { 0 } else { fraction.parse::<u32>().ok()? * 10u32.pow(9 - fraction.len() as u32) };
+    DateTime::from_timestamp(seconds.parse().ok()?, nanos)
+}
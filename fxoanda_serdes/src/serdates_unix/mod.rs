@@ -0,0 +1,25 @@
+use serde::{Deserialize, Deserializer, Serializer};
+use chrono::prelude::*;
+
+/// Serialize an Option<DateTime<Utc>> as OANDA's UNIX format: seconds since the
+/// epoch with nine fractional digits (e.g. `"1516240000.000000000"`), or None.
+pub fn serialize<S>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(ref v) = *value {
+        serializer.collect_str(&format!("{}.{:09}", v.timestamp(), v.timestamp_subsec_nanos()))
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserialize an Option<DateTime<Utc>> from OANDA's UNIX format.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|s| crate::serdates::parse_unix(&s).ok_or_else(|| serde::de::Error::custom(format!("invalid UNIX datetime: {}", s))))
+        .transpose()
+}
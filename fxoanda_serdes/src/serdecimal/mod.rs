@@ -0,0 +1,25 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serializer};
+use std::str::FromStr;
+
+/// Serialize an Option<Decimal> as a string (if Some), or None.
+pub fn serialize<S>(value: &Option<Decimal>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(ref v) = *value {
+        serializer.collect_str(v)
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserialize an Option<Decimal> from OANDA's string-encoded numbers, parsing the
+/// digits directly so no precision is lost to a binary float on the way.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|s| Decimal::from_str(&s).map_err(serde::de::Error::custom)).transpose()
+}
@@ -5,7 +5,7 @@
 //use serde::{de, Deserialize, Deserializer, Serializer};
 use serde::{Deserialize, Deserializer, Serializer};
 
-pub fn serialize<S>(value: &Option<f32>, serializer: S) -> Result<S::Ok, S::Error>
+pub fn serialize<S>(value: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
@@ -15,13 +15,13 @@ where
     serializer.serialize_none()
 }
 
-pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
 where
     D: Deserializer<'de>,
 {
     let s: Option<String> = Option::deserialize(deserializer)?;
     if let Some(s) = s {
-        return Ok(Some(s.parse::<f32>().map_err(serde::de::Error::custom)?))
+        return Ok(Some(s.parse::<f64>().map_err(serde::de::Error::custom)?))
     }
 		Ok(None)
 }
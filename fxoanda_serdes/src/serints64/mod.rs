@@ -0,0 +1,34 @@
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize an Option<i64> as an integer (if Some), or None.
+pub fn serialize<S>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if let Some(v) = *value {
+        serializer.serialize_i64(v)
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserialize an Option<i64> from a string or integer in JSON, for IDs that
+/// outgrow an i32 on long-lived accounts.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt {
+        Int(i64),
+        Str(String),
+    }
+
+    let opt = Option::<StringOrInt>::deserialize(deserializer)?;
+    match opt {
+        None => Ok(None),
+        Some(StringOrInt::Int(n)) => Ok(Some(n)),
+        Some(StringOrInt::Str(s)) => s.parse::<i64>().map(Some).map_err(serde::de::Error::custom),
+    }
+}
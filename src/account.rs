@@ -8,7 +8,7 @@ pub mod list_positions{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -22,7 +22,7 @@ pub mod list_positions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -36,7 +36,7 @@ pub mod list_positions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -46,7 +46,7 @@ pub mod list_positions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -62,7 +62,7 @@ pub mod list_positions{
 /// every instrument that has had a position during the lifetime of an the
 /// Account.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListPositionsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -70,6 +70,8 @@ pub mod list_positions{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListPositionsRequest {
@@ -80,6 +82,7 @@ pub mod list_positions{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -93,8 +96,8 @@ pub mod list_positions{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListPositionsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -107,6 +110,13 @@ pub mod list_positions{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListPositionsResponse, FxError> {
 	    // Validate required path parameters
@@ -114,36 +124,7 @@ pub mod list_positions{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ListPositionsResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, None, self.timeout).await
 		}
 	}
 
@@ -185,7 +166,7 @@ pub mod list_open_positions{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -199,7 +180,7 @@ pub mod list_open_positions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -213,7 +194,7 @@ pub mod list_open_positions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -223,7 +204,7 @@ pub mod list_open_positions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -238,7 +219,7 @@ pub mod list_open_positions{
 /// List all open Positions for an Account. An open Position is a Position
 /// in an Account that currently has a Trade opened for it.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListOpenPositionsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -246,6 +227,8 @@ pub mod list_open_positions{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListOpenPositionsRequest {
@@ -256,6 +239,7 @@ pub mod list_open_positions{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -269,8 +253,8 @@ pub mod list_open_positions{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListOpenPositionsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -283,6 +267,13 @@ pub mod list_open_positions{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListOpenPositionsResponse, FxError> {
 	    // Validate required path parameters
@@ -290,36 +281,7 @@ pub mod list_open_positions{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ListOpenPositionsResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, None, self.timeout).await
 		}
 	}
 
@@ -361,7 +323,7 @@ pub mod get_position{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -375,7 +337,7 @@ pub mod get_position{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -393,7 +355,7 @@ pub mod get_position{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -403,7 +365,7 @@ pub mod get_position{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -418,7 +380,7 @@ pub mod get_position{
 /// Get the details of a single Instrument's Position in an Account. The
 /// Position may by open or not.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetPositionRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -426,6 +388,8 @@ pub mod get_position{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetPositionRequest {
@@ -436,6 +400,7 @@ pub mod get_position{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -449,8 +414,8 @@ pub mod get_position{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetPositionRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -459,8 +424,8 @@ pub mod get_position{
 /// a "_".
   /// - param String
 	  /// - return GetPositionRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.path.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -473,6 +438,13 @@ pub mod get_position{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetPositionResponse, FxError> {
 	    // Validate required path parameters
@@ -482,36 +454,7 @@ pub mod get_position{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{instrument}", &instrument_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetPositionResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, None, self.timeout).await
 		}
 	}
 
@@ -553,7 +496,7 @@ pub mod close_position{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -571,7 +514,7 @@ pub mod close_position{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -589,7 +532,7 @@ pub mod close_position{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="longUnits", skip_serializing_if="Option::is_none" )]
@@ -615,7 +558,7 @@ pub mod close_position{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -629,7 +572,7 @@ pub mod close_position{
 	/// Close Position
 /// Closeout the open Position for a specific instrument in an Account.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ClosePositionRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -637,6 +580,8 @@ pub mod close_position{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ClosePositionRequest {
@@ -647,6 +592,7 @@ pub mod close_position{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -660,8 +606,8 @@ pub mod close_position{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ClosePositionRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -670,8 +616,8 @@ pub mod close_position{
 /// a "_".
   /// - param String
 	  /// - return ClosePositionRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.path.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -734,6 +680,13 @@ pub mod close_position{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ClosePositionResponse, FxError> {
 	    // Validate required path parameters
@@ -743,36 +696,7 @@ pub mod close_position{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{instrument}", &instrument_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ClosePositionResponse>().await.map_err(FxError::from)
+	    crate::client::execute_put(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -850,7 +774,7 @@ pub mod list_trades{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -868,7 +792,7 @@ pub mod list_trades{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -882,7 +806,7 @@ pub mod list_trades{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -892,7 +816,7 @@ pub mod list_trades{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="ids", skip_serializing_if="Option::is_none" )]
@@ -926,7 +850,7 @@ pub mod list_trades{
 	/// List Trades
 /// Get a list of Trades for an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListTradesRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -934,6 +858,8 @@ pub mod list_trades{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListTradesRequest {
@@ -944,6 +870,7 @@ pub mod list_trades{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -957,8 +884,8 @@ pub mod list_trades{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListTradesRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -1000,8 +927,8 @@ pub mod list_trades{
 /// a "_".
   /// - param String
 	  /// - return ListTradesRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.query.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.query.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -1025,6 +952,13 @@ pub mod list_trades{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListTradesResponse, FxError> {
 	    // Validate required path parameters
@@ -1032,36 +966,7 @@ pub mod list_trades{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ListTradesResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -1106,7 +1011,7 @@ pub mod list_open_trades{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -1124,7 +1029,7 @@ pub mod list_open_trades{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -1138,7 +1043,7 @@ pub mod list_open_trades{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -1148,7 +1053,7 @@ pub mod list_open_trades{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -1162,7 +1067,7 @@ pub mod list_open_trades{
 	/// List Open Trades
 /// Get the list of open Trades for an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListOpenTradesRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -1170,6 +1075,8 @@ pub mod list_open_trades{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListOpenTradesRequest {
@@ -1180,6 +1087,7 @@ pub mod list_open_trades{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -1193,8 +1101,8 @@ pub mod list_open_trades{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListOpenTradesRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -1215,6 +1123,13 @@ pub mod list_open_trades{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListOpenTradesResponse, FxError> {
 	    // Validate required path parameters
@@ -1222,36 +1137,7 @@ pub mod list_open_trades{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ListOpenTradesResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -1293,7 +1179,7 @@ pub mod get_trade{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -1311,7 +1197,7 @@ pub mod get_trade{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -1329,7 +1215,7 @@ pub mod get_trade{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -1339,7 +1225,7 @@ pub mod get_trade{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -1353,7 +1239,7 @@ pub mod get_trade{
 	/// Trade Details
 /// Get the details of a specific Trade in an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetTradeRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -1361,6 +1247,8 @@ pub mod get_trade{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetTradeRequest {
@@ -1371,6 +1259,7 @@ pub mod get_trade{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -1384,8 +1273,8 @@ pub mod get_trade{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetTradeRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -1416,6 +1305,13 @@ pub mod get_trade{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetTradeResponse, FxError> {
 	    // Validate required path parameters
@@ -1425,36 +1321,7 @@ pub mod get_trade{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{tradeSpecifier}", &trade_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetTradeResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -1498,7 +1365,7 @@ pub mod close_trade{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -1516,7 +1383,7 @@ pub mod close_trade{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -1534,7 +1401,7 @@ pub mod close_trade{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="units", skip_serializing_if="Option::is_none" )]
@@ -1548,7 +1415,7 @@ pub mod close_trade{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -1561,8 +1428,13 @@ pub mod close_trade{
 
 	/// Close Trade
 /// Close (partially or fully) a specific open Trade in an Account
+///
+/// The close takes only `units`, so the MarketOrder that closes the Trade
+/// can't be tagged with client extensions. Tag the Trade with
+/// `SetTradeClientExtensionsRequest` before closing it, or the closing
+/// order with `SetOrderClientExtensionsRequest` afterwards.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct CloseTradeRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -1570,6 +1442,8 @@ pub mod close_trade{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl CloseTradeRequest {
@@ -1580,6 +1454,7 @@ pub mod close_trade{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -1593,8 +1468,8 @@ pub mod close_trade{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return CloseTradeRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -1638,6 +1513,13 @@ pub mod close_trade{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<CloseTradeResponse, FxError> {
 	    // Validate required path parameters
@@ -1647,36 +1529,7 @@ pub mod close_trade{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{tradeSpecifier}", &trade_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<CloseTradeResponse>().await.map_err(FxError::from)
+	    crate::client::execute_put(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -1735,7 +1588,7 @@ pub mod set_trade_client_extensions{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -1753,7 +1606,7 @@ pub mod set_trade_client_extensions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -1771,7 +1624,7 @@ pub mod set_trade_client_extensions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="clientExtensions", skip_serializing_if="Option::is_none" )]
@@ -1785,7 +1638,7 @@ pub mod set_trade_client_extensions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -1800,7 +1653,7 @@ pub mod set_trade_client_extensions{
 /// Update the Client Extensions for a Trade. Do not add, update, or
 /// delete the Client Extensions if your account is associated with MT4.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct SetTradeClientExtensionsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -1808,6 +1661,8 @@ pub mod set_trade_client_extensions{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl SetTradeClientExtensionsRequest {
@@ -1818,6 +1673,7 @@ pub mod set_trade_client_extensions{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -1831,8 +1687,8 @@ pub mod set_trade_client_extensions{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return SetTradeClientExtensionsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -1873,6 +1729,13 @@ pub mod set_trade_client_extensions{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<SetTradeClientExtensionsResponse, FxError> {
 	    // Validate required path parameters
@@ -1882,36 +1745,7 @@ pub mod set_trade_client_extensions{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{tradeSpecifier}", &trade_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<SetTradeClientExtensionsResponse>().await.map_err(FxError::from)
+	    crate::client::execute_put(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -1958,7 +1792,7 @@ pub mod set_trade_dependent_orders{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -1976,7 +1810,7 @@ pub mod set_trade_dependent_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -1994,7 +1828,7 @@ pub mod set_trade_dependent_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="takeProfit", skip_serializing_if="Option::is_none" )]
@@ -2016,7 +1850,7 @@ pub mod set_trade_dependent_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -2031,7 +1865,7 @@ pub mod set_trade_dependent_orders{
 /// Create, replace and cancel a Trade's dependent Orders (Take Profit,
 /// Stop Loss and Trailing Stop Loss) through the Trade itself
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct SetTradeDependentOrdersRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -2039,6 +1873,8 @@ pub mod set_trade_dependent_orders{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl SetTradeDependentOrdersRequest {
@@ -2049,6 +1885,7 @@ pub mod set_trade_dependent_orders{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -2062,8 +1899,8 @@ pub mod set_trade_dependent_orders{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return SetTradeDependentOrdersRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -2128,6 +1965,13 @@ pub mod set_trade_dependent_orders{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<SetTradeDependentOrdersResponse, FxError> {
 	    // Validate required path parameters
@@ -2137,36 +1981,7 @@ pub mod set_trade_dependent_orders{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{tradeSpecifier}", &trade_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<SetTradeDependentOrdersResponse>().await.map_err(FxError::from)
+	    crate::client::execute_put(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -2249,7 +2064,7 @@ pub mod list_accounts{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -2263,7 +2078,7 @@ pub mod list_accounts{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 	}
 	impl RequestPath {
@@ -2273,7 +2088,7 @@ pub mod list_accounts{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -2283,7 +2098,7 @@ pub mod list_accounts{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -2297,7 +2112,7 @@ pub mod list_accounts{
 	/// List Accounts
 /// Get a list of all Accounts authorized for the provided token.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListAccountsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -2305,6 +2120,8 @@ pub mod list_accounts{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListAccountsRequest {
@@ -2315,6 +2132,7 @@ pub mod list_accounts{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -2332,41 +2150,40 @@ pub mod list_accounts{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
+	  /// A 200 whose body has no `accounts` field is how OANDA answers a rejected token,
+	  /// so it fails with an `ApiError` with status 401 and error code `INVALID_AUTH`. An
+	  /// empty `accounts` array is returned as-is unless the client has `strict_auth` set.
 		pub async fn remote(self, client: &Client) -> Result<ListAccountsResponse, FxError> {
 	    // Validate required path parameters
 	    
 	    let uri = self.uri.clone();
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &uri, &self.query, None, self.timeout);
+		  let response = client.send(request).await?;
+	    let request_id = crate::client::request_id(response.headers());
 	    
-	    response.json::<ListAccountsResponse>().await.map_err(FxError::from)
+	    let parsed_response = crate::client::read_response::<ListAccountsResponse>(client, response).await?;
+	    // OANDA answers a bad token with a 200 and no accounts; a valid token always has at least one.
+	    // A body without the field at all is never a real listing, so that is always an auth failure.
+	    if parsed_response.accounts.is_none() {
+	        return Err(FxError::ApiError {
+	            status_code: 401,
+	            error_code: "INVALID_AUTH".to_string(),
+	            error_message: "the account list has no accounts field; the token was not accepted".to_string(),
+	            request_id,
+	        });
+	    }
+	    if client.strict_auth && parsed_response.accounts.as_ref().is_some_and(|accounts| accounts.is_empty()) {
+	        return Err(FxError::Unauthorized("no accounts are visible to this token".to_string()));
+	    }
+	    Ok(parsed_response)
 		}
 	}
 
@@ -2405,7 +2222,7 @@ pub mod get_account{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -2423,7 +2240,7 @@ pub mod get_account{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -2437,7 +2254,7 @@ pub mod get_account{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -2447,7 +2264,7 @@ pub mod get_account{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -2463,7 +2280,7 @@ pub mod get_account{
 /// Full pending Order, open Trade and open Position representations are
 /// provided.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetAccountRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -2471,6 +2288,8 @@ pub mod get_account{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetAccountRequest {
@@ -2481,6 +2300,7 @@ pub mod get_account{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -2494,8 +2314,8 @@ pub mod get_account{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetAccountRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -2516,6 +2336,13 @@ pub mod get_account{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetAccountResponse, FxError> {
 	    // Validate required path parameters
@@ -2523,36 +2350,7 @@ pub mod get_account{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetAccountResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -2595,7 +2393,7 @@ pub mod get_account_summary{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -2613,7 +2411,7 @@ pub mod get_account_summary{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -2627,7 +2425,7 @@ pub mod get_account_summary{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -2637,7 +2435,7 @@ pub mod get_account_summary{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -2651,7 +2449,7 @@ pub mod get_account_summary{
 	/// Account Summary
 /// Get a summary for a single Account that a client has access to.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetAccountSummaryRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -2659,6 +2457,8 @@ pub mod get_account_summary{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetAccountSummaryRequest {
@@ -2669,6 +2469,7 @@ pub mod get_account_summary{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -2682,8 +2483,8 @@ pub mod get_account_summary{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetAccountSummaryRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -2704,6 +2505,13 @@ pub mod get_account_summary{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetAccountSummaryResponse, FxError> {
 	    // Validate required path parameters
@@ -2711,36 +2519,7 @@ pub mod get_account_summary{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetAccountSummaryResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -2784,7 +2563,7 @@ pub mod get_account_instruments{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -2798,7 +2577,7 @@ pub mod get_account_instruments{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -2812,7 +2591,7 @@ pub mod get_account_instruments{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -2822,7 +2601,7 @@ pub mod get_account_instruments{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="instruments", skip_serializing_if="Option::is_none" )]
@@ -2843,7 +2622,7 @@ pub mod get_account_instruments{
 /// the Account is located in, thus should be the same for all Accounts
 /// owned by a single user.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetAccountInstrumentsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -2851,6 +2630,8 @@ pub mod get_account_instruments{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetAccountInstrumentsRequest {
@@ -2861,6 +2642,7 @@ pub mod get_account_instruments{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -2874,8 +2656,8 @@ pub mod get_account_instruments{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetAccountInstrumentsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -2896,43 +2678,23 @@ pub mod get_account_instruments{
 	    self
 	  }
 	
-
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
+	  /// Served from the client's response cache while it holds a fresh copy; see
+	  /// `Client::with_cache_ttl`.
 		pub async fn remote(self, client: &Client) -> Result<GetAccountInstrumentsResponse, FxError> {
 	    // Validate required path parameters
 	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetAccountInstrumentsResponse>().await.map_err(FxError::from)
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &uri, &self.query, None, self.timeout);
+	    crate::client::read_cached::<GetAccountInstrumentsResponse>(client, request).await
 		}
 	}
 
@@ -2974,7 +2736,7 @@ pub mod configure_account{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -2992,7 +2754,7 @@ pub mod configure_account{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -3006,14 +2768,14 @@ pub mod configure_account{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="alias", skip_serializing_if="Option::is_none" )]
 	  pub alias : Option<String>,
 
 	  #[serde(rename="marginRate", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serfloats" )]
-	  pub margin_rate : Option<f32>,
+	  pub margin_rate : Option<f64>,
 	}
 	impl RequestBody {
 	  fn new() -> RequestBody {
@@ -3024,7 +2786,7 @@ pub mod configure_account{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -3038,7 +2800,7 @@ pub mod configure_account{
 	/// Configure Account
 /// Set the client-configurable portions of an Account.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ConfigureAccountRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -3046,6 +2808,8 @@ pub mod configure_account{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ConfigureAccountRequest {
@@ -3056,6 +2820,7 @@ pub mod configure_account{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -3069,8 +2834,8 @@ pub mod configure_account{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ConfigureAccountRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -3102,13 +2867,20 @@ pub mod configure_account{
 	  /// The string representation of a decimal number.
 /// format: A decimal number encoded as a string. The amount of precision provided
 /// depends on what the number represents.
-  /// - param f32
+  /// - param f64
 	  /// - return ConfigureAccountRequest
-	  pub fn with_margin_rate ( mut self, x : f32 ) -> Self {
+	  pub fn with_margin_rate ( mut self, x : f64 ) -> Self {
 	    self.body.margin_rate = Some(x);
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ConfigureAccountResponse, FxError> {
 	    // Validate required path parameters
@@ -3116,36 +2888,7 @@ pub mod configure_account{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.patch(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ConfigureAccountResponse>().await.map_err(FxError::from)
+	    crate::client::execute_patch(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -3188,7 +2931,7 @@ pub mod get_account_changes{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -3206,7 +2949,7 @@ pub mod get_account_changes{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -3220,7 +2963,7 @@ pub mod get_account_changes{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -3230,7 +2973,7 @@ pub mod get_account_changes{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="sinceTransactionID", skip_serializing_if="Option::is_none" )]
@@ -3249,7 +2992,7 @@ pub mod get_account_changes{
 /// Endpoint used to poll an Account for its current state and changes
 /// since a specified TransactionID.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetAccountChangesRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -3257,6 +3000,8 @@ pub mod get_account_changes{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetAccountChangesRequest {
@@ -3267,6 +3012,7 @@ pub mod get_account_changes{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -3280,8 +3026,8 @@ pub mod get_account_changes{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetAccountChangesRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -3311,6 +3057,13 @@ pub mod get_account_changes{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetAccountChangesResponse, FxError> {
 	    // Validate required path parameters
@@ -3318,36 +3071,7 @@ pub mod get_account_changes{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetAccountChangesResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -3399,7 +3123,7 @@ pub mod list_transactions{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -3417,7 +3141,7 @@ pub mod list_transactions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -3431,7 +3155,7 @@ pub mod list_transactions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -3441,7 +3165,7 @@ pub mod list_transactions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="from", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serdates" )]
@@ -3453,7 +3177,7 @@ pub mod list_transactions{
 	  #[serde(rename="pageSize", skip_serializing_if="Option::is_none" )]
 	  pub page_size : Option<i32>,
 
-	  #[serde(rename="type", skip_serializing_if="Option::is_none" )]
+	  #[serde(rename="type", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::sercsv" )]
 	  pub otype : Option<Vec<String>>,
 	}
 	impl RequestQuery {
@@ -3472,7 +3196,7 @@ pub mod list_transactions{
 /// Get a list of Transactions pages that satisfy a time-based Transaction
 /// query.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListTransactionsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -3480,6 +3204,8 @@ pub mod list_transactions{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListTransactionsRequest {
@@ -3490,6 +3216,7 @@ pub mod list_transactions{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -3503,8 +3230,8 @@ pub mod list_transactions{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListTransactionsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -3571,6 +3298,13 @@ pub mod list_transactions{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListTransactionsResponse, FxError> {
 	    // Validate required path parameters
@@ -3578,41 +3312,111 @@ pub mod list_transactions{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
+		}
+
+	  /// Fetches the listing, then every transaction on each of its pages, returned in
+	  /// time order. Pages are fetched one after another from the client's host, and a
+	  /// failed page fails the whole call. An account with no history in the range
+	  /// yields an empty list. A `with_timeout` limit applies to each page as well.
+		pub async fn remote_all(self, client: &Client) -> Result<Vec<Transaction>, FxError> {
+	    let timeout = self.timeout;
+	    let listing = self.remote(client).await?;
 	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
+	    let mut transactions = Vec::new();
+	    for page_url in listing.pages.unwrap_or_default() {
+	      transactions.extend(fetch_page::<Transaction>(client, &page_url, timeout).await?);
 	    }
-	    
-	    response.json::<ListTransactionsResponse>().await.map_err(FxError::from)
+	    transactions.sort_by_key(|transaction| transaction.time);
+	    Ok(transactions)
 		}
 	}
 
+	/// The transactions on the page at `page_url`, fetched from the client's host and
+	/// read as `T`; reporting reads them as JSON values to get at fields `Transaction` lacks.
+	pub(crate) async fn fetch_page<T: serde::de::DeserializeOwned>(client: &Client, page_url: &str, timeout: Option<std::time::Duration>) -> Result<Vec<T>, FxError> {
+	    let no_query: &[(&str, &str)] = &[];
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &client.rebase_url(page_url)?, no_query, None, timeout);
+		  let response = client.send(request).await?;
+	    let page = crate::client::read_response::<TransactionRangePage<T>>(client, response).await?;
+	    Ok(page.transactions)
+	}
+
+	#[derive(Deserialize)]
+	struct TransactionRangePage<T> {
+	  #[serde(default = "Vec::new")]
+	  transactions: Vec<T>,
+	}
+
 	pub type ListTransactionsResponse = ListTransactionsResponse200Body;
 
+	impl ListTransactionsResponse200Body {
+	  /// A cursor over the listing's pages, to fetch them one at a time.
+	  pub fn page_cursor(&self) -> TransactionPageCursor {
+	    TransactionPageCursor {
+	      pages: self.pages.clone().unwrap_or_default(),
+	      next: 0,
+	      count: self.count,
+	      page_size: self.page_size,
+	    }
+	  }
+	}
+
+	/// Walks the pages of a transaction listing in order, fetching each page only
+	/// when asked, for histories too long to hold at once:
+	///
+	/// ```no_run
+	/// # use fxoanda::*;
+	/// # async fn walk(client: &Client) -> Result<(), FxError> {
+	/// let listing = ListTransactionsRequest::new().with_account_id("101-004-1234567-001").remote(client).await?;
+	/// let mut cursor = listing.page_cursor();
+	/// while let Some(transactions) = cursor.next_page(client).await? {
+	///     println!("{} transactions, {} pages to go", transactions.len(), cursor.remaining());
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[derive(Debug, Clone)]
+	pub struct TransactionPageCursor {
+	  pages: Vec<String>,
+	  next: usize,
+	  count: Option<i32>,
+	  page_size: Option<i32>,
+	}
+
+	impl TransactionPageCursor {
+	  /// The transactions on the next page, or `None` once every page has been read.
+	  /// A failed fetch leaves the cursor where it was, so the page can be retried.
+	  pub async fn next_page(&mut self, client: &Client) -> Result<Option<Vec<Transaction>>, FxError> {
+	    let Some(page_url) = self.pages.get(self.next) else {
+	      return Ok(None);
+	    };
+	    let transactions = fetch_page(client, page_url, None).await?;
+	    self.next += 1;
+	    Ok(Some(transactions))
+	  }
+
+	  /// The URLs of every page, read or not.
+	  pub fn pages(&self) -> &[String] {
+	    &self.pages
+	  }
+
+	  /// Pages not yet read.
+	  pub fn remaining(&self) -> usize {
+	    self.pages.len() - self.next
+	  }
+
+	  /// The number of transactions across all pages, as the listing reported it.
+	  pub fn count(&self) -> Option<i32> {
+	    self.count
+	  }
+
+	  /// The most transactions on one page, as the listing reported it.
+	  pub fn page_size(&self) -> Option<i32> {
+	    self.page_size
+	  }
+	}
+
 	
 	
 	/// The requested time range of Transaction pages are provided.
@@ -3677,7 +3481,7 @@ pub mod get_transaction{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -3695,7 +3499,7 @@ pub mod get_transaction{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -3713,7 +3517,7 @@ pub mod get_transaction{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -3723,7 +3527,7 @@ pub mod get_transaction{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -3737,7 +3541,7 @@ pub mod get_transaction{
 	/// Transaction Details
 /// Get the details of a single Account Transaction.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetTransactionRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -3745,6 +3549,8 @@ pub mod get_transaction{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetTransactionRequest {
@@ -3755,6 +3561,7 @@ pub mod get_transaction{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -3768,8 +3575,8 @@ pub mod get_transaction{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetTransactionRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -3799,6 +3606,13 @@ pub mod get_transaction{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetTransactionResponse, FxError> {
 	    // Validate required path parameters
@@ -3810,36 +3624,7 @@ pub mod get_transaction{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{transactionID}", &transaction_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetTransactionResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -3882,7 +3667,7 @@ pub mod get_transaction_range{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -3900,7 +3685,7 @@ pub mod get_transaction_range{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -3914,7 +3699,7 @@ pub mod get_transaction_range{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -3924,7 +3709,7 @@ pub mod get_transaction_range{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="from", skip_serializing_if="Option::is_none" )]
@@ -3933,7 +3718,7 @@ pub mod get_transaction_range{
 	  #[serde(rename="to", skip_serializing_if="Option::is_none" )]
 	  pub to : Option<String>,
 
-	  #[serde(rename="type", skip_serializing_if="Option::is_none" )]
+	  #[serde(rename="type", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::sercsv" )]
 	  pub otype : Option<Vec<String>>,
 	}
 	impl RequestQuery {
@@ -3951,7 +3736,7 @@ pub mod get_transaction_range{
 /// Get a range of Transactions for an Account based on the Transaction
 /// IDs.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetTransactionRangeRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -3959,6 +3744,8 @@ pub mod get_transaction_range{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetTransactionRangeRequest {
@@ -3969,6 +3756,7 @@ pub mod get_transaction_range{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -3982,8 +3770,8 @@ pub mod get_transaction_range{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetTransactionRangeRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -4030,6 +3818,13 @@ pub mod get_transaction_range{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetTransactionRangeResponse, FxError> {
 	    // Validate required path parameters
@@ -4037,36 +3832,7 @@ pub mod get_transaction_range{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetTransactionRangeResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -4108,7 +3874,7 @@ pub mod get_transactions_since_id{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -4126,7 +3892,7 @@ pub mod get_transactions_since_id{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -4140,7 +3906,7 @@ pub mod get_transactions_since_id{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -4150,7 +3916,7 @@ pub mod get_transactions_since_id{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="id", skip_serializing_if="Option::is_none" )]
@@ -4169,7 +3935,7 @@ pub mod get_transactions_since_id{
 /// Get a range of Transactions for an Account starting at (but not
 /// including) a provided Transaction ID.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetTransactionsSinceIdRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -4177,6 +3943,8 @@ pub mod get_transactions_since_id{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetTransactionsSinceIdRequest {
@@ -4187,6 +3955,7 @@ pub mod get_transactions_since_id{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -4200,8 +3969,8 @@ pub mod get_transactions_since_id{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetTransactionsSinceIdRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -4232,6 +4001,13 @@ pub mod get_transactions_since_id{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetTransactionsSinceIdResponse, FxError> {
 	    // Validate required path parameters
@@ -4239,36 +4015,7 @@ pub mod get_transactions_since_id{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetTransactionsSinceIdResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -4306,11 +4053,12 @@ pub mod stream_transactions{
 	use chrono::prelude::*;
 	use crate::Client;
 	use crate::{RequestValidationError, FxError};
+	use futures::Stream;
 	#[allow(unused_imports)]
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -4324,7 +4072,7 @@ pub mod stream_transactions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -4338,7 +4086,7 @@ pub mod stream_transactions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -4348,7 +4096,7 @@ pub mod stream_transactions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -4363,7 +4111,7 @@ pub mod stream_transactions{
 /// Get a stream of Transactions for an Account starting from when the
 /// request is made.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct StreamTransactionsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -4394,8 +4142,8 @@ pub mod stream_transactions{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return StreamTransactionsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -4409,47 +4157,56 @@ pub mod stream_transactions{
 	  }
 	
 
+	  /// Reads the stream as a single response, which only completes once the server
+	  /// closes the connection. Use [`stream`](Self::stream) to receive updates as they arrive.
 		pub async fn remote(self, client: &Client) -> Result<StreamTransactionsResponse, FxError> {
 	    // Validate required path parameters
 	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+	    let url = format!("{base}{uri}", base=client.stream_base_url(), uri=uri);
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &url, &self.query, None, None);
+		  let response = client.send(request).await?;
 	    
-	    response.json::<StreamTransactionsResponse>().await.map_err(FxError::from)
+	    crate::client::read_response::<StreamTransactionsResponse>(client, response).await
 		}
+
+	  /// Opens the transaction stream.
+	  ///
+	  /// Each line the server sends is yielded as soon as it arrives, with `heartbeat` set
+	  /// for heartbeats and `transaction` otherwise. The stream ends when the server closes
+	  /// the connection; errors are only yielded for an actual failure.
+	  pub fn stream(self, client: &Client) -> impl Stream<Item = Result<StreamTransactionsResponse, FxError>> {
+	    crate::streaming::json_lines(self.stream_request(client), parse_line)
+	  }
+
+	  /// Opens the transaction stream, yielding each line as raw JSON, heartbeats
+	  /// included. [`stream`](Self::stream) keeps only the fields common to every
+	  /// `Transaction`; parse these by their `type` to get the rest.
+	  pub fn values(self, client: &Client) -> impl Stream<Item = Result<serde_json::Value, FxError>> {
+	    crate::streaming::json_lines(self.stream_request(client), |line| Ok(serde_json::from_slice(line)?))
+	  }
+
+	  fn stream_request(self, client: &Client) -> Result<reqwest::RequestBuilder, FxError> {
+	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
+	    let uri = self.uri.replace("{accountID}", &account_id_value);
+	    let url = format!("{base}{uri}", base=client.stream_base_url(), uri=uri);
+	    Ok(crate::client::build_request(client, reqwest::Method::GET, &url, &self.query, None, None))
+	  }
 	}
 
 	pub type StreamTransactionsResponse = StreamTransactionsResponse200Body;
 
+	fn parse_line(line: &[u8]) -> Result<StreamTransactionsResponse, FxError> {
+	  let value: serde_json::Value = serde_json::from_slice(line)?;
+	  if value.get("type").and_then(|t| t.as_str()) == Some("HEARTBEAT") {
+	    Ok(StreamTransactionsResponse { transaction: None, heartbeat: Some(crate::client::from_json_value::<TransactionHeartbeat>(value)?) })
+	  } else {
+	    Ok(StreamTransactionsResponse { transaction: Some(crate::client::from_json_value::<Transaction>(value)?), heartbeat: None })
+	  }
+	}
+
 	
 	
 	/// Connecting to the Transaction Stream was successful.
@@ -4487,7 +4244,7 @@ pub mod get_prices{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -4505,7 +4262,7 @@ pub mod get_prices{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -4519,7 +4276,7 @@ pub mod get_prices{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -4529,7 +4286,7 @@ pub mod get_prices{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="instruments", skip_serializing_if="Option::is_none" )]
@@ -4560,7 +4317,7 @@ pub mod get_prices{
 /// Get pricing information for a specified list of Instruments within an
 /// Account.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetPricesRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -4568,6 +4325,8 @@ pub mod get_prices{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetPricesRequest {
@@ -4578,6 +4337,7 @@ pub mod get_prices{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -4591,8 +4351,8 @@ pub mod get_prices{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetPricesRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -4658,46 +4418,40 @@ pub mod get_prices{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
-		pub async fn remote(self, client: &Client) -> Result<GetPricesResponse, FxError> {
+	  /// Fetches the prices. With an `AcceptDatetimeFormat` of `"UNIX"`, or none on a
+	  /// client asking for UNIX times, `since` goes out as a UNIX timestamp to match,
+	  /// so the response's `time` can be passed straight back for the next poll.
+		pub async fn remote(mut self, client: &Client) -> Result<GetPricesResponse, FxError> {
 	    // Validate required path parameters
-	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
+	    let account_id_value = self.path.account_id.take().ok_or(RequestValidationError::MissingAccountId)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+	    let format = self.header.accept_datetime_format.clone().unwrap_or_else(|| client.datetime_format.as_str().to_string());
+	    let unix = UnixSince { since: if format == "UNIX" { self.query.since.take() } else { None } };
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &uri, &self.query, Some(&format), self.timeout)
+	        .query(&unix);
+		  let response = client.send(request).await?;
 	    
-	    response.json::<GetPricesResponse>().await.map_err(FxError::from)
+	    crate::client::read_response::<GetPricesResponse>(client, response).await
 		}
 	}
 
+	/// `since` in OANDA's UNIX datetime format.
+	#[derive(Serialize)]
+	struct UnixSince {
+	  #[serde(rename="since", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serdates_unix" )]
+	  since : Option<DateTime<Utc>>,
+	}
+
 	pub type GetPricesResponse = GetPricesResponse200Body;
 
 	
@@ -4743,11 +4497,13 @@ pub mod stream_pricing{
 	use chrono::prelude::*;
 	use crate::Client;
 	use crate::{RequestValidationError, FxError};
+	use crate::streaming::StreamPriceItem;
+	use futures::Stream;
 	#[allow(unused_imports)]
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -4765,7 +4521,7 @@ pub mod stream_pricing{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -4779,7 +4535,7 @@ pub mod stream_pricing{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -4789,10 +4545,10 @@ pub mod stream_pricing{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
-	  #[serde(rename="instruments", skip_serializing_if="Option::is_none" )]
+	  #[serde(rename="instruments", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::sercsv" )]
 	  pub instruments : Option<Vec<String>>,
 
 	  #[serde(rename="snapshot", skip_serializing_if="Option::is_none" )]
@@ -4822,7 +4578,7 @@ pub mod stream_pricing{
 /// This means that during periods of rapid price movement, different
 /// subscribers may observe different prices depending on their alignment.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct StreamPricingRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -4853,8 +4609,8 @@ pub mod stream_pricing{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return StreamPricingRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -4884,7 +4640,7 @@ pub mod stream_pricing{
 	  }
 	
 	  /// Flag that enables/disables the sending of a pricing snapshot when
-/// initially connecting to the stream.
+/// initially connecting to the stream. Left unset, OANDA sends the snapshot.
   /// - param bool
 	  /// - return StreamPricingRequest
 	  pub fn with_snapshot ( mut self, x : bool ) -> Self {
@@ -4893,47 +4649,61 @@ pub mod stream_pricing{
 	  }
 	
 
+	  /// Reads the stream as a single response, which only completes once the server
+	  /// closes the connection. Use [`stream`](Self::stream) to receive updates as they arrive.
 		pub async fn remote(self, client: &Client) -> Result<StreamPricingResponse, FxError> {
 	    // Validate required path parameters
 	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+	    let url = format!("{base}{uri}", base=client.stream_base_url(), uri=uri);
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &url, &self.query, self.header.accept_datetime_format.as_deref(), None);
+		  let response = client.send(request).await?;
 	    
-	    response.json::<StreamPricingResponse>().await.map_err(FxError::from)
+	    crate::client::read_response::<StreamPricingResponse>(client, response).await
 		}
+
+	  /// Opens the price stream.
+	  ///
+	  /// Each line the server sends is yielded as soon as it arrives, with `heartbeat` set
+	  /// for heartbeats and `price` otherwise. The stream ends when the server closes
+	  /// the connection; errors are only yielded for an actual failure.
+	  pub fn stream(self, client: &Client) -> impl Stream<Item = Result<StreamPricingResponse, FxError>> {
+	    crate::streaming::json_lines(self.stream_request(client), parse_line)
+	  }
+
+	  /// Opens the price stream, yielding each line as a [`StreamPriceItem`] to match on.
+	  /// Pass the stream to [`prices_only`](crate::prices_only) to skip heartbeats.
+	  pub fn items(self, client: &Client) -> impl Stream<Item = Result<StreamPriceItem, FxError>> {
+	    crate::streaming::json_lines(self.stream_request(client), StreamPriceItem::from_line)
+	  }
+
+	  /// Opens the price stream on a spawned task that forwards each [`StreamPriceItem`]
+	  /// into a channel of `buffer` items, for consumers that would rather `recv()` than
+	  /// poll a stream. The task waits while the channel is full, and finishes when the
+	  /// stream ends or the receiver is dropped. Must be called within a Tokio runtime.
+	  pub fn into_channel(self, client: &Client, buffer: usize) -> (tokio::task::JoinHandle<()>, tokio::sync::mpsc::Receiver<Result<StreamPriceItem, FxError>>) {
+	    crate::streaming::spawn_channel(self.items(client), buffer)
+	  }
+
+	  fn stream_request(self, client: &Client) -> Result<reqwest::RequestBuilder, FxError> {
+	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
+	    let uri = self.uri.replace("{accountID}", &account_id_value);
+	    let url = format!("{base}{uri}", base=client.stream_base_url(), uri=uri);
+	    Ok(crate::client::build_request(client, reqwest::Method::GET, &url, &self.query, self.header.accept_datetime_format.as_deref(), None))
+	  }
 	}
 
 	pub type StreamPricingResponse = StreamPricingResponse200Body;
 
+	fn parse_line(line: &[u8]) -> Result<StreamPricingResponse, FxError> {
+	  Ok(match StreamPriceItem::from_line(line)? {
+	    StreamPriceItem::Price(price) => StreamPricingResponse { price: Some(price), heartbeat: None },
+	    StreamPriceItem::Heartbeat(heartbeat) => StreamPricingResponse { price: None, heartbeat: Some(heartbeat) },
+	  })
+	}
+
 	
 	
 	/// Connecting to the Price Stream was successful.
@@ -4973,7 +4743,7 @@ pub mod get_account_instrument_candles{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -4991,21 +4761,25 @@ pub mod get_account_instrument_candles{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
+	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
+	  pub account_id : Option<String>,
+
 	  #[serde(rename="instrument", skip_serializing_if="Option::is_none" )]
 	  pub instrument : Option<String>,
 	}
 	impl RequestPath {
 	  fn new() -> RequestPath {
 	    RequestPath { 
+	      account_id : None,
 	      instrument : None,
 	    }
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -5015,7 +4789,7 @@ pub mod get_account_instrument_candles{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="price", skip_serializing_if="Option::is_none" )]
@@ -5049,7 +4823,7 @@ pub mod get_account_instrument_candles{
 	  pub weekly_alignment : Option<String>,
 
 	  #[serde(rename="units", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serfloats" )]
-	  pub units : Option<f32>,
+	  pub units : Option<f64>,
 	}
 	impl RequestQuery {
 	  fn new() -> RequestQuery {
@@ -5073,7 +4847,7 @@ pub mod get_account_instrument_candles{
 	/// Get Candlesticks
 /// Fetch candlestick data for an instrument.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetAccountInstrumentCandlesRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -5081,6 +4855,8 @@ pub mod get_account_instrument_candles{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetAccountInstrumentCandlesRequest {
@@ -5091,6 +4867,7 @@ pub mod get_account_instrument_candles{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -5099,13 +4876,23 @@ pub mod get_account_instrument_candles{
 	  }
 
 	
+	  /// Account Identifier
+/// format: "-"-delimited string with format
+/// "{siteID}-{divisionID}-{userID}-{accountNumber}"
+  /// - param String
+	  /// - return GetAccountInstrumentCandlesRequest
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
+	    self
+	  }
+	
 	  /// Name of the Instrument
 /// format: A string containing the base currency and quote currency delimited by
 /// a "_".
   /// - param String
 	  /// - return GetAccountInstrumentCandlesRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.path.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -5238,52 +5025,32 @@ pub mod get_account_instrument_candles{
 /// and ask prices in the returned candles.
 /// format: A decimal number encoded as a string. The amount of precision provided
 /// depends on what the number represents.
-  /// - param f32
+  /// - param f64
 	  /// - return GetAccountInstrumentCandlesRequest
-	  pub fn with_units ( mut self, x : f32 ) -> Self {
+	  pub fn with_units ( mut self, x : f64 ) -> Self {
 	    self.query.units = Some(x);
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetAccountInstrumentCandlesResponse, FxError> {
 	    // Validate required path parameters
+	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
 	    let instrument_value = self.path.instrument.ok_or_else(|| {
 	        RequestValidationError::MissingInstrument
 	    })?;
 	    
 	    let uri = self.uri.clone()
+		      .replace("{accountID}", &account_id_value)
 		      .replace("{instrument}", &instrument_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetAccountInstrumentCandlesResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -5325,12 +5092,12 @@ pub mod create_market_order{
 	use chrono::prelude::*;
 	use crate::Client;
 	use crate::{RequestValidationError, FxError};
+	use crate::validation::{InstrumentMetadata, OrderFields};
 	#[allow(unused_imports)]
 	use fxoanda_definitions::*;
-	use serde_path_to_error;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -5348,7 +5115,7 @@ pub mod create_market_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -5362,7 +5129,7 @@ pub mod create_market_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="order", skip_serializing_if="Option::is_none" )]
@@ -5376,7 +5143,7 @@ pub mod create_market_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -5390,7 +5157,7 @@ pub mod create_market_order{
 	/// Create Order
 /// Create an Order for an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct CreateMarketOrderRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -5398,6 +5165,8 @@ pub mod create_market_order{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl CreateMarketOrderRequest {
@@ -5408,6 +5177,7 @@ pub mod create_market_order{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -5421,8 +5191,8 @@ pub mod create_market_order{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return CreateMarketOrderRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -5450,84 +5220,46 @@ pub mod create_market_order{
 	    self
 	  }
 	
+	  /// Checks the order against every constraint of `instrument` before it is
+	  /// submitted, reporting all violations rather than just the first.
+	  pub fn validate_order(&self, instrument: &InstrumentMetadata) -> Result<(), Vec<RequestValidationError>> {
+	    let order = match &self.body.order {
+	      Some(order) => order,
+	      None => return Ok(()),
+	    };
+	    OrderFields {
+	      units: order.units,
+	      prices: order.price_bound.into_iter().collect(),
+	      trailing_stop_loss_on_fill: order.trailing_stop_loss_on_fill.as_ref(),
+	      stop_loss_on_fill: None,
+	      trigger_condition: None,
+	    }
+	    .with_dependents(order.stop_loss_on_fill.as_ref(), order.take_profit_on_fill.as_ref())
+	    .validate(instrument)
+	  }
+
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<CreateMarketOrderResponse, FxError> {
 	    // Validate required path parameters
 	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
+	    let order = self.body.order.as_ref().ok_or(RequestValidationError::MissingOrder)?;
+	    order.units.ok_or(RequestValidationError::MissingUnits)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.post(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await;
-	    match response {
-	      Err(e) => Err(e.into()),
-	      Ok(response) => {
-	          let status = response.status();
-	          
-	          // Get the raw response text first
-	          let response_text = response.text().await.map_err(FxError::from)?;
-	          
-	          // Try to parse as JSON to see the structure
-	          match serde_json::from_str::<serde_json::Value>(&response_text) {
-	              Ok(json_value) => {
-	                  
-	                  // Check for OANDA error responses (HTTP 400, etc.)
-	                  if !status.is_success() {
-	                      // Extract detailed error information for better error reporting
-	                      let error_message = json_value.get("errorMessage")
-	                          .and_then(|v| v.as_str())
-	                          .unwrap_or("Unknown error");
-	                      let error_code = json_value.get("errorCode")
-	                          .and_then(|v| v.as_str())
-	                          .unwrap_or("UNKNOWN_ERROR_CODE");
-	                      
-	                      // Check for orderRejectTransaction details
-	                      if let Some(reject_transaction) = json_value.get("orderRejectTransaction") {
-	                          let reject_reason = reject_transaction.get("rejectReason")
-	                              .and_then(|v| v.as_str())
-	                              .unwrap_or("UNKNOWN_REJECT_REASON");
-	                          let instrument = reject_transaction.get("instrument")
-	                              .and_then(|v| v.as_str())
-	                              .unwrap_or("UNKNOWN_INSTRUMENT");
-	                          let units = reject_transaction.get("units")
-	                              .and_then(|v| v.as_str())
-	                              .unwrap_or("UNKNOWN_UNITS");
-	                              
-	                          return Err(FxError::OrderRejection {
-	                              instrument: instrument.to_string(),
-	                              units: units.to_string(),
-	                              reject_reason: reject_reason.to_string(),
-	                              error_code: error_code.to_string(),
-	                              error_message: error_message.to_string(),
-	                          });
-	                      } else {
-	                          return Err(FxError::ApiError {
-	                              status_code: status.as_u16(),
-	                              error_code: error_code.to_string(),
-	                              error_message: error_message.to_string(),
-	                          });
-	                      }
-	                  }
-	              }
-	              Err(_e) => {
-	              }
-	          }
-	          
-	          // For successful responses, try normal deserialization
-	          let deserializer = &mut serde_json::Deserializer::from_str(&response_text);
-	          match serde_path_to_error::deserialize::<_, CreateMarketOrderResponse>(deserializer) {
-	              Err(e) => {
-	                  Err(e.into())
-	              },
-	              Ok(parsed_response) => {
-	                  Ok(parsed_response)
-	              }
-	          }
-	      }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::POST, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).json(&self.body);
+		  let response = client.send(request).await?;
+	    
+	    let order = self.body.order.as_ref();
+	    crate::client::read_order_response::<CreateMarketOrderResponse>(
+	        client, response, order.and_then(|o| o.instrument.as_deref()), order.and_then(|o| o.units)).await
 		}
 	}
 
@@ -5589,11 +5321,12 @@ pub mod create_limit_order{
 	use chrono::prelude::*;
 	use crate::Client;
 	use crate::{RequestValidationError, FxError};
+	use crate::validation::{InstrumentMetadata, OrderFields};
 	#[allow(unused_imports)]
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -5611,7 +5344,7 @@ pub mod create_limit_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -5625,7 +5358,7 @@ pub mod create_limit_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="order", skip_serializing_if="Option::is_none" )]
@@ -5639,7 +5372,7 @@ pub mod create_limit_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -5653,7 +5386,7 @@ pub mod create_limit_order{
 	/// Create Order
 /// Create an Order for an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct CreateLimitOrderRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -5661,6 +5394,8 @@ pub mod create_limit_order{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl CreateLimitOrderRequest {
@@ -5671,6 +5406,7 @@ pub mod create_limit_order{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -5684,8 +5420,8 @@ pub mod create_limit_order{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return CreateLimitOrderRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -5713,43 +5449,50 @@ pub mod create_limit_order{
 	    self
 	  }
 	
+	  /// Checks the order against every constraint of `instrument` before it is
+	  /// submitted, reporting all violations rather than just the first.
+	  pub fn validate_order(&self, instrument: &InstrumentMetadata) -> Result<(), Vec<RequestValidationError>> {
+	    let order = match &self.body.order {
+	      Some(order) => order,
+	      None => return Ok(()),
+	    };
+	    OrderFields {
+	      units: order.units,
+	      prices: order.price.into_iter().collect(),
+	      trailing_stop_loss_on_fill: order.trailing_stop_loss_on_fill.as_ref(),
+	      stop_loss_on_fill: None,
+	      trigger_condition: order.trigger_condition.as_deref(),
+	    }
+	    .with_dependents(order.stop_loss_on_fill.as_ref(), order.take_profit_on_fill.as_ref())
+	    .validate(instrument)
+	  }
+
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<CreateLimitOrderResponse, FxError> {
 	    // Validate required path parameters
 	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
+	    let order = self.body.order.as_ref().ok_or(RequestValidationError::MissingOrder)?;
+	    order.units.ok_or(RequestValidationError::MissingUnits)?;
+	    let price = order.price.ok_or(RequestValidationError::MissingPrice)?;
+	    if price.is_nan() || price <= 0.0 {
+	        return Err(RequestValidationError::InvalidPrice.into());
+	    }
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.post(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::POST, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).json(&self.body);
+		  let response = client.send(request).await?;
 	    
-	    response.json::<CreateLimitOrderResponse>().await.map_err(FxError::from)
+	    let order = self.body.order.as_ref();
+	    crate::client::read_order_response::<CreateLimitOrderResponse>(
+	        client, response, order.and_then(|o| o.instrument.as_deref()), order.and_then(|o| o.units)).await
 		}
 	}
 
@@ -5811,11 +5554,12 @@ pub mod create_stop_order{
 	use chrono::prelude::*;
 	use crate::Client;
 	use crate::{RequestValidationError, FxError};
+	use crate::validation::{InstrumentMetadata, OrderFields};
 	#[allow(unused_imports)]
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -5833,7 +5577,7 @@ pub mod create_stop_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -5847,7 +5591,7 @@ pub mod create_stop_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="order", skip_serializing_if="Option::is_none" )]
@@ -5861,7 +5605,7 @@ pub mod create_stop_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -5875,7 +5619,7 @@ pub mod create_stop_order{
 	/// Create Order
 /// Create an Order for an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct CreateStopOrderRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -5883,6 +5627,8 @@ pub mod create_stop_order{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl CreateStopOrderRequest {
@@ -5893,6 +5639,7 @@ pub mod create_stop_order{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -5906,8 +5653,8 @@ pub mod create_stop_order{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return CreateStopOrderRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -5935,43 +5682,50 @@ pub mod create_stop_order{
 	    self
 	  }
 	
+	  /// Checks the order against every constraint of `instrument` before it is
+	  /// submitted, reporting all violations rather than just the first.
+	  pub fn validate_order(&self, instrument: &InstrumentMetadata) -> Result<(), Vec<RequestValidationError>> {
+	    let order = match &self.body.order {
+	      Some(order) => order,
+	      None => return Ok(()),
+	    };
+	    OrderFields {
+	      units: order.units,
+	      prices: order.price.into_iter().chain(order.price_bound).collect(),
+	      trailing_stop_loss_on_fill: order.trailing_stop_loss_on_fill.as_ref(),
+	      stop_loss_on_fill: None,
+	      trigger_condition: order.trigger_condition.as_deref(),
+	    }
+	    .with_dependents(order.stop_loss_on_fill.as_ref(), order.take_profit_on_fill.as_ref())
+	    .validate(instrument)
+	  }
+
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<CreateStopOrderResponse, FxError> {
 	    // Validate required path parameters
 	    let account_id_value = self.path.account_id.ok_or(RequestValidationError::MissingAccountId)?;
+	    let order = self.body.order.as_ref().ok_or(RequestValidationError::MissingOrder)?;
+	    order.units.ok_or(RequestValidationError::MissingUnits)?;
+	    let price = order.price.ok_or(RequestValidationError::MissingPrice)?;
+	    if price.is_nan() || price <= 0.0 {
+	        return Err(RequestValidationError::InvalidPrice.into());
+	    }
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.post(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::POST, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).json(&self.body);
+		  let response = client.send(request).await?;
 	    
-	    response.json::<CreateStopOrderResponse>().await.map_err(FxError::from)
+	    let order = self.body.order.as_ref();
+	    crate::client::read_order_response::<CreateStopOrderResponse>(
+	        client, response, order.and_then(|o| o.instrument.as_deref()), order.and_then(|o| o.units)).await
 		}
 	}
 
@@ -6037,7 +5791,7 @@ pub mod list_orders{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -6055,7 +5809,7 @@ pub mod list_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -6069,7 +5823,7 @@ pub mod list_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -6079,7 +5833,7 @@ pub mod list_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="ids", skip_serializing_if="Option::is_none" )]
@@ -6113,7 +5867,7 @@ pub mod list_orders{
 	/// List Orders
 /// Get a list of Orders for an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListOrdersRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -6121,6 +5875,8 @@ pub mod list_orders{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListOrdersRequest {
@@ -6131,6 +5887,7 @@ pub mod list_orders{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -6144,8 +5901,8 @@ pub mod list_orders{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListOrdersRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -6187,8 +5944,8 @@ pub mod list_orders{
 /// a "_".
   /// - param String
 	  /// - return ListOrdersRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.query.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.query.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -6212,6 +5969,13 @@ pub mod list_orders{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListOrdersResponse, FxError> {
 	    // Validate required path parameters
@@ -6219,36 +5983,7 @@ pub mod list_orders{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ListOrdersResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -6293,7 +6028,7 @@ pub mod list_pending_orders{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -6311,7 +6046,7 @@ pub mod list_pending_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -6325,7 +6060,7 @@ pub mod list_pending_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -6335,7 +6070,7 @@ pub mod list_pending_orders{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -6349,7 +6084,7 @@ pub mod list_pending_orders{
 	/// Pending Orders
 /// List all pending Orders in an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ListPendingOrdersRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -6357,6 +6092,8 @@ pub mod list_pending_orders{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ListPendingOrdersRequest {
@@ -6367,6 +6104,7 @@ pub mod list_pending_orders{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -6380,8 +6118,8 @@ pub mod list_pending_orders{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ListPendingOrdersRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -6402,6 +6140,13 @@ pub mod list_pending_orders{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ListPendingOrdersResponse, FxError> {
 	    // Validate required path parameters
@@ -6409,36 +6154,7 @@ pub mod list_pending_orders{
 	    
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<ListPendingOrdersResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -6480,7 +6196,7 @@ pub mod get_order{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -6498,7 +6214,7 @@ pub mod get_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -6516,7 +6232,7 @@ pub mod get_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -6526,7 +6242,7 @@ pub mod get_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -6540,7 +6256,7 @@ pub mod get_order{
 	/// Get Order
 /// Get details for a single Order in an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetOrderRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -6548,6 +6264,8 @@ pub mod get_order{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetOrderRequest {
@@ -6558,6 +6276,7 @@ pub mod get_order{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -6571,8 +6290,8 @@ pub mod get_order{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return GetOrderRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -6603,6 +6322,13 @@ pub mod get_order{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetOrderResponse, FxError> {
 	    // Validate required path parameters
@@ -6614,36 +6340,7 @@ pub mod get_order{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{orderSpecifier}", &order_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<GetOrderResponse>().await.map_err(FxError::from)
+	    crate::client::execute_get(client, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -6689,7 +6386,7 @@ pub mod replace_order{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -6711,7 +6408,7 @@ pub mod replace_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -6729,7 +6426,7 @@ pub mod replace_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="order", skip_serializing_if="Option::is_none" )]
@@ -6743,7 +6440,7 @@ pub mod replace_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -6758,7 +6455,7 @@ pub mod replace_order{
 /// Replace an Order in an Account by simultaneously cancelling it and
 /// creating a replacement Order
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct ReplaceOrderRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -6766,6 +6463,8 @@ pub mod replace_order{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl ReplaceOrderRequest {
@@ -6776,6 +6475,7 @@ pub mod replace_order{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -6789,8 +6489,8 @@ pub mod replace_order{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return ReplaceOrderRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -6838,6 +6538,13 @@ pub mod replace_order{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<ReplaceOrderResponse, FxError> {
 	    // Validate required path parameters
@@ -6849,36 +6556,10 @@ pub mod replace_order{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{orderSpecifier}", &order_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::PUT, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout).json(&self.body);
+		  let response = client.send(request).await?;
 	    
-	    response.json::<ReplaceOrderResponse>().await.map_err(FxError::from)
+	    crate::client::read_replace_response::<ReplaceOrderResponse>(client, response).await
 		}
 	}
 
@@ -6948,7 +6629,7 @@ pub mod cancel_order{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -6970,7 +6651,7 @@ pub mod cancel_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -6988,7 +6669,7 @@ pub mod cancel_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -6998,7 +6679,7 @@ pub mod cancel_order{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -7012,7 +6693,7 @@ pub mod cancel_order{
 	/// Cancel Order
 /// Cancel a pending Order in an Account
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct CancelOrderRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -7020,6 +6701,8 @@ pub mod cancel_order{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl CancelOrderRequest {
@@ -7030,6 +6713,7 @@ pub mod cancel_order{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -7043,8 +6727,8 @@ pub mod cancel_order{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return CancelOrderRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -7083,6 +6767,13 @@ pub mod cancel_order{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<CancelOrderResponse, FxError> {
 	    // Validate required path parameters
@@ -7094,36 +6785,7 @@ pub mod cancel_order{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{orderSpecifier}", &order_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<CancelOrderResponse>().await.map_err(FxError::from)
+	    crate::client::execute_put(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
@@ -7170,7 +6832,7 @@ pub mod set_order_client_extensions{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -7188,7 +6850,7 @@ pub mod set_order_client_extensions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="accountID", skip_serializing_if="Option::is_none" )]
@@ -7206,7 +6868,7 @@ pub mod set_order_client_extensions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 
 	  #[serde(rename="clientExtensions", skip_serializing_if="Option::is_none" )]
@@ -7224,7 +6886,7 @@ pub mod set_order_client_extensions{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 	}
 	impl RequestQuery {
@@ -7240,7 +6902,7 @@ pub mod set_order_client_extensions{
 /// modify, or delete clientExtensions if your account is associated with
 /// MT4.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct SetOrderClientExtensionsRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -7248,6 +6910,8 @@ pub mod set_order_client_extensions{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl SetOrderClientExtensionsRequest {
@@ -7258,6 +6922,7 @@ pub mod set_order_client_extensions{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -7271,8 +6936,8 @@ pub mod set_order_client_extensions{
 /// "{siteID}-{divisionID}-{userID}-{accountNumber}"
   /// - param String
 	  /// - return SetOrderClientExtensionsRequest
-	  pub fn with_account_id ( mut self, x : String ) -> Self {
-	    self.path.account_id = Some(x);
+	  pub fn with_account_id ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.account_id = Some(x.into());
 	    self
 	  }
 	
@@ -7323,6 +6988,13 @@ pub mod set_order_client_extensions{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<SetOrderClientExtensionsResponse, FxError> {
 	    // Validate required path parameters
@@ -7334,36 +7006,7 @@ pub mod set_order_client_extensions{
 	    let uri = self.uri.clone()
 		      .replace("{accountID}", &account_id_value)
 		      .replace("{orderSpecifier}", &order_specifier_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.put(&url)
-				      .query(&self.query).json::<RequestBody>(&self.body)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
-	    
-	    response.json::<SetOrderClientExtensionsResponse>().await.map_err(FxError::from)
+	    crate::client::execute_put(client, &uri, &self.query, &self.body, self.header.accept_datetime_format.as_deref(), self.timeout).await
 		}
 	}
 
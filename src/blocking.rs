@@ -0,0 +1,88 @@
+//! Synchronous calls for programs without an async runtime, behind the `blocking` feature.
+//!
+//! Every request type gets a `remote_blocking` that sends it and waits for the
+//! response, and [`block_on`] runs any of the crate's other async helpers the same
+//! way. Both drive the request on a small runtime shared by the whole process, so
+//! they must not be called from within an async context: doing so panics.
+
+use crate::*;
+use std::future::Future;
+use std::sync::OnceLock;
+
+/// Runs `future` to completion on the shared runtime, blocking the calling thread.
+///
+/// ```no_run
+/// # use fxoanda::*;
+/// let client = Client::from_env().unwrap();
+/// let fill = fxoanda::blocking::block_on(client.market_buy("101-004-1234567-001", "EUR_USD", 100.0));
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("fxoanda-blocking")
+                .enable_all()
+                .build()
+                .expect("failed to start the runtime for blocking calls")
+        })
+        .block_on(future)
+}
+
+macro_rules! blocking_requests {
+    ($($request:ident -> $response:ident),*) => {
+      $(
+         impl $request {
+           /// Sends the request and waits for the response. See the [`blocking`](crate::blocking) module.
+           pub fn remote_blocking(self, client: &Client) -> Result<$response, FxError> {
+             block_on(self.remote(client))
+           }
+         }
+       )*
+    };
+}
+
+blocking_requests!(
+    // Account functions
+    ListAccountsRequest -> ListAccountsResponse,
+    GetAccountRequest -> GetAccountResponse,
+    GetAccountSummaryRequest -> GetAccountSummaryResponse,
+    GetAccountInstrumentsRequest -> GetAccountInstrumentsResponse,
+    ConfigureAccountRequest -> ConfigureAccountResponse,
+    GetAccountChangesRequest -> GetAccountChangesResponse,
+    // Position functions
+    ListPositionsRequest -> ListPositionsResponse,
+    ListOpenPositionsRequest -> ListOpenPositionsResponse,
+    GetPositionRequest -> GetPositionResponse,
+    ClosePositionRequest -> ClosePositionResponse,
+    // Trade functions
+    ListTradesRequest -> ListTradesResponse,
+    ListOpenTradesRequest -> ListOpenTradesResponse,
+    GetTradeRequest -> GetTradeResponse,
+    CloseTradeRequest -> CloseTradeResponse,
+    SetTradeClientExtensionsRequest -> SetTradeClientExtensionsResponse,
+    SetTradeDependentOrdersRequest -> SetTradeDependentOrdersResponse,
+    // Order functions
+    CreateMarketOrderRequest -> CreateMarketOrderResponse,
+    CreateLimitOrderRequest -> CreateLimitOrderResponse,
+    CreateStopOrderRequest -> CreateStopOrderResponse,
+    ListOrdersRequest -> ListOrdersResponse,
+    ListPendingOrdersRequest -> ListPendingOrdersResponse,
+    GetOrderRequest -> GetOrderResponse,
+    ReplaceOrderRequest -> ReplaceOrderResponse,
+    CancelOrderRequest -> CancelOrderResponse,
+    SetOrderClientExtensionsRequest -> SetOrderClientExtensionsResponse,
+    // Transaction functions
+    ListTransactionsRequest -> ListTransactionsResponse,
+    GetTransactionRequest -> GetTransactionResponse,
+    GetTransactionRangeRequest -> GetTransactionRangeResponse,
+    GetTransactionsSinceIdRequest -> GetTransactionsSinceIdResponse,
+    // Pricing functions
+    GetPricesRequest -> GetPricesResponse,
+    GetAccountInstrumentCandlesRequest -> GetAccountInstrumentCandlesResponse,
+    // Instrument functions
+    GetInstrumentCandlesRequest -> GetInstrumentCandlesResponse,
+    GetOrderBookRequest -> GetOrderBookResponse,
+    GetPositionBookRequest -> GetPositionBookResponse
+);
@@ -0,0 +1,104 @@
+use crate::instrument::{GetOrderBookRequest, GetPositionBookRequest};
+use chrono::{DateTime, Utc};
+
+/// The neighbouring order or position book snapshots, from the `Link` header of a
+/// book response. OANDA takes a snapshot about every 20 minutes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookLinks {
+    /// The URL of the snapshot before this one.
+    pub prev: Option<String>,
+    /// The URL of the snapshot after this one; `None` for the latest.
+    pub next: Option<String>,
+}
+
+impl BookLinks {
+    /// Parses a `Link` header such as
+    /// `<https://…/orderBook?time=…>; rel="prev", <https://…/orderBook?time=…>; rel="next"`.
+    /// Links with other relations are ignored.
+    pub fn parse(header: &str) -> BookLinks {
+        let mut links = BookLinks::default();
+        for link in header.split(',') {
+            let mut parts = link.split(';');
+            let Some(url) = parts.next().map(str::trim).and_then(|url| url.strip_prefix('<')?.strip_suffix('>')) else {
+                continue;
+            };
+            let rel = parts
+                .filter_map(|param| param.trim().strip_prefix("rel="))
+                .map(|rel| rel.trim_matches('"'))
+                .next();
+            match rel {
+                Some("prev") => links.prev = Some(url.to_string()),
+                Some("next") => links.next = Some(url.to_string()),
+                _ => {}
+            }
+        }
+        links
+    }
+
+    pub(crate) fn from_headers(headers: &reqwest::header::HeaderMap) -> BookLinks {
+        headers.get(reqwest::header::LINK)
+            .and_then(|value| value.to_str().ok())
+            .map(BookLinks::parse)
+            .unwrap_or_default()
+    }
+}
+
+/// The instrument and snapshot time in a book link such as
+/// `https://…/v3/instruments/EUR_USD/orderBook?time=2024-01-02T09%3A40%3A00Z`.
+/// The time may be in either datetime format.
+fn parse_link(link: &str, book: &str) -> Option<(String, DateTime<Utc>)> {
+    let url = reqwest::Url::parse(link).ok()?;
+    let mut segments = url.path_segments()?.rev();
+    if segments.next()? != book {
+        return None;
+    }
+    let instrument = segments.next()?.to_string();
+    let time = url.query_pairs().find(|(key, _)| key == "time")?.1.into_owned();
+    let time = fxoanda_serdes::serdates::deserialize(serde_json::Value::String(time)).ok()??;
+    Some((instrument, time))
+}
+
+impl GetOrderBookRequest {
+    /// Fetches the latest snapshot taken at or before `time`. Snapshots are taken
+    /// about every 20 minutes, so the one returned may be up to that much older.
+    pub fn with_snapshot_before(self, time: DateTime<Utc>) -> Self {
+        self.with_time(time)
+    }
+
+    /// A fresh request for the snapshot a [`BookLinks`] URL points to, or `None` if
+    /// the link isn't an order book link with a `time`. Walk backwards through
+    /// history by following `prev` from each response:
+    ///
+    /// ```no_run
+    /// # use fxoanda::*;
+    /// # async fn walk(client: &Client) -> Result<(), FxError> {
+    /// let (_, mut links) = GetOrderBookRequest::new().with_instrument("EUR_USD").remote_with_headers(client).await?;
+    /// while let Some(request) = links.prev.as_deref().and_then(GetOrderBookRequest::from_link) {
+    ///     let (book, prev_links) = request.remote_with_headers(client).await?;
+    ///     // analyse book.order_book
+    ///     links = prev_links;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_link(link: &str) -> Option<GetOrderBookRequest> {
+        let (instrument, time) = parse_link(link, "orderBook")?;
+        Some(GetOrderBookRequest::new().with_instrument(instrument).with_snapshot_before(time))
+    }
+}
+
+impl GetPositionBookRequest {
+    /// Fetches the latest snapshot taken at or before `time`. Snapshots are taken
+    /// about every 20 minutes, so the one returned may be up to that much older.
+    pub fn with_snapshot_before(self, time: DateTime<Utc>) -> Self {
+        self.with_time(time)
+    }
+
+    /// A fresh request for the snapshot a [`BookLinks`] URL points to, or `None` if
+    /// the link isn't a position book link with a `time`. See
+    /// [`GetOrderBookRequest::from_link`].
+    pub fn from_link(link: &str) -> Option<GetPositionBookRequest> {
+        let (instrument, time) = parse_link(link, "positionBook")?;
+        Some(GetPositionBookRequest::new().with_instrument(instrument).with_snapshot_before(time))
+    }
+}
@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use fxoanda_definitions::*;
+
+/// Open, high, low and close prices of one price component of a candle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ohlc {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Ohlc {
+    /// The prices of `data`, or `None` if any of the four is missing.
+    pub fn from_data(data: &CandlestickData) -> Option<Ohlc> {
+        Some(Ohlc {
+            open: data.o?,
+            high: data.h?,
+            low: data.l?,
+            close: data.c?,
+        })
+    }
+}
+
+/// One price component of a run of candles, keyed by candle start time.
+pub type PriceSeries = Vec<(DateTime<Utc>, Ohlc)>;
+
+/// The mid, bid and ask components of a run of candles as separate series.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CandleSeries {
+    pub mid: PriceSeries,
+    pub bid: PriceSeries,
+    pub ask: PriceSeries,
+}
+
+/// Splits candles requested with price `"MBA"` into separate mid, bid and ask series,
+/// each in the order of `candles`.
+///
+/// A candle only contributes to the series of the components it carries, so with
+/// gaps the series can differ in length; align them on their timestamps. Candles
+/// without a time are skipped.
+pub fn split_price_components(candles: &[Candlestick]) -> CandleSeries {
+    let mut series = CandleSeries::default();
+    for candle in candles {
+        let time = match candle.time {
+            Some(time) => time,
+            None => continue,
+        };
+        let components = [
+            (&candle.mid, &mut series.mid),
+            (&candle.bid, &mut series.bid),
+            (&candle.ask, &mut series.ask),
+        ];
+        for (data, component_series) in components {
+            if let Some(ohlc) = data.as_ref().and_then(Ohlc::from_data) {
+                component_series.push((time, ohlc));
+            }
+        }
+    }
+    series
+}
+
+/// Which of a candle's prices to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceComponent {
+    Mid,
+    Bid,
+    Ask,
+}
+
+impl PriceComponent {
+    fn data(self, candle: &Candlestick) -> Option<&CandlestickData> {
+        match self {
+            PriceComponent::Mid => candle.mid.as_ref(),
+            PriceComponent::Bid => candle.bid.as_ref(),
+            PriceComponent::Ask => candle.ask.as_ref(),
+        }
+    }
+}
+
+/// One price component of a run of candles as flat arrays, for feeding
+/// technical-indicator crates.
+///
+/// Every array has one entry per candle, in order, so they stay aligned with each
+/// other and with the candles. A price or volume the candle doesn't carry is `NaN`
+/// rather than skipped; most indicator crates propagate or reject `NaN`, where a
+/// skipped value would silently shift the series.
+#[derive(Debug, Clone, Copy)]
+pub struct Candles<'a> {
+    candles: &'a [Candlestick],
+    component: PriceComponent,
+}
+
+impl<'a> Candles<'a> {
+    pub fn new(candles: &'a [Candlestick], component: PriceComponent) -> Candles<'a> {
+        Candles { candles, component }
+    }
+
+    pub fn opens(&self) -> Vec<f64> {
+        self.prices(|data| data.o)
+    }
+
+    pub fn highs(&self) -> Vec<f64> {
+        self.prices(|data| data.h)
+    }
+
+    pub fn lows(&self) -> Vec<f64> {
+        self.prices(|data| data.l)
+    }
+
+    pub fn closes(&self) -> Vec<f64> {
+        self.prices(|data| data.c)
+    }
+
+    /// Volumes are the same for every component.
+    pub fn volumes(&self) -> Vec<f64> {
+        self.candles
+            .iter()
+            .map(|candle| candle.volume.map_or(f64::NAN, f64::from))
+            .collect()
+    }
+
+    fn prices(&self, price: impl Fn(&CandlestickData) -> Option<f64>) -> Vec<f64> {
+        self.candles
+            .iter()
+            .map(|candle| self.component.data(candle).and_then(&price).unwrap_or(f64::NAN))
+            .collect()
+    }
+}
+
+/// Builds mid-price candles of one granularity from streamed prices, for instruments
+/// or granularities OANDA doesn't serve candles for.
+///
+/// Periods are aligned to the Unix epoch, so H1 candles start on the hour and D
+/// candles at midnight UTC. A candle is complete once a price from a later period
+/// arrives. Volume counts price updates, as OANDA's own candles do. Prices without a
+/// time or without both a bid and an ask are skipped, as are prices older than the
+/// candle being built.
+#[derive(Debug, Clone)]
+pub struct CandleAggregator {
+    period_seconds: i64,
+    current: Option<(DateTime<Utc>, Ohlc, i32)>,
+}
+
+impl CandleAggregator {
+    /// An aggregator for `granularity`, or `None` for W and M, which have no fixed length.
+    pub fn new(granularity: CandlestickGranularity) -> Option<CandleAggregator> {
+        Some(CandleAggregator {
+            period_seconds: granularity.seconds()? as i64,
+            current: None,
+        })
+    }
+
+    /// Adds `price` to its candle, returning the previous candle if `price` completed it.
+    pub fn push(&mut self, price: &ClientPrice) -> Option<Candlestick> {
+        let time = price.time?;
+        let mid = (price.best_bid()? + price.best_ask()?) / 2.0;
+        let start = self.period_start(time)?;
+
+        match &mut self.current {
+            Some((current_start, ohlc, volume)) if *current_start == start => {
+                ohlc.high = ohlc.high.max(mid);
+                ohlc.low = ohlc.low.min(mid);
+                ohlc.close = mid;
+                *volume += 1;
+                None
+            }
+            Some((current_start, _, _)) if *current_start > start => None,
+            _ => {
+                let ohlc = Ohlc { open: mid, high: mid, low: mid, close: mid };
+                let completed = self.current.replace((start, ohlc, 1));
+                completed.map(|candle| to_candlestick(candle, true))
+            }
+        }
+    }
+
+    /// The candle still being built, marked incomplete, leaving the aggregator empty.
+    pub fn flush(&mut self) -> Option<Candlestick> {
+        self.current.take().map(|candle| to_candlestick(candle, false))
+    }
+
+    fn period_start(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let seconds = time.timestamp();
+        DateTime::from_timestamp(seconds - seconds.rem_euclid(self.period_seconds), 0)
+    }
+}
+
+fn to_candlestick((time, ohlc, volume): (DateTime<Utc>, Ohlc, i32), complete: bool) -> Candlestick {
+    Candlestick {
+        time: Some(time),
+        mid: Some(CandlestickData::new()
+            .with_o(ohlc.open)
+            .with_h(ohlc.high)
+            .with_l(ohlc.low)
+            .with_c(ohlc.close)),
+        volume: Some(volume),
+        complete: Some(complete),
+        ..Candlestick::new()
+    }
+}
@@ -1,9 +1,17 @@
 use crate::*;
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// The main client for interacting with the Oanda V20 API.
 ///
 /// The client holds the HTTP client, host information, and authentication details
-/// required to make authenticated requests to the API endpoints.
+/// required to make authenticated requests to the API endpoints. Its other settings
+/// are private and changed with the `with_*` methods, so create one with
+/// [`Client::new`], [`Client::from_env`] or [`Client::builder`] rather than a
+/// struct literal.
 ///
 /// # Example
 ///
@@ -13,20 +21,746 @@ use crate::*;
 /// let api_key = env::var("OANDA_KEY").unwrap();
 /// let api_host = env::var("OANDA_HOST").unwrap();
 ///
-/// let client = Client {
-///     host: api_host,
-///     reqwest: reqwest::Client::new(),
-///     authentication: api_key,
-/// };
+/// let client = Client::new(api_host, api_key);
 /// ```
-#[derive(Debug)]
+///
+/// Or let [`Client::builder`] fill in the host for an environment:
+///
+/// ```no_run
+/// # use std::env;
+/// # use fxoanda::Client;
+/// let client = Client::builder()
+///     .practice()
+///     .token(env::var("OANDA_KEY").unwrap())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Default)]
 pub struct Client {
     /// The `reqwest::Client` used for making HTTP requests.
-    pub reqwest: reqwest::Client,
-    /// The Oanda API host (e.g., "api-fxpractice.oanda.com"). Do not include `https://`.
-    pub host: String,
+    pub(crate) reqwest: reqwest::Client,
+    /// The Oanda API host (e.g., "api-fxpractice.oanda.com"). Do not include `https://`;
+    /// an explicit `http://` scheme is only meant for pointing the client at a local test server.
+    pub(crate) host: String,
     /// The Oanda API authentication token (API Key).
-    pub authentication: String,
+    pub(crate) authentication: String,
+    /// Treat an empty account list as `FxError::Unauthorized`. See [`Client::with_strict_auth`].
+    pub(crate) strict_auth: bool,
+    /// How response bodies appear in debug-level `tracing` events. See [`Client::with_body_logging`].
+    pub(crate) body_logging: BodyLogging,
+    /// The OANDA environment to connect to. When set, it takes precedence over `host`
+    /// and routes streaming endpoints to the environment's stream host.
+    pub(crate) environment: Option<OandaEnvironment>,
+    /// How failed requests are retried. See [`Client::with_retry`].
+    pub(crate) retry: RetryPolicy,
+    /// The rate limit reported with the most recent response. See [`Client::rate_limit`].
+    pub(crate) rate_limit: RateLimitTracker,
+    /// Cached responses of slowly-changing read-only endpoints. See [`Client::with_cache_ttl`].
+    pub(crate) cache: ResponseCache,
+    /// The `Accept-Datetime-Format` sent with every request. See [`Client::with_datetime_format`].
+    pub(crate) datetime_format: DatetimeFormat,
+}
+
+impl Client {
+    /// A client for `host` that authenticates with the API token `authentication`,
+    /// with every other setting at its default. Change those with the `with_*` methods.
+    pub fn new(host: impl Into<String>, authentication: impl Into<String>) -> Client {
+        Client {
+            host: host.into(),
+            authentication: authentication.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Send requests with a preconfigured `reqwest::Client`, e.g. one with a proxy.
+    pub fn with_reqwest(mut self, reqwest: reqwest::Client) -> Self {
+        self.reqwest = reqwest;
+        self
+    }
+
+    /// Start building a client for one of OANDA's environments.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Create a client from the `OANDA_KEY` and `OANDA_HOST` environment variables.
+    ///
+    /// Surrounding whitespace and an `https://` prefix are removed from the host, so a
+    /// pasted URL like `https://api-fxpractice.oanda.com/` works. Fails with
+    /// `RequestValidationError::MissingCredentials` if either variable is unset or empty.
+    pub fn from_env() -> Result<Client, FxError> {
+        let authentication = env_credential("OANDA_KEY")?;
+        let host = env_credential("OANDA_HOST")?;
+        let host = host.strip_prefix("https://").unwrap_or(&host).trim_end_matches('/').to_string();
+        Ok(Client::new(host, authentication))
+    }
+
+    /// Report bad credentials as `FxError::Unauthorized` from `list_accounts`.
+    ///
+    /// OANDA answers an invalid token on the account list with a 200 and no
+    /// accounts rather than a 401. A valid token always has at least one account,
+    /// so in strict mode an empty list is taken to mean the token was rejected.
+    pub fn with_strict_auth(mut self, strict: bool) -> Self {
+        self.strict_auth = strict;
+        self
+    }
+
+    /// Retry requests that fail transiently, per `policy`. By default nothing is retried.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Keep successful responses of slowly-changing, read-only endpoints for `ttl`,
+    /// answering repeats of the same request from memory until then.
+    ///
+    /// Only endpoints whose data rarely changes are cached, currently
+    /// `GetAccountInstrumentsRequest`; orders, trades, positions and prices are always
+    /// fetched. Caching is off by default.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = ResponseCache::new(ttl);
+        self
+    }
+
+    /// Ask OANDA for times in `format` on every request, streams included, unless a
+    /// request sets its own with `with_accept_datetime_format`.
+    ///
+    /// Response times are read in either format. Candle requests send their `from`
+    /// and `to`, and pricing requests their `since`, in the chosen format too; other
+    /// requests' time parameters are always sent as RFC 3339.
+    pub fn with_datetime_format(mut self, format: DatetimeFormat) -> Self {
+        self.datetime_format = format;
+        self
+    }
+
+    /// The API host requests are sent to, unless an environment was given.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// The API token requests authenticate with.
+    pub fn authentication(&self) -> &str {
+        &self.authentication
+    }
+
+    /// The `reqwest::Client` requests are sent with. See [`Client::with_reqwest`].
+    pub fn reqwest(&self) -> &reqwest::Client {
+        &self.reqwest
+    }
+
+    /// The environment the client was built for, if it was given one.
+    pub fn environment(&self) -> Option<OandaEnvironment> {
+        self.environment
+    }
+
+    /// The format times are asked for in. See [`Client::with_datetime_format`].
+    pub fn datetime_format(&self) -> DatetimeFormat {
+        self.datetime_format
+    }
+
+    /// The cached responses. See [`Client::with_cache_ttl`].
+    pub fn cache(&self) -> &ResponseCache {
+        &self.cache
+    }
+
+    /// The rate-limit headers of the most recent response that carried any.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        self.rate_limit.latest()
+    }
+
+    /// Sends `request`, retrying transient failures according to the client's [`RetryPolicy`].
+    /// Build it with `build_request`, which adds the headers every request carries.
+    pub(crate) async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, FxError> {
+        let mut request = request.build()?;
+        let is_get = request.method() == reqwest::Method::GET;
+        let mut attempt = 0;
+        loop {
+            let retry = if attempt < self.retry.max_retries { request.try_clone() } else { None };
+            let result = self.reqwest.execute(request).await;
+            if let Ok(response) = &result {
+                self.rate_limit.record(response.headers());
+            }
+            request = match (result, retry) {
+                (Ok(response), Some(retry)) if is_get && response.status().is_server_error() => retry,
+                (Err(e), Some(retry)) if e.is_connect() || (is_get && e.is_timeout()) => retry,
+                (result, _) => return result.map_err(FxError::from),
+            };
+            tokio::time::sleep(self.retry.delay(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Choose how response bodies are written to the debug-level `tracing` event
+    /// emitted for every response. Defaults to [`BodyLogging::Truncate`] at
+    /// [`DEFAULT_BODY_LOG_LIMIT`] bytes.
+    pub fn with_body_logging(mut self, body_logging: BodyLogging) -> Self {
+        self.body_logging = body_logging;
+        self
+    }
+
+    pub(crate) fn log_response_body(&self, status: reqwest::StatusCode, body: &str) {
+        tracing::debug!(status = status.as_u16(), body = %self.body_logging.format(body), "OANDA response");
+    }
+
+    /// The scheme and host that request URIs are appended to.
+    pub(crate) fn base_url(&self) -> String {
+        match self.environment {
+            Some(environment) => format!("https://{}", environment.rest_host()),
+            None if self.host.starts_with("http://") || self.host.starts_with("https://") => {
+                self.host.trim_end_matches('/').to_string()
+            }
+            None => format!("https://{}", self.host),
+        }
+    }
+
+    /// `link`, a URL OANDA returned such as a transaction page, moved onto this
+    /// client's base URL so it is fetched from the same host as every other request.
+    pub(crate) fn rebase_url(&self, link: &str) -> Result<String, FxError> {
+        let url = reqwest::Url::parse(link).map_err(|e| FxError::DeserializationError {
+            path: "pages".to_string(),
+            message: format!("invalid page link {}: {}", link, e),
+            source: None,
+        })?;
+        Ok(match url.query() {
+            Some(query) => format!("{}{}?{}", self.base_url(), url.path(), query),
+            None => format!("{}{}", self.base_url(), url.path()),
+        })
+    }
+
+    /// The scheme and host that streaming request URIs are appended to.
+    ///
+    /// OANDA streams from different hosts than it serves REST requests from. Without
+    /// an `environment`, a `host` that is one of OANDA's REST hosts is mapped to the
+    /// matching stream host, and any other host is used as-is.
+    pub(crate) fn stream_base_url(&self) -> String {
+        match self.environment.or_else(|| OandaEnvironment::from_rest_host(&self.host)) {
+            Some(environment) => format!("https://{}", environment.stream_host()),
+            None => self.base_url(),
+        }
+    }
+}
+
+fn env_credential(name: &str) -> Result<String, RequestValidationError> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| RequestValidationError::MissingCredentials(name.to_string()))
+}
+
+/// The header through which a request picks its [`DatetimeFormat`].
+pub(crate) const ACCEPT_DATETIME_FORMAT: &str = "Accept-Datetime-Format";
+
+/// The format OANDA writes times in, chosen per request by its
+/// `Accept-Datetime-Format` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DatetimeFormat {
+    /// RFC 3339 strings such as `2024-01-02T10:00:00.000000000Z`, OANDA's default.
+    #[default]
+    Rfc3339,
+    /// Seconds since the UNIX epoch with nine fractional digits, such as `1704189600.000000000`.
+    Unix,
+}
+
+impl DatetimeFormat {
+    /// The header value naming this format.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DatetimeFormat::Rfc3339 => "RFC3339",
+            DatetimeFormat::Unix => "UNIX",
+        }
+    }
+}
+
+/// An OANDA trading environment and the API hosts that serve it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OandaEnvironment {
+    /// The fxTrade Practice (demo) environment.
+    #[default]
+    Practice,
+    /// The fxTrade (live money) environment.
+    Live,
+}
+
+impl OandaEnvironment {
+    /// The REST API host for this environment, without a scheme.
+    pub fn rest_host(&self) -> &'static str {
+        match self {
+            OandaEnvironment::Practice => "api-fxpractice.oanda.com",
+            OandaEnvironment::Live => "api-fxtrade.oanda.com",
+        }
+    }
+
+    /// The host serving the pricing and transaction streams, without a scheme.
+    pub fn stream_host(&self) -> &'static str {
+        match self {
+            OandaEnvironment::Practice => "stream-fxpractice.oanda.com",
+            OandaEnvironment::Live => "stream-fxtrade.oanda.com",
+        }
+    }
+
+    /// The environment whose REST host is `host`, if it is one of OANDA's.
+    pub fn from_rest_host(host: &str) -> Option<OandaEnvironment> {
+        [OandaEnvironment::Practice, OandaEnvironment::Live]
+            .into_iter()
+            .find(|environment| environment.rest_host() == host)
+    }
+}
+
+/// Rate-limit state reported in response headers.
+///
+/// OANDA's v20 documentation doesn't list rate-limit headers, so both the common
+/// `X-RateLimit-*` names and the IETF draft `RateLimit-*` names are recognised
+/// should a response carry them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window.
+    pub limit: Option<u64>,
+    /// Requests left in the current window.
+    pub remaining: Option<u64>,
+    /// When the window resets, as the server reports it (seconds).
+    pub reset: Option<u64>,
+}
+
+impl RateLimitInfo {
+    /// The rate-limit headers in `headers`, or `None` if there aren't any.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<RateLimitInfo> {
+        let header = |name: &str| {
+            [format!("x-ratelimit-{}", name), format!("ratelimit-{}", name)]
+                .iter()
+                .find_map(|key| headers.get(key.as_str()))
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let info = RateLimitInfo {
+            limit: header("limit"),
+            remaining: header("remaining"),
+            reset: header("reset"),
+        };
+        if info.limit.is_none() && info.remaining.is_none() && info.reset.is_none() {
+            None
+        } else {
+            Some(info)
+        }
+    }
+}
+
+/// Holds the latest [`RateLimitInfo`] seen by a [`Client`].
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitTracker(Arc<Mutex<Option<RateLimitInfo>>>);
+
+impl RateLimitTracker {
+    /// The most recently recorded rate limit.
+    pub fn latest(&self) -> Option<RateLimitInfo> {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record(&self, headers: &reqwest::header::HeaderMap) {
+        if let Some(info) = RateLimitInfo::from_headers(headers) {
+            *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(info);
+        }
+    }
+}
+
+/// Response bodies kept by a [`Client`] for its cacheable endpoints, keyed by request
+/// URL, each for the cache's time to live.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseCache {
+    ttl: Option<Duration>,
+    entries: Arc<Mutex<HashMap<String, (Instant, String)>>>,
+}
+
+impl ResponseCache {
+    /// An empty cache whose entries expire `ttl` after they were fetched.
+    pub fn new(ttl: Duration) -> Self {
+        ResponseCache { ttl: Some(ttl), ..Default::default() }
+    }
+
+    /// Drops every cached response, so the next request of each goes to OANDA.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn get(&self, key: &str, ttl: Duration) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some((fetched, body)) if fetched.elapsed() < ttl => Some(body.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, body: String) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).insert(key, (Instant::now(), body));
+    }
+}
+
+/// When and how often [`Client`] retries a request that failed transiently.
+///
+/// Connection failures are retried for every request, since nothing reached OANDA.
+/// Timeouts and 5xx responses are only retried for GET requests: an order or
+/// account change that timed out may still have been applied, and repeating it
+/// could apply it twice. 4xx responses and validation errors are never retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; zero disables retrying.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each one after it.
+    pub base_delay: Duration,
+    /// Upper bound on the delay between attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay before retry number `attempt` (from zero): exponential backoff,
+    /// capped at `max_delay`, with a random jitter of up to half of it so that
+    /// clients failing together don't retry in lockstep.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter = RandomState::new().build_hasher().finish() % 1000;
+        let half = backoff / 2;
+        half.saturating_add(half.mul_f64(jitter as f64 / 1000.0))
+    }
+}
+
+/// Bytes of a response body logged by default before it is cut off.
+pub const DEFAULT_BODY_LOG_LIMIT: usize = 1024;
+
+/// How much of a response body goes into the client's debug logging.
+///
+/// Candle and transaction responses can run to megabytes, so by default bodies
+/// are truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyLogging {
+    /// Log the whole body.
+    Full,
+    /// Log at most this many bytes of the body, followed by its total length.
+    Truncate(usize),
+    /// Log only the body's length and a hash, to tell responses apart without their contents.
+    Digest,
+}
+
+impl Default for BodyLogging {
+    fn default() -> Self {
+        BodyLogging::Truncate(DEFAULT_BODY_LOG_LIMIT)
+    }
+}
+
+impl BodyLogging {
+    /// Render `body` the way it should appear in the log.
+    pub fn format(&self, body: &str) -> String {
+        match *self {
+            BodyLogging::Full => body.to_string(),
+            BodyLogging::Truncate(limit) if body.len() > limit => {
+                let mut end = limit;
+                while !body.is_char_boundary(end) {
+                    end -= 1;
+                }
+                format!("{}... ({} bytes total)", &body[..end], body.len())
+            }
+            BodyLogging::Truncate(_) => body.to_string(),
+            BodyLogging::Digest => {
+                let mut hasher = DefaultHasher::new();
+                body.hash(&mut hasher);
+                format!("<{} bytes, hash {:016x}>", body.len(), hasher.finish())
+            }
+        }
+    }
+}
+
+/// Builds a [`Client`] for an [`OandaEnvironment`], so the host can't be mistyped.
+///
+/// The environment defaults to practice; a token must be supplied.
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    environment: OandaEnvironment,
+    token: Option<String>,
+    reqwest: Option<reqwest::Client>,
+    datetime_format: DatetimeFormat,
+}
+
+impl ClientBuilder {
+    /// Connect to the fxTrade Practice (demo) environment.
+    pub fn practice(mut self) -> Self {
+        self.environment = OandaEnvironment::Practice;
+        self
+    }
+
+    /// Connect to the fxTrade (live money) environment.
+    pub fn live(mut self) -> Self {
+        self.environment = OandaEnvironment::Live;
+        self
+    }
+
+    /// The API token used to authenticate requests.
+    pub fn token(mut self, token: String) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Use a preconfigured `reqwest::Client` instead of a default one.
+    pub fn reqwest(mut self, reqwest: reqwest::Client) -> Self {
+        self.reqwest = Some(reqwest);
+        self
+    }
+
+    /// The format OANDA should write times in. See [`Client::with_datetime_format`].
+    pub fn datetime_format(mut self, format: DatetimeFormat) -> Self {
+        self.datetime_format = format;
+        self
+    }
+
+    /// Create the client. Fails with `RequestValidationError::MissingToken` if no
+    /// token was supplied.
+    pub fn build(self) -> Result<Client, FxError> {
+        let authentication = self.token
+            .filter(|token| !token.is_empty())
+            .ok_or(RequestValidationError::MissingToken)?;
+        Ok(Client {
+            reqwest: self.reqwest.unwrap_or_default(),
+            host: self.environment.rest_host().to_string(),
+            environment: Some(self.environment),
+            authentication,
+            datetime_format: self.datetime_format,
+            ..Default::default()
+        })
+    }
+}
+
+/// An authenticated `method` request for the endpoint at `uri`, with `query` as its
+/// query string and `timeout`, if any, as its own time limit. `uri` is a path such
+/// as `/v3/accounts/{id}/pricing` under the client's base URL, or an absolute URL
+/// used as given: a page link from `rebase_url`, or a path under `stream_base_url`.
+/// Times are asked for in `datetime_format`, the request's `AcceptDatetimeFormat`
+/// header, or else the client's [`DatetimeFormat`].
+pub(crate) fn build_request<Q: serde::Serialize + ?Sized>(
+    client: &Client,
+    method: reqwest::Method,
+    uri: &str,
+    query: &Q,
+    datetime_format: Option<&str>,
+    timeout: Option<Duration>,
+) -> reqwest::RequestBuilder {
+    let url = if uri.starts_with("http://") || uri.starts_with("https://") {
+        uri.to_string()
+    } else {
+        format!("{}{}", client.base_url(), uri)
+    };
+    let mut request = client.reqwest.request(method, url)
+        .query(query)
+        .header(ACCEPT_DATETIME_FORMAT, datetime_format.unwrap_or(client.datetime_format.as_str()))
+        .bearer_auth(&client.authentication);
+    if let Some(timeout) = timeout {
+        request = request.timeout(timeout);
+    }
+    request
+}
+
+/// GETs the endpoint at `uri` and reads the response; see `build_request` and `read_response`.
+///
+/// There's no POST counterpart: every POST creates an order, whose response is read
+/// by `read_order_response` instead.
+pub(crate) async fn execute_get<T, Q>(client: &Client, uri: &str, query: &Q, datetime_format: Option<&str>, timeout: Option<Duration>) -> Result<T, FxError>
+where
+    T: serde::de::DeserializeOwned,
+    Q: serde::Serialize + ?Sized,
+{
+    let response = client.send(build_request(client, reqwest::Method::GET, uri, query, datetime_format, timeout)).await?;
+    read_response(client, response).await
+}
+
+/// PUTs `body` as JSON to the endpoint at `uri` and reads the response.
+pub(crate) async fn execute_put<T, Q, B>(client: &Client, uri: &str, query: &Q, body: &B, datetime_format: Option<&str>, timeout: Option<Duration>) -> Result<T, FxError>
+where
+    T: serde::de::DeserializeOwned,
+    Q: serde::Serialize + ?Sized,
+    B: serde::Serialize + ?Sized,
+{
+    execute_with_body(client, reqwest::Method::PUT, uri, query, body, datetime_format, timeout).await
+}
+
+/// PATCHes `body` as JSON to the endpoint at `uri` and reads the response.
+pub(crate) async fn execute_patch<T, Q, B>(client: &Client, uri: &str, query: &Q, body: &B, datetime_format: Option<&str>, timeout: Option<Duration>) -> Result<T, FxError>
+where
+    T: serde::de::DeserializeOwned,
+    Q: serde::Serialize + ?Sized,
+    B: serde::Serialize + ?Sized,
+{
+    execute_with_body(client, reqwest::Method::PATCH, uri, query, body, datetime_format, timeout).await
+}
+
+async fn execute_with_body<T, Q, B>(
+    client: &Client,
+    method: reqwest::Method,
+    uri: &str,
+    query: &Q,
+    body: &B,
+    datetime_format: Option<&str>,
+    timeout: Option<Duration>,
+) -> Result<T, FxError>
+where
+    T: serde::de::DeserializeOwned,
+    Q: serde::Serialize + ?Sized,
+    B: serde::Serialize + ?Sized,
+{
+    let request = build_request(client, method, uri, query, datetime_format, timeout).json(body);
+    let response = client.send(request).await?;
+    read_response(client, response).await
+}
+
+/// Checks an endpoint's response for an OANDA error and deserializes its body.
+///
+/// A 429 becomes `FxError::RateLimited`, and other non-2xx responses become
+/// `FxError::ApiError` built from the body's `errorCode` and `errorMessage`. Some endpoints report failures with a 200 and an `errorMessage`
+/// instead, which would otherwise deserialize into a response of empty fields, so a
+/// successful body carrying one is treated as an error too.
+pub(crate) async fn read_response<T: serde::de::DeserializeOwned>(client: &Client, response: reqwest::Response) -> Result<T, FxError> {
+    let status = response.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(FxError::rate_limited(response.headers()));
+    }
+    let request_id = request_id(response.headers());
+    let response_text = response.text().await.map_err(FxError::from)?;
+    client.log_response_body(status, &response_text);
+    parse_response(status, request_id, &response_text)
+}
+
+/// Sends `request` and reads its response like `read_response`, answering from the
+/// client's [`ResponseCache`] instead while it holds a fresh response to the same URL.
+/// Only successful responses are cached.
+pub(crate) async fn read_cached<T: serde::de::DeserializeOwned>(client: &Client, request: reqwest::RequestBuilder) -> Result<T, FxError> {
+    let Some(ttl) = client.cache.ttl else {
+        let response = client.send(request).await?;
+        return read_response(client, response).await;
+    };
+    let (http, request) = request.build_split();
+    let request = request?;
+    let key = request.url().to_string();
+    if let Some(body) = client.cache.get(&key, ttl) {
+        return parse_response(reqwest::StatusCode::OK, None, &body);
+    }
+
+    let response = client.send(reqwest::RequestBuilder::from_parts(http, request)).await?;
+    let status = response.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(FxError::rate_limited(response.headers()));
+    }
+    let request_id = request_id(response.headers());
+    let response_text = response.text().await.map_err(FxError::from)?;
+    client.log_response_body(status, &response_text);
+    let parsed = parse_response(status, request_id, &response_text)?;
+    client.cache.insert(key, response_text);
+    Ok(parsed)
+}
+
+/// `read_response` for the response to an order creation, which also fails when the
+/// body carries a reject or cancel transaction instead of a fill or a standing order.
+/// See `FxError::from_order_response`.
+pub(crate) async fn read_order_response<T: serde::de::DeserializeOwned>(
+    client: &Client,
+    response: reqwest::Response,
+    instrument: Option<&str>,
+    units: Option<f64>,
+) -> Result<T, FxError> {
+    let status = response.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(FxError::rate_limited(response.headers()));
+    }
+    let request_id = request_id(response.headers());
+    let response_text = response.text().await.map_err(FxError::from)?;
+    client.log_response_body(status, &response_text);
+
+    if let Ok(body) = serde_json::from_str::<OandaErrorResponse>(&response_text) {
+        if let Some(order_error) = FxError::from_order_response(&body, instrument, units) {
+            return Err(order_error);
+        }
+    }
+    parse_response(status, request_id, &response_text)
+}
+
+/// `read_response` for the response to an order replacement, which also fails when
+/// the body shows that nothing was replaced. See `FxError::from_replace_response`.
+pub(crate) async fn read_replace_response<T: serde::de::DeserializeOwned>(client: &Client, response: reqwest::Response) -> Result<T, FxError> {
+    let status = response.status();
+    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(FxError::rate_limited(response.headers()));
+    }
+    let request_id = request_id(response.headers());
+    let response_text = response.text().await.map_err(FxError::from)?;
+    client.log_response_body(status, &response_text);
+
+    if status.is_success() {
+        if let Ok(body) = serde_json::from_str::<OandaErrorResponse>(&response_text) {
+            if let Some(replace_error) = FxError::from_replace_response(&body) {
+                return Err(replace_error);
+            }
+        }
+    }
+    parse_response(status, request_id, &response_text)
+}
+
+/// Deserializes a response body, or fails with the error it reports.
+///
+/// OANDA occasionally answers with a 2xx and an empty body, notably for candle
+/// ranges with nothing in them. That's read as `{}`, giving a response with every
+/// field unset (`candles: None`), or `FxError::EmptyResponse` for a response type
+/// with required fields.
+fn parse_response<T: serde::de::DeserializeOwned>(status: reqwest::StatusCode, request_id: Option<String>, response_text: &str) -> Result<T, FxError> {
+    if !status.is_success() {
+        return Err(status_error(status, request_id, response_text));
+    }
+    if response_text.trim().is_empty() {
+        return serde_json::from_str("{}").map_err(|_| FxError::EmptyResponse { status_code: status.as_u16() });
+    }
+    if let Ok(body) = serde_json::from_str::<OandaErrorResponse>(response_text) {
+        if body.error_message.is_some() {
+            return Err(FxError::from_error_body(status.as_u16(), request_id, &body));
+        }
+    }
+
+    let deserializer = &mut serde_json::Deserializer::from_str(response_text);
+    serde_path_to_error::deserialize::<_, T>(deserializer).map_err(FxError::from)
+}
+
+/// Deserializes an already-parsed JSON `value`, such as a line of a stream, naming
+/// the offending field in the `DeserializationError` if it doesn't fit `T`.
+pub(crate) fn from_json_value<T: serde::de::DeserializeOwned>(value: serde_json::Value) -> Result<T, FxError> {
+    serde_path_to_error::deserialize(value).map_err(FxError::from)
+}
+
+/// The `FxError::ApiError` for a non-2xx response with body `response_text`.
+pub(crate) fn status_error(status: reqwest::StatusCode, request_id: Option<String>, response_text: &str) -> FxError {
+    match serde_json::from_str::<OandaErrorResponse>(response_text) {
+        Ok(body) => FxError::from_error_body(status.as_u16(), request_id, &body),
+        Err(_) => FxError::ApiError {
+            status_code: status.as_u16(),
+            error_code: "UNPARSEABLE_RESPONSE".to_string(),
+            error_message: "Could not parse error response".to_string(),
+            request_id,
+        },
+    }
+}
+
+/// The `RequestID` header OANDA tags every response with.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers.get("RequestID")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
 }
 
 // Macro for modules that still return Box<dyn Error>
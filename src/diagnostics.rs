@@ -0,0 +1,75 @@
+use chrono::prelude::*;
+use chrono::Duration;
+use crate::account::ListAccountsRequest;
+use crate::client::{build_request, Client};
+use crate::errors::FxError;
+
+/// How far the server's clock is ahead of `now`, going by an HTTP `Date` header
+/// such as `Fri, 15 Nov 2024 08:12:31 GMT`. Negative when the local clock is ahead.
+///
+/// Logs a warning when the skew exceeds `threshold` in either direction; GTD
+/// times and candle alignment quietly go wrong once clocks drift that far.
+/// Returns `None` if the header can't be parsed.
+pub fn clock_skew(date_header: &str, now: DateTime<Utc>, threshold: Duration) -> Option<Duration> {
+    let server_time = parse_http_date(date_header)?;
+    let skew = server_time - now;
+    if skew.abs() > threshold {
+        tracing::warn!(
+            skew_ms = skew.num_milliseconds(),
+            threshold_ms = threshold.num_milliseconds(),
+            "local clock differs from OANDA server time by more than the threshold"
+        );
+    }
+    Some(skew)
+}
+
+impl Client {
+    /// OANDA's current time, from the `Date` header of a request for the account
+    /// list, the cheapest authenticated call. The header has one-second resolution.
+    ///
+    /// The header is read whatever the response status, so this works with a
+    /// rejected token too. Pass the header to [`clock_skew`] to compare clocks.
+    pub async fn server_time(&self) -> Result<DateTime<Utc>, FxError> {
+        let no_query: &[(&str, &str)] = &[];
+        let request = build_request(self, reqwest::Method::GET, "/v3/accounts", no_query, None, None);
+        let response = self.send(request).await?;
+        response.headers()
+            .get(reqwest::header::DATE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_http_date)
+            .ok_or_else(|| FxError::DeserializationError {
+                path: "Date".to_string(),
+                message: "response has no valid Date header".to_string(),
+                source: None,
+            })
+    }
+
+    /// Checks that OANDA is reachable and accepts the client's token, with a single
+    /// request for the account list, the cheapest authenticated call.
+    ///
+    /// A rejected token fails with an `ApiError` with error code `INVALID_AUTH`: for
+    /// HTTP 401 or 403 with that status, and with status 401 for a 200 whose account
+    /// list is missing or empty, whatever the client's `strict_auth` setting.
+    pub async fn ping(&self) -> Result<(), FxError> {
+        let invalid_auth = |status_code, error_message: String, request_id| FxError::ApiError {
+            status_code,
+            error_code: "INVALID_AUTH".to_string(),
+            error_message,
+            request_id,
+        };
+        match ListAccountsRequest::new().remote(self).await {
+            Ok(accounts) if accounts.accounts.as_ref().is_some_and(|accounts| !accounts.is_empty()) => Ok(()),
+            Ok(_) | Err(FxError::Unauthorized(_)) => {
+                Err(invalid_auth(401, "no accounts are visible to this token; the token was not accepted".to_string(), None))
+            }
+            Err(FxError::ApiError { status_code: status_code @ (401 | 403), error_message, request_id, .. }) => {
+                Err(invalid_auth(status_code, error_message, request_id))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn parse_http_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc2822(value).ok().map(|date| date.with_timezone(&Utc))
+}
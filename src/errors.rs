@@ -1,4 +1,8 @@
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use fxoanda_definitions::OrderCancelTransaction;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RequestValidationError {
@@ -7,6 +11,44 @@ pub enum RequestValidationError {
     MissingInstrument,
     MissingTransactionId,
     MissingOrderSpecifier,
+    /// No API token was given to a `ClientBuilder`.
+    MissingToken,
+    /// The named environment variable needed by `Client::from_env` is unset or empty.
+    MissingCredentials(String),
+    /// The stop loss is on the wrong side of the entry price for the order's direction.
+    InvalidStopLoss,
+    /// The take profit is on the wrong side of the entry price for the order's direction.
+    InvalidTakeProfit,
+    /// The order's units are outside the instrument's minimum trade size and maximum order units.
+    UnitsOutOfRange { units: f64, minimum: f64, maximum: f64 },
+    /// A price has more decimal places than the instrument's display precision.
+    PricePrecision { price: f64, display_precision: i32 },
+    /// A guaranteed stop loss or trailing stop is closer to the entry than the instrument allows.
+    StopDistanceTooSmall { distance: f64, minimum: f64 },
+    /// The trigger condition isn't one of OANDA's `OrderTriggerCondition` values.
+    InvalidTriggerCondition(String),
+    /// A limit or stop order's price is zero or negative.
+    InvalidPrice,
+    /// An order creation request was sent without an order.
+    MissingOrder,
+    /// An order to create has no units.
+    MissingUnits,
+    /// A limit or stop order to create has no price.
+    MissingPrice,
+    /// The fraction of the balance to risk isn't above zero and at most one.
+    InvalidRiskFraction(f64),
+    /// A stop distance to size a position by isn't a finite number above zero.
+    InvalidStopDistance(f64),
+    /// An account ID isn't of the `NNN-NNN-NNNNNNN-NNN` form.
+    InvalidAccountId(String),
+    /// An instrument name isn't of the `BASE_QUOTE` form.
+    InvalidInstrument(String),
+    /// A candles request sets `count` together with both `from` and `to`.
+    ConflictingCandleParams,
+    /// A request's `from` is after its `to`.
+    InvalidTimeRange { from: DateTime<Utc>, to: DateTime<Utc> },
+    /// A candles request asks for more candles than OANDA returns at once.
+    CountTooLarge { count: i32, max: i32 },
     // Add other missing parameter types as needed
 }
 
@@ -23,12 +65,87 @@ impl fmt::Display for RequestValidationError {
                 write!(f, "Transaction ID is required but was not provided"),
             RequestValidationError::MissingOrderSpecifier => 
                 write!(f, "Order specifier is required but was not provided"),
+            RequestValidationError::MissingToken => 
+                write!(f, "API token is required but was not provided"),
+            RequestValidationError::MissingCredentials(var) => 
+                write!(f, "Environment variable {} is required but was not set", var),
+            RequestValidationError::InvalidStopLoss => 
+                write!(f, "Stop loss must be below the entry price for a buy and above it for a sell"),
+            RequestValidationError::InvalidTakeProfit => 
+                write!(f, "Take profit must be above the entry price for a buy and below it for a sell"),
+            RequestValidationError::UnitsOutOfRange { units, minimum, maximum } => 
+                write!(f, "Order units {} must be between {} and {} in magnitude", units, minimum, maximum),
+            RequestValidationError::PricePrecision { price, display_precision } => 
+                write!(f, "Price {} has more than {} decimal places", price, display_precision),
+            RequestValidationError::StopDistanceTooSmall { distance, minimum } => 
+                write!(f, "Distance {} from the entry price is below the minimum of {}", distance, minimum),
+            RequestValidationError::InvalidTriggerCondition(condition) => 
+                write!(f, "Unknown trigger condition '{}'", condition),
+            RequestValidationError::InvalidPrice => 
+                write!(f, "Order price must be greater than zero"),
+            RequestValidationError::MissingOrder => 
+                write!(f, "Order is required but was not provided"),
+            RequestValidationError::MissingUnits => 
+                write!(f, "Order units are required but were not provided"),
+            RequestValidationError::MissingPrice => 
+                write!(f, "Order price is required but was not provided"),
+            RequestValidationError::InvalidRiskFraction(fraction) => 
+                write!(f, "Risk fraction {} must be greater than zero and at most one", fraction),
+            RequestValidationError::InvalidStopDistance(distance) => 
+                write!(f, "Stop distance {} must be a finite number greater than zero", distance),
+            RequestValidationError::InvalidAccountId(id) => 
+                write!(f, "Account ID '{}' is not of the form NNN-NNN-NNNNNNN-NNN", id),
+            RequestValidationError::InvalidInstrument(name) => 
+                write!(f, "Instrument '{}' is not of the form BASE_QUOTE", name),
+            RequestValidationError::ConflictingCandleParams => 
+                write!(f, "Candle count cannot be set together with both from and to"),
+            RequestValidationError::InvalidTimeRange { from, to } => 
+                write!(f, "Range start {} is after its end {}", from.to_rfc3339(), to.to_rfc3339()),
+            RequestValidationError::CountTooLarge { count, max } => 
+                write!(f, "Candle count {} is above the maximum of {}", count, max),
         }
     }
 }
 
 impl std::error::Error for RequestValidationError {}
 
+/// The error fields of an OANDA response body. Failed requests carry `errorCode`
+/// and `errorMessage`; failed order creations also carry the transaction that
+/// rejected or cancelled the order. Every field is optional: a successful body
+/// parses too, with none of the error fields set.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OandaErrorResponse {
+    #[serde(rename = "errorCode")]
+    pub error_code: Option<String>,
+    #[serde(rename = "errorMessage")]
+    pub error_message: Option<String>,
+    #[serde(rename = "rejectReason")]
+    pub reject_reason: Option<String>,
+    #[serde(rename = "orderRejectTransaction")]
+    pub order_reject_transaction: Option<OrderRejectDetails>,
+    #[serde(rename = "orderCancelTransaction")]
+    pub order_cancel_transaction: Option<OrderCancelTransaction>,
+    /// Only whether a fill is present matters: a cancel next to one is the unfilled
+    /// remainder of an IOC order, not a failure.
+    #[serde(rename = "orderFillTransaction")]
+    pub order_fill_transaction: Option<serde::de::IgnoredAny>,
+    /// Whether an order was created; a replacement without one replaced nothing.
+    #[serde(rename = "orderCreateTransaction")]
+    pub order_create_transaction: Option<serde::de::IgnoredAny>,
+    /// The replacing order, cancelled as soon as it was created.
+    #[serde(rename = "replacingOrderCancelTransaction")]
+    pub replacing_order_cancel_transaction: Option<OrderCancelTransaction>,
+}
+
+/// The fields shared by every kind of order reject transaction.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OrderRejectDetails {
+    #[serde(rename = "rejectReason")]
+    pub reject_reason: Option<String>,
+    pub instrument: Option<String>,
+    pub units: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub enum FxError {
     OrderRejection {
@@ -42,12 +159,50 @@ pub enum FxError {
         status_code: u16,
         error_code: String,
         error_message: String,
+        /// The response's `RequestID` header, which OANDA support asks for when
+        /// investigating a failed request.
+        request_id: Option<String>,
     },
     DeserializationError {
         path: String,
         message: String,
+        /// The `serde_json` error behind the failure, also returned by `source()`.
+        /// `None` when a response parsed but lacked something it should hold.
+        source: Option<Arc<serde_json::Error>>,
+    },
+    /// A FOK/IOC order was cancelled without any fill because the market couldn't
+    /// fill it. The order may succeed if retried with fewer units. A partial fill is
+    /// returned as the response instead, with the cancel next to it.
+    InsufficientLiquidity {
+        instrument: String,
+        units: String,
+        order_id: String,
     },
-    HttpError(String),
+    /// The instrument isn't tradeable right now (outside trading hours or halted).
+    /// The order can be resubmitted once the market reopens.
+    MarketClosed {
+        instrument: String,
+    },
+    /// The API token was not accepted. Only raised for `list_accounts` on a client
+    /// with `strict_auth` enabled.
+    Unauthorized(String),
+    /// OANDA answered HTTP 429. `retry_after` is how long it asked the client to wait,
+    /// from the `Retry-After` header, if it sent one.
+    RateLimited {
+        retry_after: Option<Duration>,
+    },
+    /// An operation didn't complete within its time limit, including HTTP requests
+    /// that ran past their `reqwest` timeout. For those the `reqwest` error is kept,
+    /// and also returned by `source()`; a timeout the crate enforces itself has none.
+    Timeout(Option<Arc<reqwest::Error>>),
+    /// A successful response had an empty body where the endpoint's response has
+    /// fields it can't do without.
+    EmptyResponse {
+        status_code: u16,
+    },
+    /// The request couldn't be sent or its response couldn't be read. The `reqwest`
+    /// error, also returned by `source()`, tells a connect failure from a broken body.
+    HttpError(Arc<reqwest::Error>),
     Validation(RequestValidationError),
 }
 
@@ -55,18 +210,47 @@ impl fmt::Display for FxError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             FxError::OrderRejection { instrument, units, reject_reason, error_code, error_message } => {
-                write!(f, "OANDA rejected market order for {} {} units. Reason: {} ({}). Details: {}", 
+                write!(f, "OANDA rejected order for {} {} units. Reason: {} ({}). Details: {}", 
                        instrument, units, reject_reason, error_code, error_message)
             },
-            FxError::ApiError { status_code, error_code, error_message } => {
+            FxError::ApiError { status_code, error_code, error_message, request_id: Some(request_id) } => {
+                write!(f, "OANDA API error (HTTP {}): {} ({}) [RequestID {}]", 
+                       status_code, error_message, error_code, request_id)
+            },
+            FxError::ApiError { status_code, error_code, error_message, request_id: None } => {
                 write!(f, "OANDA API error (HTTP {}): {} ({})", 
                        status_code, error_message, error_code)
             },
-            FxError::DeserializationError { path, message } => {
+            FxError::DeserializationError { path, message, .. } => {
                 write!(f, "Deserialization failed at path '{}': {}", path, message)
             },
-            FxError::HttpError(msg) => {
-                write!(f, "HTTP request failed: {}", msg)
+            FxError::InsufficientLiquidity { instrument, units, order_id } => {
+                write!(f, "OANDA cancelled order {} for {} {} units due to insufficient liquidity", 
+                       order_id, instrument, units)
+            },
+            FxError::MarketClosed { instrument } => {
+                write!(f, "OANDA is not accepting orders for {}: market is closed or halted", instrument)
+            },
+            FxError::Unauthorized(msg) => {
+                write!(f, "OANDA authentication failed: {}", msg)
+            },
+            FxError::RateLimited { retry_after: Some(retry_after) } => {
+                write!(f, "OANDA rate limit exceeded; retry after {:?}", retry_after)
+            },
+            FxError::RateLimited { retry_after: None } => {
+                write!(f, "OANDA rate limit exceeded")
+            },
+            FxError::Timeout(Some(err)) => {
+                write!(f, "Timed out: {}", err)
+            },
+            FxError::Timeout(None) => {
+                write!(f, "Timed out")
+            },
+            FxError::EmptyResponse { status_code } => {
+                write!(f, "OANDA sent an empty response body (HTTP {})", status_code)
+            },
+            FxError::HttpError(err) => {
+                write!(f, "HTTP request failed: {}", err)
             },
             FxError::Validation(validation_error) => {
                 write!(f, "{}", validation_error)
@@ -75,7 +259,169 @@ impl fmt::Display for FxError {
     }
 }
 
-impl std::error::Error for FxError {}
+impl std::error::Error for FxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FxError::HttpError(err) | FxError::Timeout(Some(err)) => Some(err.as_ref()),
+            FxError::DeserializationError { source: Some(err), .. } => Some(err.as_ref()),
+            FxError::Validation(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl FxError {
+    /// Maps the `orderCancelTransaction` of an order creation response to a typed error
+    /// for the cancel reasons callers are expected to act on. Returns `None` for any
+    /// other cancellation, leaving the response to be returned as-is.
+    pub(crate) fn from_order_cancel(cancel: &OrderCancelTransaction, instrument: Option<&str>, units: Option<f64>) -> Option<FxError> {
+        let instrument = instrument.unwrap_or("UNKNOWN_INSTRUMENT").to_string();
+        let units = units.map(|u| u.to_string()).unwrap_or_else(|| "UNKNOWN_UNITS".to_string());
+        let order_id = cancel.order_id.clone().unwrap_or_default();
+        match cancel.reason.as_deref() {
+            Some("INSUFFICIENT_LIQUIDITY") => Some(FxError::InsufficientLiquidity { instrument, units, order_id }),
+            Some(reason) if Self::is_market_closed_reason(reason) => Some(FxError::MarketClosed { instrument }),
+            _ => None,
+        }
+    }
+}
+
+impl FxError {
+    /// The error an order creation response reports in place of a fill or a standing
+    /// order, whatever its HTTP status.
+    ///
+    /// An `orderRejectTransaction` becomes an `OrderRejection`, or `MarketClosed` for
+    /// the market-closed reasons. A response with an `orderFillTransaction` is never an
+    /// error, whatever became of the unfilled remainder. Otherwise an
+    /// `orderCancelTransaction` becomes the typed error of `from_order_cancel`, or an
+    /// `OrderRejection`. `instrument` and `units`
+    /// are the order's own, used where the transaction doesn't repeat them.
+    pub(crate) fn from_order_response(body: &OandaErrorResponse, instrument: Option<&str>, units: Option<f64>) -> Option<FxError> {
+        if let Some(reject) = &body.order_reject_transaction {
+            let reject_reason = reject.reject_reason.as_deref()
+                .or(body.reject_reason.as_deref())
+                .unwrap_or("UNKNOWN_REJECT_REASON");
+            let instrument = reject.instrument.as_deref()
+                .or(instrument)
+                .unwrap_or("UNKNOWN_INSTRUMENT");
+            if Self::is_market_closed_reason(reject_reason) {
+                return Some(FxError::MarketClosed { instrument: instrument.to_string() });
+            }
+            let units = reject.units.clone()
+                .or_else(|| units.map(|u| u.to_string()))
+                .unwrap_or_else(|| "UNKNOWN_UNITS".to_string());
+            return Some(FxError::OrderRejection {
+                instrument: instrument.to_string(),
+                units,
+                reject_reason: reject_reason.to_string(),
+                error_code: body.error_code.as_deref().unwrap_or("ORDER_REJECTED").to_string(),
+                error_message: body.error_message.as_deref().unwrap_or("The order was rejected").to_string(),
+            });
+        }
+
+        // A cancel next to a fill is the unfilled remainder of an IOC order. The fill
+        // stands, so the response is returned rather than an error that invites a retry.
+        if body.order_fill_transaction.is_some() {
+            return None;
+        }
+        let cancel = body.order_cancel_transaction.as_ref()?;
+        if let Some(cancel_error) = Self::from_order_cancel(cancel, instrument, units) {
+            return Some(cancel_error);
+        }
+        Some(FxError::OrderRejection {
+            instrument: instrument.unwrap_or("UNKNOWN_INSTRUMENT").to_string(),
+            units: units.map(|u| u.to_string()).unwrap_or_else(|| "UNKNOWN_UNITS".to_string()),
+            reject_reason: cancel.reason.clone().unwrap_or_else(|| "UNKNOWN_REJECT_REASON".to_string()),
+            error_code: body.error_code.as_deref().unwrap_or("ORDER_CANCELLED").to_string(),
+            error_message: body.error_message.as_deref().unwrap_or("The order was cancelled when it was created").to_string(),
+        })
+    }
+
+    /// The error a successful order replacement response reports in place of a
+    /// replacing order.
+    ///
+    /// Replacing cancels the order and creates its successor, so a response without
+    /// an `orderCreateTransaction` replaced nothing: the order had filled or been
+    /// cancelled in the meantime. That becomes an `OrderRejection` with the cancel's
+    /// reason, or `ORDER_DOESNT_EXIST` without one. A successor rejected, or
+    /// cancelled on creation without filling, is reported as for order creation.
+    pub(crate) fn from_replace_response(body: &OandaErrorResponse) -> Option<FxError> {
+        if body.order_reject_transaction.is_some() {
+            return Self::from_order_response(body, None, None);
+        }
+        if body.order_create_transaction.is_none() {
+            let reject_reason = body.order_cancel_transaction.as_ref()
+                .and_then(|cancel| cancel.reason.clone())
+                .filter(|reason| reason != "CLIENT_REQUEST_REPLACED")
+                .unwrap_or_else(|| "ORDER_DOESNT_EXIST".to_string());
+            return Some(FxError::OrderRejection {
+                instrument: "UNKNOWN_INSTRUMENT".to_string(),
+                units: "UNKNOWN_UNITS".to_string(),
+                reject_reason,
+                error_code: body.error_code.as_deref().unwrap_or("ORDER_DOESNT_EXIST").to_string(),
+                error_message: body.error_message.as_deref()
+                    .unwrap_or("The order was not replaced: it has already filled or been cancelled")
+                    .to_string(),
+            });
+        }
+        let cancel = body.replacing_order_cancel_transaction.clone()?;
+        Self::from_order_response(&OandaErrorResponse { order_cancel_transaction: Some(cancel), ..body.clone() }, None, None)
+    }
+
+    /// An `ApiError` from the `errorCode` and `errorMessage` of an OANDA error body.
+    pub(crate) fn from_error_body(status_code: u16, request_id: Option<String>, body: &OandaErrorResponse) -> FxError {
+        FxError::ApiError {
+            status_code,
+            error_code: body.error_code.as_deref().unwrap_or("UNKNOWN_ERROR_CODE").to_string(),
+            error_message: body.error_message.as_deref().unwrap_or("Unknown error").to_string(),
+            request_id,
+        }
+    }
+
+    /// A `RateLimited` error with the wait requested by a 429 response's `Retry-After`
+    /// header, given either as seconds or as an HTTP date.
+    pub(crate) fn rate_limited(headers: &reqwest::header::HeaderMap) -> FxError {
+        let retry_after = headers.get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .and_then(|value| match value.parse::<u64>() {
+                Ok(seconds) => Some(Duration::from_secs(seconds)),
+                Err(_) => DateTime::parse_from_rfc2822(value)
+                    .ok()
+                    .map(|date| (date.with_timezone(&Utc) - Utc::now()).to_std().unwrap_or(Duration::ZERO)),
+            });
+        FxError::RateLimited { retry_after }
+    }
+
+    /// Whether an HTTP request or other operation ran out of time.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, FxError::Timeout(_))
+    }
+
+    /// Whether the connection to OANDA couldn't be made.
+    pub fn is_connect(&self) -> bool {
+        matches!(self, FxError::HttpError(err) if err.is_connect())
+    }
+
+    /// Whether the same request may succeed if sent again: timeouts, connection
+    /// failures, rate limiting and 5xx responses. Rejections, other 4xx responses
+    /// and validation errors won't change on a retry.
+    ///
+    /// For a request that changes the account, a timeout doesn't mean the change
+    /// wasn't made; check before resending an order.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            FxError::Timeout(_) | FxError::RateLimited { .. } => true,
+            FxError::ApiError { status_code, .. } => *status_code == 429 || *status_code >= 500,
+            _ => self.is_connect(),
+        }
+    }
+
+    /// Whether an OANDA cancel or reject reason means the market isn't open for trading.
+    pub(crate) fn is_market_closed_reason(reason: &str) -> bool {
+        matches!(reason, "MARKET_HALTED" | "MARKET_CLOSED")
+    }
+}
 
 impl From<RequestValidationError> for FxError {
     fn from(err: RequestValidationError) -> Self {
@@ -85,7 +431,11 @@ impl From<RequestValidationError> for FxError {
 
 impl From<reqwest::Error> for FxError {
     fn from(err: reqwest::Error) -> Self {
-        FxError::HttpError(err.to_string())
+        if err.is_timeout() {
+            FxError::Timeout(Some(Arc::new(err)))
+        } else {
+            FxError::HttpError(Arc::new(err))
+        }
     }
 }
 
@@ -94,6 +444,7 @@ impl From<serde_path_to_error::Error<serde_json::Error>> for FxError {
         FxError::DeserializationError {
             path: err.path().to_string(),
             message: err.inner().to_string(),
+            source: Some(Arc::new(err.into_inner())),
         }
     }
 }
@@ -105,6 +456,7 @@ impl From<serde_json::Error> for FxError {
         FxError::DeserializationError {
             path: "unknown".to_string(),
             message: err.to_string(),
+            source: Some(Arc::new(err)),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+use std::fmt;
+use crate::errors::RequestValidationError;
+
+/// A transaction, order or trade ID.
+///
+/// OANDA sends these as strings, but they are sequential integers, so comparing
+/// them as strings puts `"10"` before `"9"`. `OandaId` orders numeric IDs by
+/// value, and puts them all before non-numeric IDs, which compare as strings.
+/// It (de)serializes as a plain string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OandaId(String);
+
+impl OandaId {
+    pub fn new(id: impl Into<String>) -> Self {
+        OandaId(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The ID as an integer, if it is numeric.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.0.parse().ok()
+    }
+}
+
+impl Ord for OandaId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Numeric IDs first, so mixed comparisons stay transitive. The string
+        // tie-break keeps "09" and "9" distinct, consistent with Eq.
+        let (a, b) = (self.as_u64(), other.as_u64());
+        (a.is_none(), a, &self.0).cmp(&(b.is_none(), b, &other.0))
+    }
+}
+
+impl PartialOrd for OandaId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for OandaId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for OandaId {
+    fn from(id: String) -> Self {
+        OandaId(id)
+    }
+}
+
+impl From<&str> for OandaId {
+    fn from(id: &str) -> Self {
+        OandaId(id.to_string())
+    }
+}
+
+impl From<OandaId> for String {
+    fn from(id: OandaId) -> Self {
+        id.0
+    }
+}
+
+/// An account ID, checked to be of OANDA's `NNN-NNN-NNNNNNN-NNN` form: four
+/// hyphen-separated groups of digits (site, division, user and account number).
+///
+/// The request builders' `with_account_id` takes an `AccountId` as readily as a
+/// `String`, so a mistyped ID is caught here rather than by a 400 from OANDA.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AccountId(String);
+
+impl AccountId {
+    pub fn new(id: impl Into<String>) -> Result<Self, RequestValidationError> {
+        let id = id.into();
+        let groups: Vec<&str> = id.split('-').collect();
+        let valid = groups.len() == 4
+            && groups.iter().all(|group| !group.is_empty() && group.bytes().all(|b| b.is_ascii_digit()));
+        if valid {
+            Ok(AccountId(id))
+        } else {
+            Err(RequestValidationError::InvalidAccountId(id))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for AccountId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for AccountId {
+    type Err = RequestValidationError;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        AccountId::new(id)
+    }
+}
+
+impl TryFrom<String> for AccountId {
+    type Error = RequestValidationError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        AccountId::new(id)
+    }
+}
+
+impl From<AccountId> for String {
+    fn from(id: AccountId) -> Self {
+        id.0
+    }
+}
+
+/// An instrument name such as `EUR_USD`, split into its base and quote currencies.
+///
+/// It displays and (de)serializes in OANDA's underscore form, and the request
+/// builders' `with_instrument` takes one as readily as a `String`. Both sides
+/// must be non-empty, so `"EUR"` and `"EUR_"` are rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct InstrumentName {
+    base: String,
+    quote: String,
+}
+
+impl InstrumentName {
+    pub fn new(base: impl Into<String>, quote: impl Into<String>) -> Self {
+        InstrumentName { base: base.into(), quote: quote.into() }
+    }
+
+    pub fn parse(name: &str) -> Result<Self, RequestValidationError> {
+        match name.split_once('_') {
+            Some((base, quote)) if !base.is_empty() && !quote.is_empty() && !quote.contains('_') => {
+                Ok(InstrumentName::new(base, quote))
+            }
+            _ => Err(RequestValidationError::InvalidInstrument(name.to_string())),
+        }
+    }
+
+    /// The currency (or commodity) being priced, e.g. `EUR` in `EUR_USD`.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// The currency prices are quoted in, e.g. `USD` in `EUR_USD`.
+    pub fn quote(&self) -> &str {
+        &self.quote
+    }
+}
+
+impl fmt::Display for InstrumentName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.base, self.quote)
+    }
+}
+
+impl std::str::FromStr for InstrumentName {
+    type Err = RequestValidationError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        InstrumentName::parse(name)
+    }
+}
+
+impl TryFrom<String> for InstrumentName {
+    type Error = RequestValidationError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        InstrumentName::parse(&name)
+    }
+}
+
+impl From<InstrumentName> for String {
+    fn from(name: InstrumentName) -> Self {
+        name.to_string()
+    }
+}
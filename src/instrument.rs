@@ -8,7 +8,7 @@ pub mod get_instrument_candles{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -26,7 +26,7 @@ pub mod get_instrument_candles{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="instrument", skip_serializing_if="Option::is_none" )]
@@ -40,7 +40,7 @@ pub mod get_instrument_candles{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -50,7 +50,7 @@ pub mod get_instrument_candles{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="price", skip_serializing_if="Option::is_none" )]
@@ -104,7 +104,7 @@ pub mod get_instrument_candles{
 	/// Get Candlesticks
 /// Fetch candlestick data for an instrument.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetInstrumentCandlesRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -112,6 +112,8 @@ pub mod get_instrument_candles{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetInstrumentCandlesRequest {
@@ -122,6 +124,7 @@ pub mod get_instrument_candles{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -135,8 +138,8 @@ pub mod get_instrument_candles{
 /// a "_".
   /// - param String
 	  /// - return GetInstrumentCandlesRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.path.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -265,44 +268,138 @@ pub mod get_instrument_candles{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`. With `remote_paged`
+	  /// the limit applies to each window's request.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
-		pub async fn remote(self, client: &Client) -> Result<GetInstrumentCandlesResponse, FxError> {
+		pub async fn remote(mut self, client: &Client) -> Result<GetInstrumentCandlesResponse, FxError> {
 	    // Validate required path parameters
-	    let instrument_value = self.path.instrument.ok_or(RequestValidationError::MissingInstrument)?;
+	    let instrument_value = self.path.instrument.take().ok_or(RequestValidationError::MissingInstrument)?;
+	    self.validate_range()?;
+	    
+	    let uri = self.uri.clone()
+		      .replace("{instrument}", &instrument_value);
+		  let request = self.request(client, &uri);
+		  let response = client.send(request).await?;
+	    
+	    crate::client::read_response::<GetInstrumentCandlesResponse>(client, response).await
+		}
+
+	  /// Fetches the candles like `remote`, with their prices read straight into
+	  /// `Decimal`s from the strings OANDA sends.
+	  #[cfg(feature = "decimal")]
+		pub async fn remote_decimal(mut self, client: &Client) -> Result<Vec<DecimalCandlestick>, FxError> {
+	    let instrument_value = self.path.instrument.take().ok_or(RequestValidationError::MissingInstrument)?;
+	    self.validate_range()?;
+	    
+	    let uri = self.uri.clone()
+		      .replace("{instrument}", &instrument_value);
+		  let request = self.request(client, &uri);
+		  let response = client.send(request).await?;
+	    
+	    let page = crate::client::read_response::<DecimalCandles>(client, response).await?;
+	    Ok(page.candles.unwrap_or_default())
+		}
+
+	  /// Fetches every candle between `from` and `to`, however many there are.
+	  ///
+	  /// OANDA returns at most 5000 candles per request, so the range is split into
+	  /// windows of that many candles at the request's granularity, fetched one after
+	  /// another. The candles are returned sorted by time, with any candle repeated at
+	  /// a window boundary kept once; a failed window fails the whole call. Without
+	  /// both `from` and `to`, or for W and M candles, which have no fixed length, this
+	  /// makes a single request like `remote`. `count` is ignored when paging.
+		pub async fn remote_paged(mut self, client: &Client) -> Result<Vec<Candlestick>, FxError> {
+	    let granularity = self.query.granularity.unwrap_or(CandlestickGranularity::S5);
+	    let (from, to, seconds) = match (self.query.from, self.query.to, granularity.seconds()) {
+	      (Some(from), Some(to), Some(seconds)) => (from, to, seconds as i64),
+	      _ => return Ok(self.remote(client).await?.candles.unwrap_or_default()),
+	    };
+	    let instrument_value = self.path.instrument.take().ok_or(RequestValidationError::MissingInstrument)?;
+	    if from > to {
+	      return Err(RequestValidationError::InvalidTimeRange { from, to }.into());
+	    }
 	    
 	    let uri = self.uri.clone()
 		      .replace("{instrument}", &instrument_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
+	    // One candle short of the limit, as both ends of a window can fall on a candle boundary
+	    let window = chrono::Duration::seconds(seconds * (MAX_CANDLES_PER_REQUEST - 1));
+	    self.query.count = None;
 	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
+	    let mut candles = Vec::new();
+	    let mut start = from;
+	    while start < to {
+	      let end = (start + window).min(to);
+	      self.query.from = Some(start);
+	      self.query.to = Some(end);
+		    let request = self.request(client, &uri);
+		    let response = client.send(request).await?;
+	      let page = crate::client::read_response::<GetInstrumentCandlesResponse>(client, response).await?;
+	      candles.extend(page.candles.unwrap_or_default());
+	      start = end;
 	    }
 	    
-	    response.json::<GetInstrumentCandlesResponse>().await.map_err(FxError::from)
+	    candles.sort_by_key(|candle| candle.time);
+	    candles.dedup_by_key(|candle| candle.time);
+	    Ok(candles)
 		}
+
+	  /// OANDA rejects `count` alongside both `from` and `to`, and a `from` after `to`.
+	  /// It caps `count` at 5000 without saying so, so a larger one is rejected here.
+	  fn validate_range(&self) -> Result<(), RequestValidationError> {
+	    match (self.query.count, self.query.from, self.query.to) {
+	      (Some(count), _, _) if count as i64 > MAX_CANDLES_PER_REQUEST =>
+	        Err(RequestValidationError::CountTooLarge { count, max: MAX_CANDLES_PER_REQUEST as i32 }),
+	      (Some(_), Some(_), Some(_)) => Err(RequestValidationError::ConflictingCandleParams),
+	      (_, Some(from), Some(to)) if from > to => Err(RequestValidationError::InvalidTimeRange { from, to }),
+	      _ => Ok(()),
+	    }
+	  }
+
+	  /// The GET for the current query. With an `AcceptDatetimeFormat` of `"UNIX"`, or
+	  /// none on a client asking for UNIX times, `from` and `to` go out as UNIX
+	  /// timestamps to match.
+	  fn request(&mut self, client: &Client, uri: &str) -> reqwest::RequestBuilder {
+	    let format = self.header.accept_datetime_format.as_deref().unwrap_or(client.datetime_format.as_str());
+	    let unix = format == "UNIX";
+	    let range = UnixRange { from: self.query.from, to: self.query.to };
+	    if unix {
+	      self.query.from = None;
+	      self.query.to = None;
+	    }
+		  let mut request = crate::client::build_request(client, reqwest::Method::GET, uri, &self.query, Some(format), self.timeout);
+	    if unix {
+	      request = request.query(&range);
+	      self.query.from = range.from;
+	      self.query.to = range.to;
+	    }
+	    request
+	  }
+	}
+
+	/// The candles of a candles response, with `Decimal` prices.
+	#[cfg(feature = "decimal")]
+	#[derive(Deserialize)]
+	struct DecimalCandles {
+	  candles: Option<Vec<DecimalCandlestick>>,
+	}
+
+	/// The most candles OANDA returns for one candles request.
+	const MAX_CANDLES_PER_REQUEST: i64 = 5000;
+
+	/// `from` and `to` in OANDA's UNIX datetime format.
+	#[derive(Serialize)]
+	struct UnixRange {
+	  #[serde(rename="from", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serdates_unix" )]
+	  from : Option<DateTime<Utc>>,
+
+	  #[serde(rename="to", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serdates_unix" )]
+	  to : Option<DateTime<Utc>>,
 	}
 
 	pub type GetInstrumentCandlesResponse = GetInstrumentCandlesResponse200Body;
@@ -347,7 +444,7 @@ pub mod get_order_book{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -365,7 +462,7 @@ pub mod get_order_book{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="instrument", skip_serializing_if="Option::is_none" )]
@@ -379,7 +476,7 @@ pub mod get_order_book{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -389,7 +486,7 @@ pub mod get_order_book{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="time", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serdates" )]
@@ -407,7 +504,7 @@ pub mod get_order_book{
 	/// Get Order Book
 /// Fetch an order book for an instrument.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetOrderBookRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -415,6 +512,8 @@ pub mod get_order_book{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetOrderBookRequest {
@@ -425,6 +524,7 @@ pub mod get_order_book{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -438,8 +538,8 @@ pub mod get_order_book{
 /// a "_".
   /// - param String
 	  /// - return GetOrderBookRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.path.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -475,43 +575,31 @@ pub mod get_order_book{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetOrderBookResponse, FxError> {
+	    self.remote_with_headers(client).await.map(|(response, _)| response)
+		}
+
+	  /// Like `remote`, also returning the links to the neighbouring snapshots
+	  /// from the response's `Link` header.
+		pub async fn remote_with_headers(self, client: &Client) -> Result<(GetOrderBookResponse, crate::books::BookLinks), FxError> {
 	    // Validate required path parameters
 	    let instrument_value = self.path.instrument.ok_or(RequestValidationError::MissingInstrument)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{instrument}", &instrument_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout);
+		  let response = client.send(request).await?;
+	    let links = crate::books::BookLinks::from_headers(response.headers());
 	    
-	    response.json::<GetOrderBookResponse>().await.map_err(FxError::from)
+	    crate::client::read_response::<GetOrderBookResponse>(client, response).await.map(|body| (body, links))
 		}
 	}
 
@@ -555,7 +643,7 @@ pub mod get_position_book{
 	use fxoanda_definitions::*;
 
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestHead { 
 
 	  #[serde(rename="Authorization", skip_serializing_if="Option::is_none" )]
@@ -573,7 +661,7 @@ pub mod get_position_book{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestPath { 
 
 	  #[serde(rename="instrument", skip_serializing_if="Option::is_none" )]
@@ -587,7 +675,7 @@ pub mod get_position_book{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestBody { 
 	}
 	impl RequestBody {
@@ -597,7 +685,7 @@ pub mod get_position_book{
 	  }
 	}
 	
-	#[derive(Debug,Serialize,Deserialize)]
+	#[derive(Debug,Clone,Serialize,Deserialize)]
 	struct RequestQuery { 
 
 	  #[serde(rename="time", skip_serializing_if="Option::is_none" , with="fxoanda_serdes::serdates" )]
@@ -615,7 +703,7 @@ pub mod get_position_book{
 	/// Get Position Book
 /// Fetch a position book for an instrument.
 
-	#[derive(Debug, Serialize, Deserialize)]
+	#[derive(Debug, Clone, Serialize, Deserialize)]
 	pub struct GetPositionBookRequest { 
 	  #[serde(skip_serializing)]
 	  uri: String,
@@ -623,6 +711,8 @@ pub mod get_position_book{
 	  body: RequestBody,
 	  path: RequestPath,
 	  query: RequestQuery,
+	  #[serde(skip)]
+	  timeout: Option<std::time::Duration>,
 	}
 
 	impl GetPositionBookRequest {
@@ -633,6 +723,7 @@ pub mod get_position_book{
 	      body: RequestBody::new(),
 	      path: RequestPath::new(),
 	      query: RequestQuery::new(),
+	      timeout: None,
 	    }
 	  }
 	  pub fn with_uri(mut self, x: String) -> Self{
@@ -646,8 +737,8 @@ pub mod get_position_book{
 /// a "_".
   /// - param String
 	  /// - return GetPositionBookRequest
-	  pub fn with_instrument ( mut self, x : String ) -> Self {
-	    self.path.instrument = Some(x);
+	  pub fn with_instrument ( mut self, x : impl Into<String> ) -> Self {
+	    self.path.instrument = Some(x.into());
 	    self
 	  }
 	
@@ -683,43 +774,31 @@ pub mod get_position_book{
 	    self
 	  }
 	
+	  /// Time limit for this request alone, overriding the `reqwest::Client`'s timeout.
+	  /// A request that runs over fails with `FxError::Timeout`.
+	  pub fn with_timeout ( mut self, x : std::time::Duration ) -> Self {
+	    self.timeout = Some(x);
+	    self
+	  }
+	
 
 		pub async fn remote(self, client: &Client) -> Result<GetPositionBookResponse, FxError> {
+	    self.remote_with_headers(client).await.map(|(response, _)| response)
+		}
+
+	  /// Like `remote`, also returning the links to the neighbouring snapshots
+	  /// from the response's `Link` header.
+		pub async fn remote_with_headers(self, client: &Client) -> Result<(GetPositionBookResponse, crate::books::BookLinks), FxError> {
 	    // Validate required path parameters
 	    let instrument_value = self.path.instrument.ok_or(RequestValidationError::MissingInstrument)?;
 	    
 	    let uri = self.uri.clone()
 		      .replace("{instrument}", &instrument_value);
-	    let url = format!("https://{host}{uri}", host=client.host, uri=uri);
-		  let response = client.reqwest.get(&url)
-				      .query(&self.query)
-						.bearer_auth(&client.authentication)
-						  .send().await.map_err(FxError::from)?;
-	    
-	    let status = response.status();
-	    if !status.is_success() {
-	        let response_text = response.text().await.map_err(FxError::from)?;
-	        match serde_json::from_str::<serde_json::Value>(&response_text) {
-	            Ok(json_value) => {
-	                let error_message = json_value.get("errorMessage").and_then(|v| v.as_str()).unwrap_or("Unknown error");
-	                let error_code = json_value.get("errorCode").and_then(|v| v.as_str()).unwrap_or("UNKNOWN_ERROR_CODE");
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: error_code.to_string(),
-	                    error_message: error_message.to_string(),
-	                });
-	            },
-	            Err(_) => {
-	                return Err(FxError::ApiError {
-	                    status_code: status.as_u16(),
-	                    error_code: "UNPARSEABLE_RESPONSE".to_string(),
-	                    error_message: "Could not parse error response".to_string(),
-	                });
-	            }
-	        }
-	    }
+		  let request = crate::client::build_request(client, reqwest::Method::GET, &uri, &self.query, self.header.accept_datetime_format.as_deref(), self.timeout);
+		  let response = client.send(request).await?;
+	    let links = crate::books::BookLinks::from_headers(response.headers());
 	    
-	    response.json::<GetPositionBookResponse>().await.map_err(FxError::from)
+	    crate::client::read_response::<GetPositionBookResponse>(client, response).await.map(|body| (body, links))
 		}
 	}
 
@@ -18,22 +18,12 @@
 //! It requires the `OANDA_KEY` and `OANDA_HOST` environment variables to be set.
 //!
 //! ```no_run
-//! # use std::env;
 //! # use fxoanda::*;
 //! #
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//!     let api_key = env::var("OANDA_KEY")
-//!         .expect("OANDA_KEY environment variable must be set");
-//!     let api_host = env::var("OANDA_HOST")
-//!         .expect("OANDA_HOST environment variable must be set");
-//!
-//!     // Create a new client
-//!     let client = fxoanda::Client {
-//!         host: api_host,
-//!         reqwest: reqwest::Client::new(),
-//!         authentication: api_key,
-//!     };
+//!     // Create a new client from OANDA_KEY and OANDA_HOST
+//!     let client = fxoanda::Client::from_env()?;
 //!
 //!     // Build a request for H4 candles for EUR_USD
 //!     let request = fxoanda::GetInstrumentCandlesRequest::new()
@@ -86,12 +76,38 @@ extern crate serde_json;
 extern crate time;
 
 pub mod account;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod books;
+pub mod candles;
 pub mod client;
+pub mod diagnostics;
 pub mod errors;
+pub mod ids;
 pub mod instrument;
+pub mod monitor;
+pub mod pricing;
+pub mod reporting;
+pub mod risk;
+pub mod snapshot;
+pub mod streaming;
+pub mod trading;
+pub mod validation;
 pub use self::account::*;
+pub use self::books::*;
+pub use self::candles::*;
 pub use self::client::*;
-pub use self::errors::{RequestValidationError, FxError};
+pub use self::diagnostics::*;
+pub use self::errors::{RequestValidationError, FxError, OandaErrorResponse, OrderRejectDetails};
+pub use self::ids::*;
 pub use self::instrument::*;
+pub use self::monitor::*;
+pub use self::pricing::*;
+pub use self::reporting::*;
+pub use self::risk::*;
+pub use self::snapshot::*;
+pub use self::streaming::*;
+pub use self::trading::*;
+pub use self::validation::InstrumentMetadata;
 pub use fxoanda_definitions::*;
 pub use fxoanda_serdes::*;
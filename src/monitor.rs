@@ -0,0 +1,146 @@
+use crate::account::*;
+use crate::client::{from_json_value, Client};
+use crate::errors::FxError;
+use crate::ids::OandaId;
+use crate::snapshot::AccountSnapshot;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use fxoanda_definitions::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// An open trade as tracked by a [`PositionMonitor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenTrade {
+    pub id: String,
+    pub instrument: String,
+    /// Units still open: positive for a long, negative for a short.
+    pub units: f64,
+    /// The price the trade was opened at.
+    pub price: f64,
+    pub open_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+struct MonitorState {
+    trades: HashMap<String, OpenTrade>,
+    last_transaction_id: Option<OandaId>,
+}
+
+/// The account's open trades, kept up to date from its transaction stream.
+///
+/// Order fills open, reduce and close trades; every other transaction is ignored.
+/// Positions are the trades' units netted per instrument. Clones share the same
+/// state, so one clone can [`follow`](Self::follow) the stream on a spawned task
+/// while others read [`trades`](Self::trades) and [`positions`](Self::positions).
+///
+/// Seed the monitor with [`from_snapshot`](Self::from_snapshot) before following
+/// the stream, or trades opened before it started are missing. Transactions at or
+/// before the last one applied are skipped, so those already reflected in the
+/// snapshot aren't counted twice.
+#[derive(Debug, Clone, Default)]
+pub struct PositionMonitor {
+    state: Arc<Mutex<MonitorState>>,
+}
+
+impl PositionMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A monitor holding the snapshot's open trades, applying only transactions
+    /// after the summary's last transaction ID.
+    pub fn from_snapshot(snapshot: &AccountSnapshot) -> Self {
+        let trades = snapshot.trades.iter()
+            .filter_map(|trade| {
+                Some((trade.id.clone()?, OpenTrade {
+                    id: trade.id.clone()?,
+                    instrument: trade.instrument.clone().unwrap_or_default(),
+                    units: trade.current_units.unwrap_or(0.0),
+                    price: trade.price.unwrap_or(0.0),
+                    open_time: trade.open_time,
+                }))
+            })
+            .collect();
+        let last_transaction_id = snapshot.summary.last_transaction_id.clone().map(OandaId::new);
+        PositionMonitor { state: Arc::new(Mutex::new(MonitorState { trades, last_transaction_id })) }
+    }
+
+    /// Applies a transaction as sent by the transaction stream (see
+    /// [`StreamTransactionsRequest::values`]). Heartbeats and transactions that don't
+    /// change trades are ignored; a fill that doesn't parse is an error.
+    pub fn apply(&self, transaction: &serde_json::Value) -> Result<(), FxError> {
+        if transaction.get("type").and_then(|t| t.as_str()) != Some("ORDER_FILL") {
+            return Ok(());
+        }
+        let fill: OrderFillTransaction = from_json_value(transaction.clone())?;
+        self.apply_fill(&fill);
+        Ok(())
+    }
+
+    /// Applies the trades opened, reduced and closed by `fill`.
+    pub fn apply_fill(&self, fill: &OrderFillTransaction) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(id) = fill.id.clone().map(OandaId::new) {
+            if state.last_transaction_id.as_ref().is_some_and(|last| &id <= last) {
+                return;
+            }
+            state.last_transaction_id = Some(id);
+        }
+
+        for closed in fill.trades_closed.iter().flatten() {
+            if let Some(trade_id) = &closed.trade_id {
+                state.trades.remove(trade_id);
+            }
+        }
+        if let Some(trade_id) = fill.trade_reduced.as_ref().and_then(|reduced| reduced.trade_id.as_ref()) {
+            if let Some(trade) = state.trades.get_mut(trade_id) {
+                // The reduction is signed like the fill, against the trade's units
+                trade.units += fill.trade_reduced.as_ref().and_then(|reduced| reduced.units).unwrap_or(0.0);
+            }
+        }
+        if let Some(opened) = &fill.trade_opened {
+            if let Some(trade_id) = &opened.trade_id {
+                state.trades.insert(trade_id.clone(), OpenTrade {
+                    id: trade_id.clone(),
+                    instrument: fill.instrument.clone().unwrap_or_default(),
+                    units: opened.units.unwrap_or(0.0),
+                    price: opened.price.or(fill.price).unwrap_or(0.0),
+                    open_time: fill.time,
+                });
+            }
+        }
+    }
+
+    /// The open trades, oldest first.
+    pub fn trades(&self) -> Vec<OpenTrade> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut trades: Vec<OpenTrade> = state.trades.values().cloned().collect();
+        trades.sort_by_key(|trade| OandaId::new(trade.id.clone()));
+        trades
+    }
+
+    /// Net open units per instrument. Instruments whose trades net to zero are left out.
+    pub fn positions(&self) -> HashMap<String, f64> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut positions = HashMap::new();
+        for trade in state.trades.values() {
+            *positions.entry(trade.instrument.clone()).or_insert(0.0) += trade.units;
+        }
+        positions.retain(|_, units| *units != 0.0);
+        positions
+    }
+
+    /// Follows the account's transaction stream, applying each transaction as it
+    /// arrives. Returns when the server closes the stream, or with the first error.
+    pub async fn follow(&self, client: &Client, account_id: &str) -> Result<(), FxError> {
+        let values = StreamTransactionsRequest::new()
+            .with_account_id(account_id)
+            .values(client);
+        let mut values = std::pin::pin!(values);
+        while let Some(value) = values.next().await {
+            self.apply(&value?)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::account::GetPricesRequest;
+use crate::client::Client;
+use crate::errors::FxError;
+use fxoanda_definitions::ClientPrice;
+use std::collections::{HashMap, HashSet};
+
+/// The most instruments [`Client::prices_for`] puts in a single pricing request.
+///
+/// OANDA doesn't publish the cap, but long instrument lists are rejected; this
+/// stays well below where that happens.
+pub const MAX_PRICING_INSTRUMENTS: usize = 50;
+
+impl Client {
+    /// Current prices for `instruments`, in the order given.
+    ///
+    /// Repeated instruments are asked for once. Lists longer than
+    /// [`MAX_PRICING_INSTRUMENTS`] are split across concurrent requests, and the
+    /// first failing request fails the whole call. Instruments OANDA returns no
+    /// price for are left out.
+    pub async fn prices_for(&self, account_id: &str, instruments: &[&str]) -> Result<Vec<ClientPrice>, FxError> {
+        let mut seen = HashSet::new();
+        let instruments: Vec<&str> = instruments.iter().copied().filter(|i| seen.insert(*i)).collect();
+
+        let requests = instruments.chunks(MAX_PRICING_INSTRUMENTS).map(|chunk| {
+            GetPricesRequest::new()
+                .with_account_id(account_id)
+                .with_instruments(chunk.join(","))
+                .remote(self)
+        });
+        let mut prices: HashMap<String, ClientPrice> = futures::future::try_join_all(requests)
+            .await?
+            .into_iter()
+            .flat_map(|response| response.prices.unwrap_or_default())
+            .filter_map(|price| Some((price.instrument.clone()?, price)))
+            .collect();
+
+        Ok(instruments.iter().filter_map(|instrument| prices.remove(*instrument)).collect())
+    }
+}
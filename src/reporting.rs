@@ -0,0 +1,197 @@
+use crate::account::*;
+use crate::account::list_transactions::fetch_page;
+use crate::client::{from_json_value, Client};
+use crate::errors::FxError;
+use chrono::prelude::*;
+use fxoanda_definitions::*;
+use std::collections::HashMap;
+
+/// Every transaction of the given types between `from` and `to`, across all pages.
+///
+/// The transaction list endpoint only returns links to ID ranges, which carry the
+/// type filter; each is fetched in turn by
+/// [`ListTransactionsRequest::remote_all`]. An empty `types` returns all types.
+pub async fn transactions_of_type(
+    client: &Client,
+    account_id: &str,
+    types: Vec<TransactionFilter>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<Transaction>, FxError> {
+    let types: Vec<String> = types.iter().map(filter_name).collect();
+    list_transactions(account_id, types, from, to).remote_all(client).await
+}
+
+/// The last `n` transactions on the account, newest first.
+///
+/// Fetched as a single ID range ending at the account's `lastTransactionID`,
+/// so there is no paging or time window to pick.
+pub async fn recent_transactions(client: &Client, account_id: &str, n: u64) -> Result<Vec<Transaction>, FxError> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    let summary = GetAccountSummaryRequest::new()
+        .with_account_id(account_id.to_string())
+        .remote(client)
+        .await?;
+    let last_id = summary.last_transaction_id
+        .as_deref()
+        .and_then(|id| id.parse::<u64>().ok())
+        .ok_or_else(|| FxError::DeserializationError {
+            path: "lastTransactionID".to_string(),
+            message: "missing or non-numeric transaction ID".to_string(),
+            source: None,
+        })?;
+    if last_id == 0 {
+        return Ok(Vec::new());
+    }
+
+    let first_id = last_id.saturating_sub(n - 1).max(1);
+    let mut transactions = GetTransactionRangeRequest::new()
+        .with_account_id(account_id.to_string())
+        .with_from(first_id.to_string())
+        .with_to(last_id.to_string())
+        .remote(client)
+        .await?
+        .transactions
+        .unwrap_or_default();
+    transactions.reverse();
+    Ok(transactions)
+}
+
+/// Financing and dividend adjustments for an account over a period.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FinancingSummary {
+    /// Net financing across all instruments, in the account's home currency.
+    /// Negative when the account paid more than it received.
+    pub total_financing: f64,
+    /// Net dividend adjustments across all instruments, in the home currency.
+    pub total_dividend_adjustment: f64,
+    pub by_instrument: HashMap<String, InstrumentFinancing>,
+}
+
+/// One instrument's share of a [`FinancingSummary`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InstrumentFinancing {
+    pub financing: f64,
+    pub dividend_adjustment: f64,
+}
+
+impl FinancingSummary {
+    /// Financing and dividend adjustments combined.
+    pub fn total(&self) -> f64 {
+        self.total_financing + self.total_dividend_adjustment
+    }
+}
+
+/// Totals the daily financing and dividend adjustment transactions between
+/// `from` and `to`, broken down by instrument.
+pub async fn financing_summary(
+    client: &Client,
+    account_id: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<FinancingSummary, FxError> {
+    // DIVIDEND_ADJUSTMENT postdates the generated TransactionFilter, so the filter is given by name
+    let types = vec!["DAILY_FINANCING".to_string(), "DIVIDEND_ADJUSTMENT".to_string()];
+    let listing = list_transactions(account_id, types, from, to).remote(client).await?;
+    let mut transactions = Vec::new();
+    for page_url in listing.pages.unwrap_or_default() {
+        transactions.extend(fetch_page::<serde_json::Value>(client, &page_url, None).await?);
+    }
+
+    let mut summary = FinancingSummary::default();
+    for transaction in transactions {
+        match transaction.get("type").and_then(|t| t.as_str()) {
+            Some("DAILY_FINANCING") => {
+                let financing: DailyFinancingTransaction = from_json_value(transaction)?;
+                for position in financing.position_financings.unwrap_or_default() {
+                    let amount = position.financing.unwrap_or(0.0);
+                    let instrument = position.instrument.unwrap_or_default();
+                    summary.by_instrument.entry(instrument).or_default().financing += amount;
+                    summary.total_financing += amount;
+                }
+            }
+            Some("DIVIDEND_ADJUSTMENT") => {
+                let instrument = transaction.get("instrument").and_then(|i| i.as_str()).unwrap_or_default();
+                let amount = transaction.get("dividendAdjustment")
+                    .and_then(|a| a.as_str())
+                    .and_then(|a| a.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                summary.by_instrument.entry(instrument.to_string()).or_default().dividend_adjustment += amount;
+                summary.total_dividend_adjustment += amount;
+            }
+            _ => {}
+        }
+    }
+    Ok(summary)
+}
+
+/// An account's realized and unrealized profit and loss at one moment, in its home currency.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PnlSnapshot {
+    /// Profit and loss realized over the lifetime of the account.
+    pub realized_pnl: f64,
+    /// Profit and loss the open trades would realize if closed now.
+    pub unrealized_pnl: f64,
+}
+
+impl PnlSnapshot {
+    /// Realized and unrealized profit and loss combined.
+    pub fn total(&self) -> f64 {
+        self.realized_pnl + self.unrealized_pnl
+    }
+}
+
+/// The summed unrealized profit and loss of `trades`.
+pub fn unrealized_pnl(trades: &[Trade]) -> f64 {
+    trades.iter().map(|trade| trade.unrealized_pl.unwrap_or(0.0)).sum()
+}
+
+/// How the account is doing right now: its lifetime realized profit and loss, from
+/// the account summary, and the unrealized profit and loss of its open trades,
+/// fetched concurrently.
+pub async fn total_pnl(client: &Client, account_id: &str) -> Result<PnlSnapshot, FxError> {
+    let (summary, trades) = tokio::try_join!(
+        GetAccountSummaryRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+        ListOpenTradesRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+    )?;
+    let realized_pnl = summary.account
+        .and_then(|account| account.pl)
+        .ok_or_else(|| FxError::DeserializationError {
+            path: "account.pl".to_string(),
+            message: "missing realized profit and loss".to_string(),
+            source: None,
+        })?;
+
+    Ok(PnlSnapshot {
+        realized_pnl,
+        unrealized_pnl: unrealized_pnl(&trades.trades.unwrap_or_default()),
+    })
+}
+
+/// A listing of the transactions of `types` between `from` and `to`; an empty
+/// `types` lists every type.
+fn list_transactions(account_id: &str, types: Vec<String>, from: DateTime<Utc>, to: DateTime<Utc>) -> ListTransactionsRequest {
+    let request = ListTransactionsRequest::new()
+        .with_account_id(account_id.to_string())
+        .with_from(from)
+        .with_to(to);
+    if types.is_empty() {
+        request
+    } else {
+        request.with_otype(types)
+    }
+}
+
+/// The wire name of a transaction filter, e.g. `ORDER_FILL`.
+fn filter_name(filter: &TransactionFilter) -> String {
+    match serde_json::to_value(filter) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => format!("{:?}", filter),
+    }
+}
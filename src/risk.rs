@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use crate::account::*;
+use crate::client::Client;
+use crate::errors::{FxError, RequestValidationError};
+use crate::ids::InstrumentName;
+use fxoanda_definitions::*;
+
+/// Net exposure per currency across a set of positions.
+///
+/// Each instrument's net units are decomposed into its two currencies: the base
+/// currency is exposed by the units held, the quote currency by the opposite amount
+/// at the side's average price. A long of 10,000 EUR_USD at 1.10 is therefore
+/// +10,000 EUR and -11,000 USD. Positions whose instrument isn't of the
+/// `BASE_QUOTE` form are skipped.
+pub fn currency_exposure(positions: &[Position]) -> HashMap<String, f64> {
+    let mut exposure = HashMap::new();
+    for position in positions {
+        let name = match position.instrument.as_deref().map(InstrumentName::parse) {
+            Some(Ok(name)) => name,
+            _ => continue,
+        };
+        for side in [&position.long, &position.short].into_iter().flatten() {
+            let units = side.units.unwrap_or(0.0);
+            if units == 0.0 {
+                continue;
+            }
+            let price = side.average_price.unwrap_or(0.0);
+            *exposure.entry(name.base().to_string()).or_insert(0.0) += units;
+            *exposure.entry(name.quote().to_string()).or_insert(0.0) -= units * price;
+        }
+    }
+    exposure
+}
+
+/// Signed distance from `from` to `to` in pips, where a pip is `10^pip_location`
+/// (the instrument's `pipLocation`, e.g. -4 for EUR_USD and -2 for USD_JPY).
+/// Positive when `to` is above `from`.
+pub fn pip_distance(from: f64, to: f64, pip_location: i32) -> f64 {
+    (to - from) / 10f64.powi(pip_location)
+}
+
+/// Price at which closing `trade` would net zero once its accrued financing is counted.
+///
+/// `pip_value` converts a price move into the Account's home currency: the home-currency
+/// P/L of a 1.0 move in price for a single unit (1.0 when the quote currency is the home
+/// currency). Financing paid moves the breakeven away from the entry price, financing
+/// collected moves it towards it. Commission isn't reported on a Trade, so callers
+/// trading commission-based instruments should add it to the trade's financing.
+///
+/// Returns `None` if the trade has no price or open units, or `pip_value` is zero.
+pub fn breakeven_price(trade: &Trade, pip_value: f64) -> Option<f64> {
+    let price = trade.price?;
+    let units = trade.current_units?;
+    if units == 0.0 || pip_value == 0.0 {
+        return None;
+    }
+    let financing = trade.financing.unwrap_or(0.0);
+    Some(price - financing / (units * pip_value))
+}
+
+/// Units of `instrument` to trade so that a stop `stop_distance` away in price loses
+/// `risk_fraction` of the account balance (e.g. 0.01 to risk 1%).
+///
+/// Fetches the account balance and the instrument's price with home conversions
+/// concurrently. A price move loses in the instrument's quote currency, which is
+/// converted into the account's home currency with OANDA's `accountLoss` factor for
+/// that currency. The result is rounded down to whole units and is always positive;
+/// the caller picks the direction.
+pub async fn units_for_risk(
+    client: &Client,
+    account_id: &str,
+    instrument: &str,
+    stop_distance: f64,
+    risk_fraction: f64,
+) -> Result<f64, FxError> {
+    if !stop_distance.is_finite() || stop_distance <= 0.0 {
+        return Err(RequestValidationError::InvalidStopDistance(stop_distance).into());
+    }
+    if risk_fraction == 0.0 || !(0.0..=1.0).contains(&risk_fraction) {
+        return Err(RequestValidationError::InvalidRiskFraction(risk_fraction).into());
+    }
+    let name = InstrumentName::parse(instrument)?;
+
+    let (summary, prices) = tokio::try_join!(
+        GetAccountSummaryRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+        GetPricesRequest::new()
+            .with_account_id(account_id.to_string())
+            .with_instruments(instrument.to_string())
+            .with_include_home_conversions(true)
+            .remote(client),
+    )?;
+    let account = summary.account.unwrap_or_else(AccountSummary::new);
+    let balance = account.balance.ok_or_else(|| FxError::DeserializationError {
+        path: "account.balance".to_string(),
+        message: "missing account balance".to_string(),
+        source: None,
+    })?;
+
+    let loss_factor = prices.home_conversions
+        .unwrap_or_default()
+        .into_iter()
+        .find(|conversion| conversion.currency.as_deref() == Some(name.quote()))
+        .and_then(|conversion| conversion.account_loss)
+        .filter(|factor| *factor > 0.0)
+        .or_else(|| (account.currency.as_deref() == Some(name.quote())).then_some(1.0))
+        .ok_or_else(|| FxError::DeserializationError {
+            path: "homeConversions".to_string(),
+            message: format!("no conversion from {} to the home currency", name.quote()),
+            source: None,
+        })?;
+
+    Ok((balance * risk_fraction / (stop_distance * loss_factor)).floor())
+}
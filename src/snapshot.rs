@@ -0,0 +1,74 @@
+use crate::account::*;
+use crate::client::Client;
+use crate::errors::FxError;
+use fxoanda_definitions::*;
+use std::time::Duration;
+
+/// Everything needed to reconcile local state against an account, fetched in one go.
+#[derive(Debug)]
+pub struct AccountSnapshot {
+    pub summary: AccountSummary,
+    pub trades: Vec<Trade>,
+    pub positions: Vec<Position>,
+    pub pending_orders: Vec<Order>,
+}
+
+/// Fetches the account summary, open trades, open positions and pending orders
+/// concurrently. Fails if any of the four requests fails.
+pub async fn full_snapshot(client: &Client, account_id: &str) -> Result<AccountSnapshot, FxError> {
+    let (summary, trades, positions, orders) = tokio::try_join!(
+        GetAccountSummaryRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+        ListOpenTradesRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+        ListOpenPositionsRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+        ListPendingOrdersRequest::new()
+            .with_account_id(account_id.to_string())
+            .remote(client),
+    )?;
+
+    let summary = summary.account.ok_or_else(|| FxError::DeserializationError {
+        path: "account".to_string(),
+        message: "missing field `account`".to_string(),
+        source: None,
+    })?;
+
+    Ok(AccountSnapshot {
+        summary,
+        trades: trades.trades.unwrap_or_default(),
+        positions: positions.positions.unwrap_or_default(),
+        pending_orders: orders.orders.unwrap_or_default(),
+    })
+}
+
+/// Polls [`full_snapshot`] every `interval` until `predicate` holds, returning the
+/// snapshot that satisfied it, e.g. to wait for a position to go flat.
+///
+/// Fails with `FxError::Timeout` if the predicate still doesn't hold once
+/// `timeout` has elapsed, or with the first error a poll returns.
+pub async fn wait_until<F>(
+    client: &Client,
+    account_id: &str,
+    predicate: F,
+    timeout: Duration,
+    interval: Duration,
+) -> Result<AccountSnapshot, FxError>
+where
+    F: Fn(&AccountSnapshot) -> bool,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let snapshot = full_snapshot(client, account_id).await?;
+        if predicate(&snapshot) {
+            return Ok(snapshot);
+        }
+        if tokio::time::Instant::now() + interval > deadline {
+            return Err(FxError::Timeout(None));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
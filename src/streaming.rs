@@ -0,0 +1,215 @@
+use crate::account::StreamPricingRequest;
+use crate::client::{from_json_value, request_id, status_error, Client};
+use crate::errors::FxError;
+use fxoanda_definitions::{ClientPrice, PricingHeartbeat};
+use futures::future;
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// A line of the pricing stream: a price tick, or the heartbeat OANDA sends about
+/// every five seconds while no prices are changing.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)] // prices vastly outnumber heartbeats, so boxing them would cost more than it saves
+pub enum StreamPriceItem {
+    Price(ClientPrice),
+    Heartbeat(PricingHeartbeat),
+}
+
+impl StreamPriceItem {
+    pub(crate) fn from_line(line: &[u8]) -> Result<StreamPriceItem, FxError> {
+        let value: serde_json::Value = serde_json::from_slice(line)?;
+        if value.get("type").and_then(|t| t.as_str()) == Some("HEARTBEAT") {
+            Ok(StreamPriceItem::Heartbeat(from_json_value(value)?))
+        } else {
+            Ok(StreamPriceItem::Price(from_json_value(value)?))
+        }
+    }
+}
+
+/// Drops the heartbeats from a stream of [`StreamPriceItem`]s, leaving the prices and any errors.
+pub fn prices_only<S>(items: S) -> impl Stream<Item = Result<ClientPrice, FxError>>
+where
+    S: Stream<Item = Result<StreamPriceItem, FxError>>,
+{
+    items.filter_map(|item| future::ready(match item {
+        Ok(StreamPriceItem::Price(price)) => Some(Ok(price)),
+        Ok(StreamPriceItem::Heartbeat(_)) => None,
+        Err(e) => Some(Err(e)),
+    }))
+}
+
+/// An item of [`Client::pricing_stream_reconnecting`]: a line of the pricing stream,
+/// or word that the connection dropped and was re-established.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)] // as for StreamPriceItem
+pub enum StreamEvent {
+    Price(ClientPrice),
+    Heartbeat(PricingHeartbeat),
+    /// The stream reconnected after a drop, and prices may have changed unseen in
+    /// between. Comes just before the first item from the new connection.
+    Reconnected,
+}
+
+impl From<StreamPriceItem> for StreamEvent {
+    fn from(item: StreamPriceItem) -> Self {
+        match item {
+            StreamPriceItem::Price(price) => StreamEvent::Price(price),
+            StreamPriceItem::Heartbeat(heartbeat) => StreamEvent::Heartbeat(heartbeat),
+        }
+    }
+}
+
+struct Reconnecting<'a> {
+    client: &'a Client,
+    request: StreamPricingRequest,
+    items: Option<BoxStream<'a, Result<StreamPriceItem, FxError>>>,
+    /// Reconnection attempts since the last item arrived.
+    attempt: u32,
+    connected: bool,
+    dropped: bool,
+    /// The first item of a new connection, held back while `Reconnected` goes out.
+    pending: Option<StreamPriceItem>,
+}
+
+impl Client {
+    /// A price stream for `instruments` that reconnects when the connection drops,
+    /// for consumers that need prices for hours or days.
+    ///
+    /// OANDA closes streams now and then, and networks fail; whenever the stream
+    /// ends, times out or breaks off, it is opened again after a backoff delay from
+    /// the client's [`RetryPolicy`](crate::RetryPolicy) (whose `max_retries` doesn't
+    /// apply: reconnecting carries on until the stream is dropped). Each successful
+    /// reconnection is announced with [`StreamEvent::Reconnected`] so consumers can
+    /// re-sync anything derived from earlier prices.
+    ///
+    /// Errors that reconnecting won't fix, such as a rejected token or an unknown
+    /// instrument, are yielded and end the stream. A line that fails to parse is
+    /// yielded as an `Err` and the stream carries on.
+    pub fn pricing_stream_reconnecting<'a>(&'a self, account_id: &str, instruments: &[&str]) -> impl Stream<Item = Result<StreamEvent, FxError>> + 'a {
+        let request = StreamPricingRequest::new()
+            .with_account_id(account_id)
+            .with_instruments(instruments.iter().map(|instrument| instrument.to_string()).collect());
+        let state = Reconnecting { client: self, request, items: None, attempt: 0, connected: false, dropped: false, pending: None };
+        stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+            loop {
+                if let Some(item) = state.pending.take() {
+                    return Some((Ok(item.into()), Some(state)));
+                }
+                let client = state.client;
+                let request = &state.request;
+                let items = state.items.get_or_insert_with(|| request.clone().items(client).boxed());
+                let error = match items.next().await {
+                    Some(Ok(item)) => {
+                        state.attempt = 0;
+                        state.connected = true;
+                        if std::mem::take(&mut state.dropped) {
+                            state.pending = Some(item);
+                            return Some((Ok(StreamEvent::Reconnected), Some(state)));
+                        }
+                        return Some((Ok(item.into()), Some(state)));
+                    }
+                    Some(Err(e @ FxError::DeserializationError { .. })) => return Some((Err(e), Some(state))),
+                    Some(Err(e)) if !(e.is_retryable() || matches!(e, FxError::HttpError(_))) => return Some((Err(e), None)),
+                    Some(Err(e)) => Some(e),
+                    None => None,
+                };
+
+                tracing::warn!(error = ?error, attempt = state.attempt, "pricing stream dropped; reconnecting");
+                state.items = None;
+                state.dropped = state.connected;
+                tokio::time::sleep(state.client.retry.delay(state.attempt)).await;
+                state.attempt = state.attempt.saturating_add(1);
+            }
+        })
+    }
+}
+
+/// Spawns a task that reads `items` into a channel holding up to `buffer` of them.
+///
+/// The task waits for room in the channel before reading on, so a slow receiver
+/// holds the stream back instead of items piling up. It finishes when the stream
+/// ends or the receiver is dropped. A `buffer` of zero is treated as one.
+pub(crate) fn spawn_channel<T, S>(items: S, buffer: usize) -> (JoinHandle<()>, mpsc::Receiver<T>)
+where
+    T: Send + 'static,
+    S: Stream<Item = T> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(buffer.max(1));
+    let handle = tokio::spawn(async move {
+        let mut items = std::pin::pin!(items);
+        while let Some(item) = items.next().await {
+            if sender.send(item).await.is_err() {
+                break;
+            }
+        }
+    });
+    (handle, receiver)
+}
+
+enum LineReader {
+    Connecting(Result<reqwest::RequestBuilder, FxError>),
+    Reading(reqwest::Response, Vec<u8>),
+    Done,
+}
+
+/// Opens a streaming endpoint and yields each line of its newline-delimited JSON
+/// body, parsed by `parse`, as it arrives.
+///
+/// Lines are split on newlines rather than on the chunks the body arrives in, so a
+/// chunk may carry several objects and an object may span chunks.
+///
+/// The stream ends (`None`) when the server closes the body cleanly. A failed
+/// connection, an error response or a dropped connection is yielded as an `Err`
+/// and ends the stream; a line that fails to parse is yielded as an `Err` and
+/// reading carries on with the next one.
+pub(crate) fn json_lines<T>(
+    request: Result<reqwest::RequestBuilder, FxError>,
+    parse: fn(&[u8]) -> Result<T, FxError>,
+) -> impl Stream<Item = Result<T, FxError>> {
+    stream::unfold(LineReader::Connecting(request), move |state| async move {
+        let (mut response, mut buffer) = match state {
+            LineReader::Connecting(request) => match connect(request).await {
+                Ok(response) => (response, Vec::new()),
+                Err(e) => return Some((Err(e), LineReader::Done)),
+            },
+            LineReader::Reading(response, buffer) => (response, buffer),
+            LineReader::Done => return None,
+        };
+        loop {
+            if let Some(end) = buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=end).collect();
+                if is_blank(&line) {
+                    continue;
+                }
+                return Some((parse(&line), LineReader::Reading(response, buffer)));
+            }
+            match response.chunk().await {
+                Ok(Some(chunk)) => buffer.extend_from_slice(&chunk),
+                Ok(None) if is_blank(&buffer) => return None,
+                // The body ended without a trailing newline; the remainder is the last line
+                Ok(None) => return Some((parse(&buffer), LineReader::Done)),
+                Err(e) => return Some((Err(e.into()), LineReader::Done)),
+            }
+        }
+    })
+}
+
+async fn connect(request: Result<reqwest::RequestBuilder, FxError>) -> Result<reqwest::Response, FxError> {
+    let response = request?.send().await?;
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        Err(FxError::rate_limited(response.headers()))
+    } else {
+        let request_id = request_id(response.headers());
+        let response_text = response.text().await?;
+        Err(status_error(status, request_id, &response_text))
+    }
+}
+
+fn is_blank(line: &[u8]) -> bool {
+    line.iter().all(u8::is_ascii_whitespace)
+}
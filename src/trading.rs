@@ -0,0 +1,326 @@
+use crate::account::*;
+use crate::client::Client;
+use crate::errors::{FxError, RequestValidationError};
+use fxoanda_definitions::*;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Characters escaped when a client-assigned ID is put into a URL path: everything
+/// but the unreserved characters, so a `/`, `?` or `#` stays inside the segment.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// A filled market order, as returned by [`Client::market_buy`] and [`Client::market_sell`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketFill {
+    pub order_id: String,
+    pub fill_id: String,
+    pub instrument: String,
+    /// Units filled: positive for a buy, negative for a sell.
+    pub units: f64,
+    /// The average price the order filled at.
+    pub price: f64,
+    /// The trade opened by the fill, if it opened one.
+    pub trade_opened: Option<String>,
+    /// The trade partially closed by the fill, if any.
+    pub trade_reduced: Option<String>,
+    /// Trades fully closed by the fill.
+    pub trades_closed: Vec<String>,
+}
+
+/// The side of a trade: a long is opened by buying, a short by selling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeDirection {
+    Long,
+    Short,
+}
+
+impl TradeDirection {
+    /// The direction of an order for `units`: positive units buy, anything else sells.
+    pub fn from_units(units: f64) -> Self {
+        if units > 0.0 {
+            TradeDirection::Long
+        } else {
+            TradeDirection::Short
+        }
+    }
+}
+
+/// Checks that a stop loss and take profit are on the right side of `entry` for a
+/// trade in `direction`: for a long the stop loss must be below the entry and the
+/// take profit above it, and the reverse for a short. A level equal to the entry is
+/// on neither side and is rejected.
+pub fn validate_sl_tp(
+    direction: TradeDirection,
+    entry: f64,
+    stop_loss: Option<f64>,
+    take_profit: Option<f64>,
+) -> Result<(), RequestValidationError> {
+    let is_long = direction == TradeDirection::Long;
+    if let Some(stop_loss) = stop_loss {
+        if (is_long && stop_loss >= entry) || (!is_long && stop_loss <= entry) {
+            return Err(RequestValidationError::InvalidStopLoss);
+        }
+    }
+    if let Some(take_profit) = take_profit {
+        if (is_long && take_profit <= entry) || (!is_long && take_profit >= entry) {
+            return Err(RequestValidationError::InvalidTakeProfit);
+        }
+    }
+    Ok(())
+}
+
+impl Client {
+    /// Buys `units` of `instrument` at market, fill-or-kill.
+    pub async fn market_buy(&self, account_id: &str, instrument: &str, units: f64) -> Result<MarketFill, FxError> {
+        self.market_order(account_id, instrument, units.abs()).await
+    }
+
+    /// Sells `units` of `instrument` at market, fill-or-kill. `units` is the
+    /// amount to sell; its sign is ignored.
+    pub async fn market_sell(&self, account_id: &str, instrument: &str, units: f64) -> Result<MarketFill, FxError> {
+        self.market_order(account_id, instrument, -units.abs()).await
+    }
+
+    async fn market_order(&self, account_id: &str, instrument: &str, units: f64) -> Result<MarketFill, FxError> {
+        let fill = self.fill_market_order(account_id, instrument, units).await?;
+
+        Ok(MarketFill {
+            order_id: fill.order_id.unwrap_or_default(),
+            fill_id: fill.id.unwrap_or_default(),
+            instrument: fill.instrument.unwrap_or_else(|| instrument.to_string()),
+            units: fill.units.unwrap_or(units),
+            price: fill.price.unwrap_or_default(),
+            trade_opened: fill.trade_opened.and_then(|trade| trade.trade_id),
+            trade_reduced: fill.trade_reduced.and_then(|trade| trade.trade_id),
+            trades_closed: fill.trades_closed
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|trade| trade.trade_id)
+                .collect(),
+        })
+    }
+
+    /// Places a fill-or-kill market order, returning its fill or an `OrderRejection`
+    /// if it was cancelled instead.
+    async fn fill_market_order(&self, account_id: &str, instrument: &str, units: f64) -> Result<OrderFillTransaction, FxError> {
+        let order = MarketOrder::new()
+            .with_otype("MARKET".to_string())
+            .with_instrument(instrument.to_string())
+            .with_units(units)
+            .with_time_in_force("FOK".to_string());
+        let response = CreateMarketOrderRequest::new()
+            .with_account_id(account_id.to_string())
+            .with_order(order)
+            .remote(self)
+            .await?;
+
+        match response.order_fill_transaction {
+            Some(fill) => Ok(fill),
+            None => {
+                let reason = response.order_cancel_transaction
+                    .and_then(|cancel| cancel.reason)
+                    .unwrap_or_else(|| "NOT_FILLED".to_string());
+                Err(FxError::OrderRejection {
+                    instrument: instrument.to_string(),
+                    units: units.to_string(),
+                    reject_reason: reason,
+                    error_code: "ORDER_CANCELLED".to_string(),
+                    error_message: "The market order was not filled".to_string(),
+                })
+            }
+        }
+    }
+
+    /// Places a good-till-cancelled limit order, with a stop loss and take profit
+    /// attached to the trade it opens, and returns the new order's ID.
+    ///
+    /// Positive `units` buy, negative `units` sell. The stop loss and take profit
+    /// are checked against `price` with [`validate_sl_tp`] before anything is sent.
+    pub async fn limit_order(
+        &self,
+        account_id: &str,
+        instrument: &str,
+        units: f64,
+        price: f64,
+        stop_loss: Option<f64>,
+        take_profit: Option<f64>,
+    ) -> Result<String, FxError> {
+        validate_sl_tp(TradeDirection::from_units(units), price, stop_loss, take_profit)?;
+
+        let mut order = LimitOrder::new()
+            .with_otype("LIMIT".to_string())
+            .with_instrument(instrument.to_string())
+            .with_units(units)
+            .with_price(price)
+            .with_time_in_force("GTC".to_string());
+        if let Some(stop_loss) = stop_loss {
+            order = order.with_stop_loss_on_fill(StopLossDetails::new().with_price(stop_loss));
+        }
+        if let Some(take_profit) = take_profit {
+            order = order.with_take_profit_on_fill(TakeProfitDetails::new().with_price(take_profit));
+        }
+
+        let response = CreateLimitOrderRequest::new()
+            .with_account_id(account_id.to_string())
+            .with_order(order)
+            .remote(self)
+            .await?;
+        response.order_create_transaction
+            .and_then(|transaction| transaction.id)
+            .ok_or_else(|| FxError::DeserializationError {
+                path: "orderCreateTransaction.id".to_string(),
+                message: "missing order ID".to_string(),
+                source: None,
+            })
+    }
+
+    /// Closes every open unit of `instrument`, on whichever sides are open.
+    ///
+    /// Looks the position up first and closes only its open sides with `"ALL"`, as
+    /// OANDA rejects closing a side that holds no units. Returns `None` without
+    /// sending a close when there is nothing open, including when OANDA has no
+    /// position for the instrument at all.
+    pub async fn flatten_position(&self, account_id: &str, instrument: &str) -> Result<Option<ClosePositionResponse>, FxError> {
+        let position = match GetPositionRequest::new()
+            .with_account_id(account_id)
+            .with_instrument(instrument)
+            .remote(self)
+            .await
+        {
+            Ok(response) => response.position,
+            Err(FxError::ApiError { status_code: 404, .. }) => None,
+            Err(e) => return Err(e),
+        };
+        match position.and_then(|position| close_open_sides(account_id, instrument, &position)) {
+            Some(request) => request.remote(self).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Closes every open position in the account, for use as a kill switch.
+    ///
+    /// The open positions are listed, then all of them are closed concurrently, each
+    /// on its open sides. A failed close doesn't stop the others: each instrument's
+    /// outcome is returned alongside it, in the order OANDA listed the positions.
+    /// Only a failure to list the positions fails the whole call.
+    pub async fn close_all_positions(&self, account_id: &str) -> Result<Vec<(String, Result<ClosePositionResponse, FxError>)>, FxError> {
+        let positions = ListOpenPositionsRequest::new()
+            .with_account_id(account_id)
+            .remote(self)
+            .await?
+            .positions
+            .unwrap_or_default();
+        let closes = positions.iter().filter_map(|position| {
+            let instrument = position.instrument.clone()?;
+            let request = close_open_sides(account_id, &instrument, position)?;
+            Some(async move { (instrument, request.remote(self).await) })
+        });
+        Ok(futures::future::join_all(closes).await)
+    }
+
+    /// Cancels every pending order in the account and returns each order's outcome.
+    ///
+    /// The orders are listed, then all cancelled concurrently. A failed cancel doesn't
+    /// stop the others: each order ID is returned alongside its outcome, in the order
+    /// OANDA listed the orders. An order that filled or was cancelled after the listing
+    /// is answered by OANDA with a 404 (or an `ORDER_DOESNT_EXIST` or
+    /// `ORDER_NOT_PENDING` error); it's already gone, so it counts as done and is left
+    /// out of the result. Only a failure to list the orders fails the whole call.
+    pub async fn cancel_all_pending_orders(&self, account_id: &str) -> Result<Vec<(String, Result<CancelOrderResponse, FxError>)>, FxError> {
+        let orders = ListPendingOrdersRequest::new()
+            .with_account_id(account_id)
+            .remote(self)
+            .await?
+            .orders
+            .unwrap_or_default();
+        let cancels = orders.into_iter().filter_map(|order| order.id).map(|order_id| async move {
+            let result = CancelOrderRequest::new()
+                .with_account_id(account_id)
+                .with_order_specifier(order_id.clone())
+                .remote(self)
+                .await;
+            (order_id, result)
+        });
+        let outcomes = futures::future::join_all(cancels).await;
+        Ok(outcomes.into_iter().filter(|(_, result)| !matches!(result,
+            Err(FxError::ApiError { status_code, error_code, .. })
+                if *status_code == 404 || error_code == "ORDER_DOESNT_EXIST" || error_code == "ORDER_NOT_PENDING"
+        )).collect())
+    }
+}
+
+/// A request closing all units of each side of `position` that holds any, or `None`
+/// if neither does. OANDA rejects closing a side without units.
+fn close_open_sides(account_id: &str, instrument: &str, position: &Position) -> Option<ClosePositionRequest> {
+    let open = |side: Option<&PositionSide>| side.and_then(|side| side.units).is_some_and(|units| units != 0.0);
+    let long_open = open(position.long.as_ref());
+    let short_open = open(position.short.as_ref());
+    if !long_open && !short_open {
+        return None;
+    }
+
+    let mut request = ClosePositionRequest::new()
+        .with_account_id(account_id)
+        .with_instrument(instrument);
+    if long_open {
+        request = request.with_long_units("ALL".to_string());
+    }
+    if short_open {
+        request = request.with_short_units("ALL".to_string());
+    }
+    Some(request)
+}
+
+/// Executes `total_units` of `instrument` as `slices` equal fill-or-kill market orders,
+/// one every `interval`, and returns their fills in order. Negative units sell.
+///
+/// Slices are rounded to whole units, with the rounding spread so that they add up to
+/// `total_units`; slices that round to zero are skipped. Execution stops at the first
+/// slice that fails or isn't filled, returning that error. Slices filled before it are
+/// not undone, so check the account's trades before retrying. A `slices` of zero is
+/// treated as one.
+pub async fn twap_execute(
+    client: &Client,
+    account_id: &str,
+    instrument: &str,
+    total_units: f64,
+    slices: u32,
+    interval: std::time::Duration,
+) -> Result<Vec<OrderFillTransaction>, FxError> {
+    let slices = slices.max(1);
+    let mut fills = Vec::new();
+    let mut submitted = 0.0;
+    for slice in 1..=slices {
+        let target = (total_units * slice as f64 / slices as f64).round();
+        let units = target - submitted;
+        if units == 0.0 {
+            continue;
+        }
+        if !fills.is_empty() {
+            tokio::time::sleep(interval).await;
+        }
+        fills.push(client.fill_market_order(account_id, instrument, units).await?);
+        submitted = target;
+    }
+    Ok(fills)
+}
+
+/// Whether the account has an order with the client-assigned ID `client_id`, in any
+/// state. A bot restarting after a crash can check this before resubmitting an order
+/// it tagged with a client ID, so an order that did reach OANDA isn't placed twice.
+///
+/// Looks the order up by the `@clientID` order specifier, with `client_id`
+/// percent-encoded; OANDA's 404 for an unknown ID is `false`, any other error is
+/// returned. Only orders are searched: a trade or transaction carrying the same
+/// client ID without a matching order isn't found.
+pub async fn has_order_with_client_id(client: &Client, account_id: &str, client_id: &str) -> Result<bool, FxError> {
+    let result = GetOrderRequest::new()
+        .with_account_id(account_id.to_string())
+        .with_order_specifier(format!("@{}", utf8_percent_encode(client_id, PATH_SEGMENT)))
+        .remote(client)
+        .await;
+    match result {
+        Ok(_) => Ok(true),
+        Err(FxError::ApiError { status_code: 404, .. }) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
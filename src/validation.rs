@@ -0,0 +1,98 @@
+use crate::errors::RequestValidationError;
+use fxoanda_definitions::*;
+
+/// The trading constraints of an instrument, as returned by `GetAccountInstrumentsRequest`.
+///
+/// Order requests check themselves against it with `validate_order` before submission.
+/// Constraints the instrument doesn't specify are not checked.
+pub type InstrumentMetadata = Instrument;
+
+/// The parts of an order that `validate_order` checks, gathered from whichever order type
+/// a request carries.
+#[derive(Default)]
+pub(crate) struct OrderFields<'a> {
+    pub units: Option<f64>,
+    /// Every price on the order, including those of orders created on fill.
+    pub prices: Vec<f64>,
+    pub trailing_stop_loss_on_fill: Option<&'a TrailingStopLossDetails>,
+    pub stop_loss_on_fill: Option<&'a StopLossDetails>,
+    pub trigger_condition: Option<&'a str>,
+}
+
+impl<'a> OrderFields<'a> {
+    /// Adds the stop loss and take profit created when the order fills.
+    pub fn with_dependents(mut self, stop_loss: Option<&'a StopLossDetails>, take_profit: Option<&TakeProfitDetails>) -> Self {
+        self.stop_loss_on_fill = stop_loss;
+        self.prices.extend(stop_loss.and_then(|sl| sl.price));
+        self.prices.extend(take_profit.and_then(|tp| tp.price));
+        self
+    }
+
+    /// Every constraint of `instrument` the order breaks.
+    ///
+    /// Units must lie between the minimum trade size and maximum order units in
+    /// magnitude, prices may not be quoted finer than the display precision, a
+    /// trailing stop may not be closer than the minimum trailing stop distance, and a
+    /// guaranteed stop loss given as a distance may not be closer than the minimum
+    /// guaranteed stop loss distance. OANDA sets no minimum for a stop loss that
+    /// isn't guaranteed, so its distance isn't checked.
+    pub fn violations(&self, instrument: &InstrumentMetadata) -> Vec<RequestValidationError> {
+        let mut violations = Vec::new();
+
+        if let Some(units) = self.units {
+            let minimum = instrument.minimum_trade_size.unwrap_or(0.0);
+            let maximum = instrument.maximum_order_units.unwrap_or(f64::INFINITY);
+            if units == 0.0 || units.abs() < minimum || units.abs() > maximum {
+                violations.push(RequestValidationError::UnitsOutOfRange { units, minimum, maximum });
+            }
+        }
+
+        if let Some(display_precision) = instrument.display_precision {
+            for &price in &self.prices {
+                if decimal_places(price) > display_precision {
+                    violations.push(RequestValidationError::PricePrecision { price, display_precision });
+                }
+            }
+        }
+
+        let trailing_distance = self.trailing_stop_loss_on_fill.and_then(|ts| ts.distance);
+        if let (Some(distance), Some(minimum)) = (trailing_distance, instrument.minimum_trailing_stop_distance) {
+            if distance < minimum {
+                violations.push(RequestValidationError::StopDistanceTooSmall { distance, minimum });
+            }
+        }
+
+        let guaranteed_distance = self.stop_loss_on_fill
+            .filter(|sl| sl.guaranteed == Some(true))
+            .and_then(|sl| sl.distance);
+        if let (Some(distance), Some(minimum)) = (guaranteed_distance, instrument.minimum_guaranteed_stop_loss_distance) {
+            if distance < minimum {
+                violations.push(RequestValidationError::StopDistanceTooSmall { distance, minimum });
+            }
+        }
+
+        if let Some(condition) = self.trigger_condition {
+            if condition.parse::<OrderTriggerCondition>().is_err() {
+                violations.push(RequestValidationError::InvalidTriggerCondition(condition.to_string()));
+            }
+        }
+
+        violations
+    }
+
+    /// `violations` as the result `validate_order` returns.
+    pub fn validate(&self, instrument: &InstrumentMetadata) -> Result<(), Vec<RequestValidationError>> {
+        let violations = self.violations(instrument);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+/// Decimal places in the shortest representation of `price`, which is how the
+/// price is written to the wire.
+fn decimal_places(price: f64) -> i32 {
+    price.to_string().split_once('.').map_or(0, |(_, decimals)| decimals.len() as i32)
+}
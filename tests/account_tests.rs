@@ -114,11 +114,7 @@ async fn test_get_account_instruments() {
 #[tokio::test]
 async fn test_account_changes_tracking() {
     // Create a mock client for validation testing
-    let client = Client {
-        host: "api-fxpractice.oanda.com".to_string(),
-        reqwest: reqwest::Client::new(),
-        authentication: "test-token".to_string(),
-    };
+    let client = Client::new("api-fxpractice.oanda.com", "test-token");
     
     // Test missing account ID validation
     let result = GetAccountChangesRequest::new()
@@ -175,11 +171,7 @@ async fn test_configure_account() {
 #[tokio::test]
 async fn test_account_error_handling() {
     // Create a mock client for validation testing
-    let client = Client {
-        host: "api-fxpractice.oanda.com".to_string(),
-        reqwest: reqwest::Client::new(),
-        authentication: "test-token".to_string(),
-    };
+    let client = Client::new("api-fxpractice.oanda.com", "test-token");
     
     // Test missing account ID validation
     let result = GetAccountRequest::new()
@@ -258,4 +250,208 @@ async fn test_account_instruments_filtering() {
             // (some OANDA endpoints may not support instrument filtering)
         }
     }
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_full_snapshot_workflow() {
+    let client = create_test_client();
+    let account_id = get_test_account_id(&client).await;
+
+    let result = full_snapshot(&client, &account_id).await;
+    assert!(result.is_ok(), "Failed to fetch account snapshot: {:?}", result);
+
+    let snapshot = result.unwrap();
+    assert_eq!(snapshot.summary.id.as_deref(), Some(account_id.as_str()),
+        "Snapshot summary should be for the requested account");
+    assert!(snapshot.summary.balance.is_some(), "Snapshot summary should have a balance");
+
+    // Other tests trade on the same demo account, so counts can drift between requests
+    for trade in &snapshot.trades {
+        assert_eq!(trade.state.as_deref(), Some("OPEN"), "Snapshot trades should all be open");
+    }
+    for position in &snapshot.positions {
+        assert!(position.instrument.is_some(), "Snapshot position should have an instrument");
+    }
+    for order in &snapshot.pending_orders {
+        assert_eq!(order.state.as_deref(), Some("PENDING"), "Snapshot orders should all be pending");
+    }
+}
+
+#[tokio::test]
+async fn test_strict_auth_rejects_empty_account_list() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"accounts": []}"#)
+        .expect(2)
+        .create_async().await;
+
+    // By default the empty list is passed through as-is
+    let lenient = create_mock_server_client(&server);
+    let result = ListAccountsRequest::new().remote(&lenient).await;
+    assert!(result.is_ok(), "Non-strict client should accept an empty account list: {:?}", result);
+
+    let strict = create_mock_server_client(&server).with_strict_auth(true);
+    let result = ListAccountsRequest::new().remote(&strict).await;
+
+    mock.assert_async().await;
+    assert!(matches!(result, Err(FxError::Unauthorized(_))),
+        "Strict client should treat an empty account list as unauthorized, got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_missing_accounts_field_is_invalid_auth() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("RequestID", "42")
+        .with_body("{}")
+        .create_async().await;
+
+    // Unlike an empty list, this fails whether or not the client is strict
+    let client = create_mock_server_client(&server);
+    let result = ListAccountsRequest::new().remote(&client).await;
+
+    match result {
+        Err(FxError::ApiError { status_code, error_code, request_id, .. }) => {
+            assert_eq!(status_code, 401);
+            assert_eq!(error_code, "INVALID_AUTH");
+            assert_eq!(request_id.as_deref(), Some("42"));
+        }
+        other => panic!("Expected an INVALID_AUTH error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_margin_closeout_accessors() {
+    let account = Account::new()
+        .with_margin_closeout_nav(1000.0)
+        .with_margin_closeout_margin_used(1700.0)
+        .with_margin_closeout_position_value(85000.0)
+        .with_margin_closeout_percent(0.85);
+
+    assert_eq!(account.margin_closeout_nav(), Some(1000.0));
+    assert_eq!(account.margin_closeout_margin_used(), Some(1700.0));
+    assert_eq!(account.margin_closeout_position_value(), Some(85000.0));
+    assert!((account.margin_closeout_percent().unwrap() - 0.85).abs() < 1e-6);
+
+    assert!(account.is_near_margin_closeout(0.8), "85% should be near an 80% threshold");
+    assert!(!account.is_near_margin_closeout(0.9), "85% should not be near a 90% threshold");
+    assert!(!Account::new().is_near_margin_closeout(0.8), "Missing closeout percent is not near closeout");
+}
+
+#[test]
+fn test_account_position_mode() {
+    assert_eq!(Account::new().with_hedging_enabled(true).is_hedging(), Some(true));
+    assert_eq!(Account::new().with_hedging_enabled(false).is_hedging(), Some(false));
+    assert_eq!(AccountSummary::new().with_hedging_enabled(false).is_hedging(), Some(false));
+    assert_eq!(Account::new().is_hedging(), None);
+}
+
+#[tokio::test]
+async fn test_wait_until_polls_until_predicate_holds() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let mut server = mockito::Server::new_async().await;
+    let account_path = "/v3/accounts/101-001-1234567-001";
+    let mut mocks = Vec::new();
+    for (endpoint, body) in [
+        ("summary", r#"{"account": {"id": "101-001-1234567-001"}, "lastTransactionID": "1"}"#),
+        ("openTrades", r#"{"trades": [], "lastTransactionID": "1"}"#),
+        ("openPositions", r#"{"positions": [], "lastTransactionID": "1"}"#),
+    ] {
+        mocks.push(server.mock("GET", format!("{}/{}", account_path, endpoint).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .expect(2)
+            .create_async().await);
+    }
+
+    // The pending order is gone by the second poll
+    let polls = Arc::new(AtomicUsize::new(0));
+    let poll_counter = polls.clone();
+    mocks.push(server.mock("GET", format!("{}/pendingOrders", account_path).as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |_| {
+            if poll_counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                br#"{"orders": [{"id": "42", "state": "PENDING"}], "lastTransactionID": "1"}"#.to_vec()
+            } else {
+                br#"{"orders": [], "lastTransactionID": "2"}"#.to_vec()
+            }
+        })
+        .expect(2)
+        .create_async().await);
+    let client = create_mock_server_client(&server);
+
+    let result = wait_until(
+        &client,
+        "101-001-1234567-001",
+        |snapshot| snapshot.pending_orders.is_empty(),
+        Duration::from_secs(5),
+        Duration::from_millis(10),
+    ).await;
+
+    for mock in &mocks {
+        mock.assert_async().await;
+    }
+    let snapshot = result.expect("Predicate should hold on the second poll");
+    assert!(snapshot.pending_orders.is_empty());
+    assert_eq!(polls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_account_id_validation_and_builders() {
+    let id = AccountId::new("101-004-1234567-001").expect("well-formed ID should be accepted");
+    assert_eq!(id.to_string(), "101-004-1234567-001");
+    assert_eq!(id.as_ref(), "101-004-1234567-001");
+
+    for bad in ["", "101-004-1234567", "101-004-1234567-001-1", "101-004-12345a7-001", "101--1234567-001", "101_004_1234567_001"] {
+        assert_eq!(AccountId::new(bad), Err(RequestValidationError::InvalidAccountId(bad.to_string())), "{:?} should be rejected", bad);
+    }
+    assert!("101-004-1234567-001".parse::<AccountId>().is_ok());
+    assert!(serde_json::from_str::<AccountId>(r#""not-an-id""#).is_err(), "Deserializing should validate too");
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/summary")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"account": {"id": "101-004-1234567-001"}, "lastTransactionID": "1"}"#)
+        .expect(2)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    // Builders take a validated ID or a plain String alike
+    GetAccountSummaryRequest::new().with_account_id(id).remote(&client).await.unwrap();
+    GetAccountSummaryRequest::new().with_account_id("101-004-1234567-001".to_string()).remote(&client).await.unwrap();
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_instrument_pips_and_display_rounding() {
+    let eur_usd = Instrument::new()
+        .with_name("EUR_USD".to_string())
+        .with_pip_location(-4)
+        .with_display_precision(5);
+    let usd_jpy = Instrument::new()
+        .with_name("USD_JPY".to_string())
+        .with_pip_location(-2)
+        .with_display_precision(3);
+
+    assert!((eur_usd.pips(1.10250 - 1.10000) - 25.0).abs() < 1e-9);
+    assert!((eur_usd.pips(-0.0015) + 15.0).abs() < 1e-9, "Negative deltas stay negative");
+    // The same number of pips is a hundred times the price move on a JPY pair
+    assert!((usd_jpy.pips(150.250 - 150.000) - 25.0).abs() < 1e-9);
+
+    assert_eq!(eur_usd.round_to_display_precision(1.1234567), 1.12346);
+    assert_eq!(usd_jpy.round_to_display_precision(150.12345), 150.123);
+
+    let bare = Instrument::new();
+    assert_eq!(bare.pips(0.5), 0.5);
+    assert_eq!(bare.round_to_display_precision(1.1234567), 1.1234567);
+}
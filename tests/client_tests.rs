@@ -11,9 +11,9 @@ use common::*;
 async fn test_client_initialization_valid_credentials() {
     let client = create_test_client();
     
-    assert!(!client.host.is_empty());
-    assert!(!client.authentication.is_empty());
-    assert_eq!(client.host, "api-fxpractice.oanda.com");
+    assert!(!client.host().is_empty());
+    assert!(!client.authentication().is_empty());
+    assert_eq!(client.host(), "api-fxpractice.oanda.com");
 }
 
 #[test]
@@ -21,15 +21,11 @@ fn test_client_initialization_invalid_host() {
     // Test direct client creation with invalid host (bypassing create_test_client)
     let api_key = env::var("OANDA_KEY").expect("OANDA_KEY environment variable must be set");
     
-    let client = Client {
-        host: "api-fxtrade.oanda.com".to_string(),
-        reqwest: reqwest::Client::new(),
-        authentication: api_key,
-    };
+    let client = Client::new("api-fxtrade.oanda.com", api_key);
     
     // This should work fine - the client accepts any host, only create_test_client enforces demo
-    assert_eq!(client.host, "api-fxtrade.oanda.com");
-    assert!(!client.authentication.is_empty());
+    assert_eq!(client.host(), "api-fxtrade.oanda.com");
+    assert!(!client.authentication().is_empty());
 }
 
 #[tokio::test]
@@ -45,8 +41,8 @@ async fn test_authentication_header_construction() {
     // Test that authentication header is properly constructed
     // We can't easily test the actual header construction without making a request,
     // but we can verify the authentication field is set
-    assert!(!client.authentication.is_empty());
-    assert!(client.authentication.len() > 10); // Basic sanity check for token length
+    assert!(!client.authentication().is_empty());
+    assert!(client.authentication().len() > 10); // Basic sanity check for token length
 }
 
 #[tokio::test]
@@ -64,15 +60,21 @@ async fn test_basic_connectivity() {
     assert!(!accounts.accounts.expect("Should have accounts").is_empty(), "Should have at least one demo account");
 }
 
+#[tokio::test]
+async fn test_ping_demo() {
+    let client = create_test_client();
+
+    let result = client.ping().await;
+    assert!(result.is_ok(), "Demo account should answer a ping: {:?}", result);
+}
+
 #[tokio::test]
 async fn test_client_timeout_handling() {
-    let mut client = create_test_client();
-    
     // Create a client with very short timeout
-    client.reqwest = reqwest::ClientBuilder::new()
+    let client = create_test_client().with_reqwest(reqwest::ClientBuilder::new()
         .timeout(std::time::Duration::from_millis(1))
         .build()
-        .unwrap();
+        .unwrap());
     
     // This should timeout
     let result = ListAccountsRequest::new()
@@ -84,8 +86,7 @@ async fn test_client_timeout_handling() {
 
 #[tokio::test]
 async fn test_invalid_authentication() {
-    let mut client = create_test_client();
-    client.authentication = "invalid_token".to_string();
+    let client = Client::new(create_test_client().host(), "invalid_token");
     
     let result = ListAccountsRequest::new()
         .remote(&client)
@@ -126,11 +127,7 @@ async fn test_multiple_concurrent_requests() {
     
     // Helper function to check if error is due to temporary API issues
     let is_temporary_api_error = |e: &FxError| -> bool {
-        let error_str = format!("{:?}", e);
-        error_str.contains("expected value") || 
-        error_str.contains("connection") || 
-        error_str.contains("timeout") ||
-        error_str.contains("Decode")
+        e.is_retryable() || matches!(e, FxError::DeserializationError { .. } | FxError::HttpError(_))
     };
     
     let max_attempts = 3;
@@ -293,14 +290,14 @@ async fn test_client_shared_across_tasks() {
 
 #[tokio::test]
 async fn test_manual_retry_logic_simulation() {
-    let mut client = create_test_client();
+    let client = create_test_client();
     let account_id = get_test_account_id(&client).await;
     
     // Create a client with a moderately short timeout to simulate temporary failures
-    client.reqwest = reqwest::ClientBuilder::new()
+    let client = client.with_reqwest(reqwest::ClientBuilder::new()
         .timeout(Duration::from_millis(500)) // Short timeout to potentially cause failures
         .build()
-        .unwrap();
+        .unwrap());
     
     let max_retries = 3;
     let mut retry_count = 0;
@@ -487,11 +484,8 @@ async fn test_client_error_recovery_patterns() {
     // Test how the client behaves with various error conditions
     
     // 1. Test with completely invalid host
-    let invalid_client = Client {
-        host: "invalid-host-that-does-not-exist.com".to_string(),
-        reqwest: client.reqwest.clone(),
-        authentication: client.authentication.clone(),
-    };
+    let invalid_client = Client::new("invalid-host-that-does-not-exist.com", client.authentication())
+        .with_reqwest(client.reqwest().clone());
     
     let invalid_result = ListAccountsRequest::new()
         .remote(&invalid_client)
@@ -500,8 +494,7 @@ async fn test_client_error_recovery_patterns() {
     assert!(invalid_result.is_err(), "Request to invalid host should fail");
     
     // 2. Test with invalid authentication
-    let mut temp_client = create_test_client();
-    temp_client.authentication = "invalid_temp_token".to_string();
+    let temp_client = Client::new(create_test_client().host(), "invalid_temp_token");
     
     let temp_result = ListAccountsRequest::new()
         .remote(&temp_client)
@@ -567,4 +560,500 @@ async fn test_high_frequency_request_pattern() {
     
     // This test helps identify where retry logic and connection pooling would be beneficial
     println!("High-frequency request pattern test completed successfully");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_clock_skew_from_date_header() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    let now = Utc.with_ymd_and_hms(2024, 11, 15, 8, 12, 0).unwrap();
+    let threshold = Duration::seconds(5);
+
+    // Server 31s ahead of the local clock
+    let skew = clock_skew("Fri, 15 Nov 2024 08:12:31 GMT", now, threshold);
+    assert_eq!(skew, Some(Duration::seconds(31)));
+
+    // Local clock ahead of the server
+    let skew = clock_skew("Fri, 15 Nov 2024 08:11:58 GMT", now, threshold);
+    assert_eq!(skew, Some(Duration::seconds(-2)));
+
+    assert_eq!(clock_skew("not a date", now, threshold), None);
+}
+
+#[test]
+fn test_client_builder_environments() {
+    let practice = Client::builder()
+        .practice()
+        .token("practice-token".to_string())
+        .build()
+        .expect("builder with a token should succeed");
+    assert_eq!(practice.host(), "api-fxpractice.oanda.com");
+    assert_eq!(practice.authentication(), "practice-token");
+
+    let live = Client::builder()
+        .live()
+        .token("live-token".to_string())
+        .reqwest(reqwest::Client::new())
+        .build()
+        .expect("builder with a token should succeed");
+    assert_eq!(live.host(), "api-fxtrade.oanda.com");
+
+    let default_environment = Client::builder().token("token".to_string()).build().unwrap();
+    assert_eq!(default_environment.host(), OandaEnvironment::Practice.rest_host());
+
+    let missing_token = Client::builder().live().build();
+    assert!(matches!(missing_token, Err(FxError::Validation(RequestValidationError::MissingToken))));
+}
+
+/// Collects formatted tracing output so tests can inspect emitted events.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_large_response_body_is_truncated_in_logs() {
+    let mut summary: serde_json::Value = serde_json::from_str(&load_fixture("account_summary.json")).unwrap();
+    summary["account"]["alias"] = serde_json::Value::String("x".repeat(10_000));
+    let body = summary.to_string();
+
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/summary")
+        .with_status(200)
+        .with_body(&body)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server).with_body_logging(BodyLogging::Truncate(200));
+
+    let logs = CapturedLogs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    GetAccountSummaryRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .remote(&client)
+        .await
+        .expect("summary should deserialize");
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains("OANDA response"), "Expected a response event, got: {}", output);
+    assert!(output.contains(&format!("... ({} bytes total)", body.len())), "Body should be truncated: {}", output);
+    assert!(!output.contains(&"x".repeat(1_000)), "Full body should not be logged");
+}
+
+#[test]
+fn test_body_logging_modes() {
+    let body = "é".repeat(100);
+    assert_eq!(BodyLogging::Full.format(&body), body);
+    assert_eq!(BodyLogging::Truncate(500).format(&body), body);
+    // Cuts back to a char boundary rather than splitting a character
+    assert_eq!(BodyLogging::Truncate(5).format(&body), "éé... (200 bytes total)");
+
+    let digest = BodyLogging::Digest.format(&body);
+    assert!(digest.starts_with("<200 bytes, hash "), "Unexpected digest: {}", digest);
+    assert_eq!(digest, BodyLogging::Digest.format(&body), "Digest should be stable for the same body");
+    assert_ne!(digest, BodyLogging::Digest.format("other"));
+}
+
+#[test]
+fn test_oanda_environment_hosts() {
+    assert_eq!(OandaEnvironment::Practice.rest_host(), "api-fxpractice.oanda.com");
+    assert_eq!(OandaEnvironment::Practice.stream_host(), "stream-fxpractice.oanda.com");
+    assert_eq!(OandaEnvironment::Live.rest_host(), "api-fxtrade.oanda.com");
+    assert_eq!(OandaEnvironment::Live.stream_host(), "stream-fxtrade.oanda.com");
+    assert_eq!(OandaEnvironment::from_rest_host("api-fxtrade.oanda.com"), Some(OandaEnvironment::Live));
+    assert_eq!(OandaEnvironment::from_rest_host("localhost:8080"), None);
+
+    let client = Client::builder().live().token("token".to_string()).build().unwrap();
+    assert_eq!(client.environment(), Some(OandaEnvironment::Live));
+}
+
+fn fast_retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(5),
+    }
+}
+
+#[tokio::test]
+async fn test_retry_policy_retries_server_errors() {
+    let path = "/v3/accounts/101-004-1234567-001/summary";
+    let mut server = mockito::Server::new_async().await;
+    let unavailable = server.mock("GET", path)
+        .with_status(503)
+        .with_body(r#"{"errorMessage":"Service unavailable"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let recovered = server.mock("GET", path)
+        .with_status(200)
+        .with_body(load_fixture("account_summary.json"))
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server).with_retry(fast_retry_policy());
+
+    let result = GetAccountSummaryRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .remote(&client)
+        .await;
+
+    unavailable.assert_async().await;
+    recovered.assert_async().await;
+    assert!(result.is_ok(), "Request should succeed after retrying: {:?}", result);
+}
+
+#[tokio::test]
+async fn test_retry_policy_skips_deterministic_failures() {
+    let path = "/v3/accounts/101-004-1234567-001/summary";
+    let mut server = mockito::Server::new_async().await;
+    let bad_request = server.mock("GET", path)
+        .with_status(400)
+        .with_body(r#"{"errorMessage":"Invalid value specified for 'accountID'"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server).with_retry(fast_retry_policy());
+
+    let result = GetAccountSummaryRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .remote(&client)
+        .await;
+    bad_request.assert_async().await;
+    assert!(matches!(result, Err(FxError::ApiError { status_code: 400, .. })));
+
+    // Order creation isn't idempotent, so a 5xx isn't retried
+    let orders = server.mock("POST", "/v3/accounts/101-004-1234567-001/orders")
+        .with_status(503)
+        .with_body(r#"{"errorMessage":"Service unavailable"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let result = CreateLimitOrderRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .with_order(LimitOrder::new().with_instrument("EUR_USD".to_string()).with_units(1.0).with_price(1.0))
+        .remote(&client)
+        .await;
+    orders.assert_async().await;
+    assert!(matches!(result, Err(FxError::ApiError { status_code: 503, .. })));
+
+    // Without a policy nothing is retried
+    let unavailable = server.mock("GET", "/v3/accounts/101-004-1234567-001/instruments")
+        .with_status(503)
+        .with_body(r#"{"errorMessage":"Service unavailable"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let result = GetAccountInstrumentsRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .remote(&create_mock_server_client(&server))
+        .await;
+    unavailable.assert_async().await;
+    assert!(matches!(result, Err(FxError::ApiError { status_code: 503, .. })));
+}
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy = RetryPolicy {
+        max_retries: 10,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(1),
+    };
+    for (attempt, backoff) in [(0, 100), (1, 200), (2, 400), (3, 800), (4, 1000), (9, 1000)] {
+        let delay = policy.delay(attempt);
+        let backoff = Duration::from_millis(backoff);
+        assert!(delay >= backoff / 2 && delay <= backoff,
+            "Retry {} should wait between {:?} and {:?}, got {:?}", attempt, backoff / 2, backoff, delay);
+    }
+    assert_eq!(RetryPolicy::default().max_retries, 0, "Retrying should be opt-in");
+}
+
+#[test]
+fn test_retry_policy_backoff_with_unbounded_max_delay() {
+    let policy = RetryPolicy {
+        max_retries: u32::MAX,
+        base_delay: Duration::from_secs(u64::MAX / 4),
+        max_delay: Duration::MAX,
+    };
+    assert!(policy.delay(0) >= Duration::from_secs(u64::MAX / 8));
+    for attempt in [2, 3, 64, u32::MAX] {
+        let delay = policy.delay(attempt);
+        assert!(delay >= Duration::MAX / 2, "Retry {} should saturate near Duration::MAX, got {:?}", attempt, delay);
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limit_headers_are_captured() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/summary")
+        .with_status(200)
+        .with_header("X-RateLimit-Limit", "120")
+        .with_header("X-RateLimit-Remaining", "87")
+        .with_header("X-RateLimit-Reset", "1")
+        .with_body(load_fixture("account_summary.json"))
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+    assert_eq!(client.rate_limit(), None, "Nothing is known before the first response");
+
+    GetAccountSummaryRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .remote(&client)
+        .await
+        .expect("summary should deserialize");
+
+    assert_eq!(client.rate_limit(), Some(RateLimitInfo { limit: Some(120), remaining: Some(87), reset: Some(1) }));
+}
+
+#[test]
+fn test_rate_limit_info_from_headers() {
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    let mut headers = HeaderMap::new();
+    assert_eq!(RateLimitInfo::from_headers(&headers), None);
+
+    headers.insert("RateLimit-Remaining", HeaderValue::from_static("5"));
+    headers.insert("RateLimit-Reset", HeaderValue::from_static("not-a-number"));
+    assert_eq!(
+        RateLimitInfo::from_headers(&headers),
+        Some(RateLimitInfo { limit: None, remaining: Some(5), reset: None })
+    );
+}
+
+#[tokio::test]
+async fn test_server_time_from_date_header() {
+    use chrono::{TimeZone, Utc};
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts")
+        .with_status(200)
+        .with_header("Date", "Fri, 15 Nov 2024 08:12:31 GMT")
+        .with_body(r#"{"accounts": [{"id": "101-004-1234567-001", "tags": []}]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let server_time = client.server_time().await.expect("Date header should parse");
+
+    mock.assert_async().await;
+    assert_eq!(server_time, Utc.with_ymd_and_hms(2024, 11, 15, 8, 12, 31).unwrap());
+}
+
+#[tokio::test]
+async fn test_cached_read_skips_network_within_ttl() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/instruments")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"instruments": [{"name": "EUR_USD", "type": "CURRENCY", "displayPrecision": 5}], "lastTransactionID": "6"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server).with_cache_ttl(std::time::Duration::from_secs(60));
+    let request = || GetAccountInstrumentsRequest::new().with_account_id("101-001-1234567-001".to_string());
+
+    let first = request().remote(&client).await.expect("first read should succeed");
+    let second = request().remote(&client).await.expect("cached read should succeed");
+    assert_eq!(first.instruments.unwrap()[0].name, second.instruments.unwrap()[0].name);
+
+    // Clearing the cache sends the next read to the server again
+    client.cache().clear();
+    request().remote(&client).await.expect("read after clear should succeed");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_ping_fails_on_bad_auth() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorMessage": "Insufficient authorization to perform request."}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let result = client.ping().await;
+
+    mock.assert_async().await;
+    assert!(matches!(&result, Err(FxError::ApiError { status_code: 401, error_code, .. }) if error_code == "INVALID_AUTH"), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_per_request_timeout_on_mixed_workload() {
+    let mut server = mockito::Server::new_async().await;
+    let _slow = server.mock("GET", "/v3/accounts/101-001-1234567-001/summary")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|_| {
+            std::thread::sleep(Duration::from_millis(500));
+            br#"{"account": {}, "lastTransactionID": "1"}"#.to_vec()
+        })
+        .create_async()
+        .await;
+    let _fast = server.mock("GET", "/v3/accounts/101-001-1234567-001/openTrades")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"trades": [], "lastTransactionID": "1"}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let slow = GetAccountSummaryRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_timeout(Duration::from_millis(50))
+        .remote(&client)
+        .await;
+    let slow = slow.unwrap_err();
+    assert!(matches!(slow, FxError::Timeout(Some(_))), "{:?}", slow);
+    let source = std::error::Error::source(&slow).and_then(|source| source.downcast_ref::<reqwest::Error>());
+    assert!(source.is_some_and(|err| err.is_timeout()), "the reqwest error should be the source: {:?}", slow);
+
+    // The same client still serves other requests under their own limits
+    let fast = ListOpenTradesRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_timeout(Duration::from_secs(5))
+        .remote(&client)
+        .await;
+    assert!(fast.is_ok(), "{:?}", fast);
+}
+
+#[tokio::test]
+async fn test_ping_treats_empty_account_list_as_invalid_auth() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"accounts": []}"#)
+        .create_async()
+        .await;
+    // A client without strict_auth: ping is strict regardless
+    let client = create_mock_server_client(&server);
+
+    let result = client.ping().await;
+    assert!(matches!(&result, Err(FxError::ApiError { status_code: 401, error_code, .. }) if error_code == "INVALID_AUTH"), "{:?}", result);
+
+    // The same shape when list_accounts itself rejects the empty list
+    let result = create_mock_server_client(&server).with_strict_auth(true).ping().await;
+    assert!(matches!(&result, Err(FxError::ApiError { status_code: 401, error_code, .. }) if error_code == "INVALID_AUTH"), "{:?}", result);
+
+    let _forbidden = server.mock("GET", "/v3/accounts")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorMessage": "The provided request was forbidden."}"#)
+        .create_async()
+        .await;
+    let result = client.ping().await;
+    assert!(matches!(&result, Err(FxError::ApiError { status_code: 403, error_code, .. }) if error_code == "INVALID_AUTH"), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_request_datetime_format_overrides_client() {
+    let mut server = mockito::Server::new_async().await;
+    let trades = server.mock("GET", "/v3/accounts/101-001-1234567-001/openTrades")
+        .match_header("Accept-Datetime-Format", "RFC3339")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"trades": [], "lastTransactionID": "7"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server).with_datetime_format(DatetimeFormat::Unix);
+
+    ListOpenTradesRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_accept_datetime_format("RFC3339".to_string())
+        .remote(&client)
+        .await
+        .expect("the request's own format should be sent");
+
+    trades.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_client_datetime_format_applies_to_every_request() {
+    use chrono::{TimeZone, Utc};
+
+    let mut server = mockito::Server::new_async().await;
+    let trades = server.mock("GET", "/v3/accounts/101-001-1234567-001/openTrades")
+        .match_header("Accept-Datetime-Format", "UNIX")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"trades": [{"id": "7", "instrument": "EUR_USD", "openTime": "1704189600.250000000"}], "lastTransactionID": "7"}"#)
+        .create_async()
+        .await;
+    let candles = server.mock("GET", "/v3/instruments/EUR_USD/candles")
+        .match_header("Accept-Datetime-Format", "UNIX")
+        .match_query(mockito::Matcher::UrlEncoded("from".to_string(), "1704189600.000000000".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"instrument": "EUR_USD", "granularity": "M1", "candles": []}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server).with_datetime_format(DatetimeFormat::Unix);
+
+    let response = ListOpenTradesRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .remote(&client)
+        .await
+        .expect("request should carry the client's datetime format");
+    let trade = &response.trades.unwrap()[0];
+    assert_eq!(trade.open_time, Utc.timestamp_opt(1704189600, 250_000_000).single());
+
+    // Candle ranges follow the client's format without a per-request header
+    GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD")
+        .with_granularity(CandlestickGranularity::M1)
+        .with_from(Utc.timestamp_opt(1704189600, 0).unwrap())
+        .remote(&client)
+        .await
+        .expect("candles should be requested with a UNIX from");
+
+    trades.assert_async().await;
+    candles.assert_async().await;
+
+    let built = Client::builder()
+        .token("token".to_string())
+        .datetime_format(DatetimeFormat::Unix)
+        .build()
+        .unwrap();
+    assert_eq!(built.datetime_format(), DatetimeFormat::Unix);
+    assert_eq!(Client::default().datetime_format().as_str(), "RFC3339");
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_remote_blocking_without_runtime() {
+    let mut server = mockito::Server::new();
+    let _mock = server.mock("GET", "/v3/accounts")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"accounts": [{"id": "101-004-1234567-001", "tags": []}]}"#)
+        .create();
+    let client = create_mock_server_client(&server);
+
+    let response = ListAccountsRequest::new()
+        .remote_blocking(&client)
+        .expect("blocking request should succeed outside a runtime");
+    assert_eq!(response.accounts.unwrap()[0].id.as_deref(), Some("101-004-1234567-001"));
+
+    // Other async helpers run the same way
+    assert!(fxoanda::blocking::block_on(client.ping()).is_ok());
+}
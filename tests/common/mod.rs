@@ -1,30 +1,26 @@
 use fxoanda::*;
-use std::env;
 use chrono::Utc;
 
 /// Create a test client with demo credentials and safety checks
 pub fn create_test_client() -> Client {
-    let api_key = env::var("OANDA_KEY").expect("OANDA_KEY environment variable must be set for tests");
-    let api_host = env::var("OANDA_HOST").expect("OANDA_HOST environment variable must be set for tests");
+    let client = Client::from_env().expect("OANDA_KEY and OANDA_HOST environment variables must be set for tests");
     
     // Safety: Ensure we're only using the demo environment
-    assert_eq!(api_host, "api-fxpractice.oanda.com", 
+    assert_eq!(client.host(), "api-fxpractice.oanda.com", 
         "Tests must only run against demo environment. Set OANDA_HOST=api-fxpractice.oanda.com");
     
-    Client {
-        host: api_host,
-        reqwest: reqwest::Client::new(),
-        authentication: api_key,
-    }
+    client
 }
 
 /// Create a mock client for unit tests - no real API calls
 pub fn create_mock_client() -> Client {
-    Client {
-        host: "mock-api.test".to_string(),
-        reqwest: reqwest::Client::new(),
-        authentication: "mock-token".to_string(),
-    }
+    Client::new("mock-api.test", "mock-token")
+}
+
+/// Create a client pointed at a local mockito server - canned responses, no real API calls
+#[allow(dead_code)]
+pub fn create_mock_server_client(server: &mockito::ServerGuard) -> Client {
+    Client::new(server.url(), "mock-token")
 }
 
 /// Helper to get the first available account ID from the client
@@ -105,7 +101,7 @@ pub async fn create_test_position(ctx: &TestContext, instrument: &str, units: i3
     
     let market_order = MarketOrder::new()
         .with_instrument(instrument.to_string())
-        .with_units(units as f32)
+        .with_units(units as f64)
         .with_time_in_force("FOK".to_string())
         .with_otype("MARKET".to_string());
     
@@ -175,6 +171,30 @@ pub fn assert_price_precision(price: f64, instrument: &str) {
     }
 }
 
+/// Read a recorded API response from `tests/fixtures`
+#[allow(dead_code)]
+pub fn load_fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read fixture {}: {}", path, e))
+}
+
+/// Deserialize like serde's `deny_unknown_fields`, listing every field the target type doesn't model.
+///
+/// serde silently drops unknown fields, so fields OANDA adds to a response would otherwise go
+/// unnoticed. Run against recorded fixtures to catch schema drift.
+#[allow(dead_code)]
+pub fn deserialize_strict<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, String> {
+    let mut unknown_fields = Vec::new();
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let value = serde_ignored::deserialize(deserializer, |path| unknown_fields.push(path.to_string()))
+        .map_err(|e| e.to_string())?;
+    if unknown_fields.is_empty() {
+        Ok(value)
+    } else {
+        Err(format!("unknown fields: {}", unknown_fields.join(", ")))
+    }
+}
+
 /// Test data for various scenarios
 pub mod fixtures {
     use super::*;
@@ -0,0 +1,39 @@
+// Kept in its own test binary: these tests change process-wide environment
+// variables that the live tests read.
+use fxoanda::*;
+use std::env;
+
+#[test]
+fn test_client_from_env() {
+    let saved_key = env::var("OANDA_KEY").ok();
+    let saved_host = env::var("OANDA_HOST").ok();
+
+    env::set_var("OANDA_KEY", " test-token\n");
+    env::set_var("OANDA_HOST", "  https://api-fxpractice.oanda.com/ ");
+    let client = Client::from_env().expect("both variables are set");
+    assert_eq!(client.host(), "api-fxpractice.oanda.com");
+    assert_eq!(client.authentication(), "test-token");
+
+    env::set_var("OANDA_HOST", "api-fxtrade.oanda.com");
+    assert_eq!(Client::from_env().unwrap().host(), "api-fxtrade.oanda.com");
+
+    env::remove_var("OANDA_HOST");
+    assert!(matches!(
+        Client::from_env(),
+        Err(FxError::Validation(RequestValidationError::MissingCredentials(ref var))) if var == "OANDA_HOST"
+    ));
+
+    env::set_var("OANDA_HOST", "api-fxpractice.oanda.com");
+    env::set_var("OANDA_KEY", "   ");
+    assert!(matches!(
+        Client::from_env(),
+        Err(FxError::Validation(RequestValidationError::MissingCredentials(ref var))) if var == "OANDA_KEY"
+    ));
+
+    for (name, saved) in [("OANDA_KEY", saved_key), ("OANDA_HOST", saved_host)] {
+        match saved {
+            Some(value) => env::set_var(name, value),
+            None => env::remove_var(name),
+        }
+    }
+}
@@ -1,11 +1,7 @@
 use fxoanda::*;
 
 fn create_mock_client() -> Client {
-    Client {
-        host: "api-fxpractice.oanda.com".to_string(),
-        reqwest: reqwest::Client::new(),
-        authentication: "test-token".to_string(),
-    }
+    Client::new("api-fxpractice.oanda.com", "test-token")
 }
 
 #[cfg(test)]
@@ -76,6 +72,46 @@ mod test_instrument_errors {
         assert!(matches!(error, FxError::Validation(RequestValidationError::MissingInstrument)));
     }
 
+    #[tokio::test]
+    async fn test_get_candles_conflicting_range_params() {
+        let client = create_mock_client();
+        let from = chrono::Utc::now() - chrono::Duration::days(2);
+        let to = chrono::Utc::now() - chrono::Duration::days(1);
+
+        let result = GetInstrumentCandlesRequest::new()
+            .with_instrument("EUR_USD")
+            .with_count(10)
+            .with_from(from)
+            .with_to(to)
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::ConflictingCandleParams))), "{:?}", result);
+
+        let result = GetInstrumentCandlesRequest::new()
+            .with_instrument("EUR_USD")
+            .with_from(to)
+            .with_to(from)
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidTimeRange { .. }))), "{:?}", result);
+
+        let result = GetInstrumentCandlesRequest::new()
+            .with_instrument("EUR_USD")
+            .with_from(to)
+            .with_to(from)
+            .remote_paged(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidTimeRange { .. }))), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_get_candles_count_too_large() {
+        let client = create_mock_client();
+
+        let result = GetInstrumentCandlesRequest::new()
+            .with_instrument("EUR_USD")
+            .with_count(10000)
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::CountTooLarge { count: 10000, max: 5000 }))), "{:?}", result);
+    }
+
     #[tokio::test]
     async fn test_get_order_book_missing_instrument() {
         let client = create_mock_client();
@@ -99,6 +135,70 @@ mod test_instrument_errors {
     }
 }
 
+#[cfg(test)]
+mod test_order_errors {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_limit_order_zero_price() {
+        let client = create_mock_client();
+        let request = CreateLimitOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .with_order(LimitOrder::new().with_instrument("EUR_USD".to_string()).with_units(100.0).with_price(0.0));
+        
+        let result = request.remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidPrice))));
+    }
+
+    #[tokio::test]
+    async fn test_create_limit_order_missing_price() {
+        let client = create_mock_client();
+        let request = CreateLimitOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .with_order(LimitOrder::new().with_instrument("EUR_USD".to_string()).with_units(100.0));
+        
+        let result = request.remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::MissingPrice))));
+    }
+
+    #[tokio::test]
+    async fn test_create_orders_missing_order_or_units() {
+        let client = create_mock_client();
+        let result = CreateMarketOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::MissingOrder))), "{:?}", result);
+
+        let result = CreateMarketOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .with_order(MarketOrder::new().with_instrument("EUR_USD".to_string()))
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::MissingUnits))), "{:?}", result);
+
+        let result = CreateLimitOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .with_order(LimitOrder::new().with_instrument("EUR_USD".to_string()).with_price(1.1))
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::MissingUnits))), "{:?}", result);
+
+        let result = CreateStopOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::MissingOrder))), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_create_stop_order_negative_price() {
+        let client = create_mock_client();
+        let request = CreateStopOrderRequest::new()
+            .with_account_id("123-456-789-012".to_string())
+            .with_order(StopOrder::new().with_instrument("EUR_USD".to_string()).with_units(100.0).with_price(-1.1));
+        
+        let result = request.remote(&client).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidPrice))));
+    }
+}
+
 #[cfg(test)]
 mod test_pricing_errors {
     // Note: We need to identify what pricing requests have path parameters
@@ -109,4 +209,4 @@ mod test_pricing_errors {
         // pricing requests that have unwrap() calls with path parameters
         assert!(true, "Placeholder for pricing error tests");
     }
-}
\ No newline at end of file
+}
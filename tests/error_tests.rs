@@ -5,8 +5,7 @@ use common::*;
 
 #[tokio::test]
 async fn test_invalid_authentication() {
-    let mut client = create_test_client();
-    client.authentication = "invalid_token".to_string();
+    let client = Client::new(create_test_client().host(), "invalid_token");
     
     let result = ListAccountsRequest::new()
         .remote(&client)
@@ -25,11 +24,7 @@ async fn test_invalid_authentication() {
 #[tokio::test]
 async fn test_invalid_account_id() {
     // Create a mock client for testing
-    let client = Client {
-        host: "api-fxpractice.oanda.com".to_string(),
-        reqwest: reqwest::Client::new(),
-        authentication: "test-token".to_string(),
-    };
+    let client = Client::new("api-fxpractice.oanda.com", "test-token");
     
     let result = GetAccountRequest::new()
         .with_account_id("invalid_account_id".to_string())
@@ -65,13 +60,11 @@ async fn test_invalid_instrument() {
 
 #[tokio::test]
 async fn test_network_timeout_handling() {
-    let mut client = create_test_client();
-    
     // Create a client with very short timeout
-    client.reqwest = reqwest::ClientBuilder::new()
+    let client = create_test_client().with_reqwest(reqwest::ClientBuilder::new()
         .timeout(std::time::Duration::from_millis(1))
         .build()
-        .unwrap();
+        .unwrap());
     
     // This should timeout
     let result = ListAccountsRequest::new()
@@ -83,12 +76,248 @@ async fn test_network_timeout_handling() {
 
 #[tokio::test]
 async fn test_malformed_host() {
-    let mut client = create_test_client();
-    client.host = "nonexistent.domain.invalid".to_string();
+    let client = Client::new("nonexistent.domain.invalid", create_test_client().authentication());
     
     let result = ListAccountsRequest::new()
         .remote(&client)
         .await;
     
     assert!(result.is_err(), "Request to invalid host should fail");
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_success_status_with_error_message_is_api_error() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/summary")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorCode": "ACCOUNT_LOCKED", "errorMessage": "The Account is locked"}"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = GetAccountSummaryRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::ApiError { status_code, error_code, error_message, .. }) => {
+            assert_eq!(status_code, 200);
+            assert_eq!(error_code, "ACCOUNT_LOCKED");
+            assert_eq!(error_message, "The Account is locked");
+        }
+        other => panic!("Expected ApiError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_api_error_carries_request_id() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/summary")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_header("RequestID", "24712853178632736")
+        .with_body(r#"{"errorCode": "NO_SUCH_ACCOUNT", "errorMessage": "The Account specified does not exist"}"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = GetAccountSummaryRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    let error = result.expect_err("404 should be an error");
+    match &error {
+        FxError::ApiError { status_code, request_id, .. } => {
+            assert_eq!(*status_code, 404);
+            assert_eq!(request_id.as_deref(), Some("24712853178632736"));
+        }
+        other => panic!("Expected ApiError, got {:?}", other),
+    }
+    assert!(error.to_string().contains("RequestID 24712853178632736"), "{}", error);
+}
+
+#[tokio::test]
+async fn test_too_many_requests_is_rate_limited() {
+    use std::time::Duration;
+
+    let mut server = mockito::Server::new_async().await;
+    let candles = server.mock("GET", "/v3/instruments/EUR_USD/candles")
+        .match_query(mockito::Matcher::Any)
+        .with_status(429)
+        .with_header("Retry-After", "2")
+        .with_body(r#"{"errorMessage": "Too many requests"}"#)
+        .create_async().await;
+    // An HTTP date an hour ahead; allow for the time the test takes to run
+    let retry_at = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc2822().replace("+0000", "GMT");
+    let orders = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(429)
+        .with_header("Retry-After", &retry_at)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD".to_string())
+        .with_count(5)
+        .remote(&client)
+        .await;
+    candles.assert_async().await;
+    match result {
+        Err(FxError::RateLimited { retry_after }) => assert_eq!(retry_after, Some(Duration::from_secs(2))),
+        other => panic!("Expected RateLimited, got {:?}", other),
+    }
+
+    let result = CreateMarketOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(MarketOrder::new().with_instrument("EUR_USD".to_string()).with_units(1.0))
+        .remote(&client)
+        .await;
+    orders.assert_async().await;
+    match result {
+        Err(FxError::RateLimited { retry_after: Some(retry_after) }) => {
+            assert!(retry_after > Duration::from_secs(3500) && retry_after <= Duration::from_secs(3600),
+                "Retry-After date should be about an hour away, got {:?}", retry_after);
+        }
+        other => panic!("Expected RateLimited with a delay, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_errors_expose_their_source() {
+    use std::error::Error;
+
+    // Nothing listens on a port just released by the OS
+    let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let client = Client::new(format!("http://127.0.0.1:{}", port), "mock-token");
+    let error = ListAccountsRequest::new().remote(&client).await.unwrap_err();
+    let source = error.source()
+        .and_then(|source| source.downcast_ref::<reqwest::Error>())
+        .unwrap_or_else(|| panic!("Expected a reqwest source, got {:?}", error));
+    assert!(source.is_connect(), "{:?}", source);
+
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"accounts": [{"id": 42}]}"#)
+        .create_async().await;
+    let error = ListAccountsRequest::new().remote(&create_mock_server_client(&server)).await.unwrap_err();
+    assert!(matches!(error, FxError::DeserializationError { .. }), "{:?}", error);
+    assert!(error.source().is_some_and(|source| source.is::<serde_json::Error>()), "{:?}", error);
+
+    let error = FxError::from(RequestValidationError::MissingAccountId);
+    assert!(error.source().is_some_and(|source| source.is::<RequestValidationError>()));
+}
+
+#[tokio::test]
+async fn test_error_retry_predicates() {
+    let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let client = Client::new(format!("http://127.0.0.1:{}", port), "mock-token");
+    let connect = ListAccountsRequest::new().remote(&client).await.unwrap_err();
+    assert!(connect.is_connect() && connect.is_retryable() && !connect.is_timeout(), "{:?}", connect);
+
+    assert!(FxError::Timeout(None).is_timeout() && FxError::Timeout(None).is_retryable());
+    assert!(FxError::RateLimited { retry_after: None }.is_retryable());
+
+    let api_error = |status_code| FxError::ApiError {
+        status_code,
+        error_code: "ERROR".to_string(),
+        error_message: "error".to_string(),
+        request_id: None,
+    };
+    assert!(api_error(503).is_retryable());
+    assert!(api_error(429).is_retryable());
+    assert!(!api_error(400).is_retryable());
+    assert!(!api_error(404).is_retryable());
+    assert!(!FxError::from(RequestValidationError::MissingAccountId).is_retryable());
+}
+
+#[test]
+fn test_oanda_error_response_parsing() {
+    let body: OandaErrorResponse = serde_json::from_str(r#"{
+        "errorCode": "INSUFFICIENT_MARGIN",
+        "errorMessage": "Insufficient margin",
+        "orderRejectTransaction": {"id": "6390", "type": "MARKET_ORDER_REJECT", "instrument": "EUR_USD",
+            "units": "5000000", "rejectReason": "INSUFFICIENT_MARGIN"},
+        "lastTransactionID": "6390"
+    }"#).unwrap();
+    assert_eq!(body.error_code.as_deref(), Some("INSUFFICIENT_MARGIN"));
+    assert_eq!(body.error_message.as_deref(), Some("Insufficient margin"));
+    let reject = body.order_reject_transaction.unwrap();
+    assert_eq!(reject.reject_reason.as_deref(), Some("INSUFFICIENT_MARGIN"));
+    assert_eq!(reject.units.as_deref(), Some("5000000"));
+    assert!(body.order_cancel_transaction.is_none() && body.order_fill_transaction.is_none());
+
+    let success: OandaErrorResponse = serde_json::from_str(r#"{"accounts": [], "lastTransactionID": "1"}"#).unwrap();
+    assert!(success.error_code.is_none() && success.error_message.is_none());
+}
+
+#[tokio::test]
+async fn test_deserialization_errors_name_the_field() {
+    let mut server = mockito::Server::new_async().await;
+    let _candles = server.mock("GET", "/v3/instruments/EUR_USD/candles")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"instrument": "EUR_USD", "granularity": "H1", "candles": [{"complete": true, "volume": "many"}]}"#)
+        .create_async().await;
+    let _stream = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body("{\"type\":\"PRICE\",\"instrument\":\"EUR_USD\",\"time\":\"2024-01-02T10:00:00Z\",\"tradeable\":\"yes\"}\n")
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let error = GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD")
+        .remote(&client)
+        .await
+        .unwrap_err();
+    match error {
+        FxError::DeserializationError { path, .. } => assert_eq!(path, "candles[0].volume"),
+        other => panic!("Expected a DeserializationError, got {:?}", other),
+    }
+
+    let items: Vec<_> = futures::StreamExt::collect(StreamPricingRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_instruments(vec!["EUR_USD".to_string()])
+        .stream(&client)).await;
+    match &items[..] {
+        [Err(FxError::DeserializationError { path, .. })] => assert_eq!(path, "tradeable"),
+        other => panic!("Expected a DeserializationError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_empty_success_body() {
+    let mut server = mockito::Server::new_async().await;
+    let _candles = server.mock("GET", "/v3/instruments/EUR_USD/candles")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body("  \n")
+        .create_async().await;
+    let _prices = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body("")
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let response = GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD")
+        .with_count(5)
+        .remote(&client)
+        .await
+        .expect("an empty body should read as an empty response");
+    assert!(response.candles.is_none());
+
+    // A pricing response must carry its time, so there's no empty one to return
+    let result = GetPricesRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_instruments("EUR_USD".to_string())
+        .remote(&client)
+        .await;
+    assert!(matches!(result, Err(FxError::EmptyResponse { status_code: 200 })), "{:?}", result);
+}
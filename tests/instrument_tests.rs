@@ -19,7 +19,7 @@ async fn test_get_candlestick_data_h4() {
     
     let response = result.unwrap();
     assert_eq!(response.instrument, Some("EUR_USD".to_string()));
-    // Note: granularity comparison removed due to PartialEq not implemented
+    assert_eq!(response.granularity, Some(CandlestickGranularity::H4));
     
     if let Some(candles) = response.candles {
         assert!(!candles.is_empty(), "Should have candlestick data");
@@ -38,16 +38,11 @@ async fn test_get_candlestick_data_h4() {
         assert!(has_mid || has_bid || has_ask, "Candle should have at least one price type");
         
         // If mid prices exist, validate OHLC structure
-        if let Some(mid_prices) = &first_candle.mid {
-            assert!(mid_prices.o.is_some(), "Should have open price");
-            assert!(mid_prices.h.is_some(), "Should have high price");
-            assert!(mid_prices.l.is_some(), "Should have low price");
-            assert!(mid_prices.c.is_some(), "Should have close price");
+        if first_candle.mid.is_some() {
+            let (open, _, _, _) = first_candle.mid_ohlc().expect("Should have open, high, low and close prices");
             
             // Validate price precision
-            if let Some(open) = mid_prices.o {
-                assert_price_precision(open.into(), "EUR_USD");
-            }
+            assert_price_precision(open, "EUR_USD");
         }
     }
 }
@@ -72,7 +67,7 @@ async fn test_get_candlestick_data_different_timeframes() {
         assert!(result.is_ok(), "Failed to get candlestick data: {:?}", result);
         
         let response = result.unwrap();
-        // Note: granularity comparison removed due to PartialEq not implemented
+        assert_eq!(response.granularity, Some(granularity));
         
         if let Some(candles) = response.candles {
             assert!(!candles.is_empty(), "Should have candlestick data");
@@ -95,17 +90,6 @@ async fn test_get_candlestick_data_date_range() {
         .remote(&client)
         .await;
     
-    // API might return decode errors for date range requests, handle gracefully
-    if result.is_err() {
-        // Check if it's a JSON decode error (empty response)
-        let err_str = format!("{:?}", result.as_ref().err().unwrap());
-        if err_str.contains("Decode") || err_str.contains("expected value") {
-            // API returned empty response - this can happen with date ranges
-            println!("Date range request returned empty response, skipping test");
-            return;
-        }
-    }
-    
     assert!(result.is_ok(), "Failed to get date range candlestick data: {:?}", result);
     
     let response = result.unwrap();
@@ -262,23 +246,14 @@ async fn test_get_instrument_price() {
         assert!(price.time.is_some(), "Price should have timestamp");
         
         // Should have at least bid or ask prices
-        let has_bids = price.bids.is_some() && !price.bids.as_ref().unwrap().is_empty();
-        let has_asks = price.asks.is_some() && !price.asks.as_ref().unwrap().is_empty();
+        assert!(price.best_bid().is_some() || price.best_ask().is_some(), "Price should have bid or ask data");
         
-        assert!(has_bids || has_asks, "Price should have bid or ask data");
-        
-        if has_bids {
-            let bid = &price.bids.as_ref().unwrap()[0];
-            if let Some(bid_price) = bid.price {
-                assert_price_precision(bid_price.into(), "EUR_USD");
-            }
+        if let Some(bid_price) = price.best_bid() {
+            assert_price_precision(bid_price, "EUR_USD");
         }
         
-        if has_asks {
-            let ask = &price.asks.as_ref().unwrap()[0];
-            if let Some(ask_price) = ask.price {
-                assert_price_precision(ask_price.into(), "EUR_USD");
-            }
+        if let Some(ask_price) = price.best_ask() {
+            assert_price_precision(ask_price, "EUR_USD");
         }
     }
 }
@@ -335,18 +310,14 @@ async fn test_multiple_instrument_pricing() {
             .remote(&client)
             .await;
         
-        // Handle potential decode errors gracefully
-        if result.is_err() {
-            let err_str = format!("{:?}", result.as_ref().err().unwrap());
-            if err_str.contains("Decode") || err_str.contains("expected value") {
-                println!("Candle request for {} returned empty response, skipping", instrument);
-                continue;
-            }
-        }
-        
         assert!(result.is_ok(), "Failed to get candles for {}: {:?}", instrument, result);
         
         let response = result.unwrap();
+        // An empty body comes back as a response with nothing set
+        if response.instrument.is_none() && response.candles.is_none() {
+            println!("Candle request for {} returned empty response, skipping", instrument);
+            continue;
+        }
         assert_eq!(response.instrument, Some(instrument.to_string()));
         
         if let Some(candles) = response.candles {
@@ -354,10 +325,8 @@ async fn test_multiple_instrument_pricing() {
             
             // Validate price precision for this specific instrument
             if let Some(first_candle) = candles.first() {
-                if let Some(mid_prices) = &first_candle.mid {
-                    if let Some(open) = mid_prices.o {
-                        assert_price_precision(open.into(), instrument);
-                    }
+                if let Some((open, _, _, _)) = first_candle.mid_ohlc() {
+                    assert_price_precision(open, instrument);
                 }
             }
         }
@@ -424,4 +393,464 @@ async fn test_large_dataset_handling() {
         // Should not exceed requested count
         assert!(candles.len() <= 100, "Should not exceed requested count");
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_granularity_seconds() {
+    assert_eq!(CandlestickGranularity::S5.seconds(), Some(5));
+    assert_eq!(CandlestickGranularity::M1.seconds(), Some(60));
+    assert_eq!(CandlestickGranularity::H1.seconds(), Some(3600));
+    assert_eq!(CandlestickGranularity::D.seconds(), Some(86400));
+
+    // Weeks and months have no fixed length
+    assert_eq!(CandlestickGranularity::W.seconds(), None);
+    assert_eq!(CandlestickGranularity::M.seconds(), None);
+
+    assert_eq!(CandlestickGranularity::H4.duration(), Some(Duration::hours(4)));
+}
+
+#[test]
+fn test_granularity_string_round_trip() {
+    let all = [
+        "S5", "S10", "S15", "S30", "M1", "M2", "M4", "M5", "M10", "M15", "M30",
+        "H1", "H2", "H3", "H4", "H6", "H8", "H12", "D", "W", "M",
+    ];
+    for wire in all {
+        let granularity: CandlestickGranularity = wire.parse().expect("OANDA granularity should parse");
+        assert_eq!(granularity.to_string(), wire);
+        assert_eq!(serde_json::to_string(&granularity).unwrap(), format!("\"{}\"", wire));
+    }
+
+    let error = "h4".parse::<CandlestickGranularity>().unwrap_err();
+    assert_eq!(error, ParseGranularityError("h4".to_string()));
+    assert!(error.to_string().contains("'h4'"));
+}
+
+#[test]
+fn test_granularity_as_map_key() {
+    use std::collections::HashMap;
+
+    let mut candle_counts = HashMap::new();
+    for granularity in [CandlestickGranularity::M5, CandlestickGranularity::H1, CandlestickGranularity::M5] {
+        *candle_counts.entry(granularity).or_insert(0) += 1;
+    }
+    assert_eq!(candle_counts[&CandlestickGranularity::M5], 2);
+    assert_eq!(candle_counts[&CandlestickGranularity::H1], 1);
+    assert_ne!(CandlestickGranularity::M, CandlestickGranularity::M1);
+}
+
+#[tokio::test]
+async fn test_account_candles_alignment_params() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/instruments/EUR_USD/candles")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("granularity".to_string(), "D".to_string()),
+            mockito::Matcher::UrlEncoded("dailyAlignment".to_string(), "17".to_string()),
+            mockito::Matcher::UrlEncoded("alignmentTimezone".to_string(), "America/New_York".to_string()),
+            mockito::Matcher::UrlEncoded("weeklyAlignment".to_string(), "Friday".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"instrument": "EUR_USD", "granularity": "D", "candles": []}"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = GetAccountInstrumentCandlesRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_instrument("EUR_USD".to_string())
+        .with_granularity(CandlestickGranularity::D)
+        .with_daily_alignment(17)
+        .with_alignment_timezone("America/New_York".to_string())
+        .with_weekly_alignment("Friday".to_string())
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok(), "Account candles request failed: {:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_account_candles_requires_account_id() {
+    let client = create_mock_client();
+
+    let result = GetAccountInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD".to_string())
+        .remote(&client)
+        .await;
+
+    assert!(matches!(result, Err(FxError::Validation(RequestValidationError::MissingAccountId))));
+}
+
+#[test]
+fn test_split_price_components() {
+    let candles: Vec<Candlestick> = serde_json::from_str(r#"[
+        {"time":"2024-01-02T10:00:00.000000000Z","complete":true,"volume":10,
+         "mid":{"o":"1.10005","h":"1.10105","l":"1.09905","c":"1.10055"},
+         "bid":{"o":"1.10000","h":"1.10100","l":"1.09900","c":"1.10050"},
+         "ask":{"o":"1.10010","h":"1.10110","l":"1.09910","c":"1.10060"}},
+        {"time":"2024-01-02T11:00:00.000000000Z","complete":true,"volume":12,
+         "mid":{"o":"1.10055","h":"1.10205","l":"1.10005","c":"1.10155"},
+         "bid":{"o":"1.10050","h":"1.10200","l":"1.10000","c":"1.10150"},
+         "ask":{"o":"1.10060","h":"1.10210","l":"1.10010","c":"1.10160"}},
+        {"time":"2024-01-02T12:00:00.000000000Z","complete":false,"volume":3,
+         "mid":{"o":"1.10155","h":"1.10155","l":"1.10105","c":"1.10125"}}
+    ]"#).unwrap();
+
+    let series = split_price_components(&candles);
+    assert_eq!(series.mid.len(), 3);
+    assert_eq!(series.bid.len(), 2, "The last candle has no bid component");
+    assert_eq!(series.ask.len(), 2, "The last candle has no ask component");
+
+    for (bid, ask) in series.bid.iter().zip(&series.ask) {
+        assert_eq!(bid.0, ask.0, "Bid and ask series should be aligned by time");
+        assert!(ask.1.close > bid.1.close, "Ask should be above bid");
+    }
+    let (time, first_bid) = series.bid[0];
+    assert_eq!(time, candles[0].time.unwrap());
+    assert!((first_bid.open - 1.1).abs() < 1e-6);
+    assert!((first_bid.high - 1.101).abs() < 1e-6);
+    assert!((first_bid.low - 1.099).abs() < 1e-6);
+    assert!((first_bid.close - 1.1005).abs() < 1e-6);
+}
+
+#[test]
+fn test_candles_extracts_closes_for_indicators() {
+    let candles: Vec<Candlestick> = serde_json::from_str(r#"[
+        {"time":"2024-01-02T10:00:00.000000000Z","complete":true,"volume":10,
+         "mid":{"o":"1.10005","h":"1.10105","l":"1.09905","c":"1.10055"},
+         "bid":{"o":"1.10000","h":"1.10100","l":"1.09900","c":"1.10050"}},
+        {"time":"2024-01-02T11:00:00.000000000Z","complete":true,"volume":12,
+         "mid":{"o":"1.10055","h":"1.10205","l":"1.10005","c":"1.10155"}},
+        {"time":"2024-01-02T12:00:00.000000000Z","complete":false,
+         "mid":{"o":"1.10155","h":"1.10155","l":"1.10105","c":"1.10125"},
+         "bid":{"o":"1.10150","h":"1.10150","l":"1.10100","c":"1.10120"}}
+    ]"#).unwrap();
+
+    let mid = Candles::new(&candles, PriceComponent::Mid);
+    assert_eq!(mid.closes(), vec![1.10055, 1.10155, 1.10125]);
+    assert_eq!(mid.highs(), vec![1.10105, 1.10205, 1.10155]);
+
+    // Missing values stay in place as NaN so the arrays remain aligned
+    let bid_closes = Candles::new(&candles, PriceComponent::Bid).closes();
+    assert_eq!(bid_closes.len(), 3);
+    assert_eq!(bid_closes[0], 1.1005);
+    assert!(bid_closes[1].is_nan());
+    assert_eq!(bid_closes[2], 1.1012);
+
+    let volumes = mid.volumes();
+    assert_eq!(&volumes[..2], &[10.0, 12.0]);
+    assert!(volumes[2].is_nan());
+}
+
+#[test]
+fn test_candlestick_ohlc_accessors() {
+    let candle: Candlestick = serde_json::from_str(r#"{"time":"2024-01-02T10:00:00.000000000Z","complete":true,"volume":10,
+        "mid":{"o":"1.10005","h":"1.10105","l":"1.09905","c":"1.10055"},
+        "ask":{"o":"1.10010","h":"1.10110","l":"1.09910"}}"#).unwrap();
+    assert_eq!(candle.mid_ohlc(), Some((1.10005, 1.10105, 1.09905, 1.10055)));
+    assert_eq!(candle.bid_ohlc(), None, "No bid component");
+    assert_eq!(candle.ask_ohlc(), None, "Ask component without a close");
+    assert!(candle.is_complete());
+    assert!(!Candlestick::new().is_complete(), "A candle that doesn't say is still forming");
+}
+
+#[test]
+fn test_candle_aggregator_emits_candle_at_boundary() {
+    let price = |time: &str, bid: &str, ask: &str| -> ClientPrice {
+        serde_json::from_str(&format!(
+            r#"{{"type":"PRICE","instrument":"EUR_USD","time":"{}","bids":[{{"price":"{}","liquidity":1000000}}],"asks":[{{"price":"{}","liquidity":1000000}}]}}"#,
+            time, bid, ask
+        )).unwrap()
+    };
+    let mut aggregator = CandleAggregator::new(CandlestickGranularity::M1).unwrap();
+
+    assert!(aggregator.push(&price("2024-01-02T10:00:05Z", "1.10000", "1.10020")).is_none());
+    assert!(aggregator.push(&price("2024-01-02T10:00:20Z", "1.10040", "1.10060")).is_none());
+    assert!(aggregator.push(&price("2024-01-02T10:00:40Z", "1.09980", "1.10000")).is_none());
+    assert!(aggregator.push(&price("2024-01-02T10:00:59Z", "1.10020", "1.10040")).is_none());
+
+    let candle = aggregator.push(&price("2024-01-02T10:01:02Z", "1.10100", "1.10120"))
+        .expect("the first price of the next minute completes the candle");
+    assert_eq!(candle.time, Some("2024-01-02T10:00:00Z".parse().unwrap()));
+    assert!(candle.is_complete());
+    assert_eq!(candle.volume, Some(4));
+    let (open, high, low, close) = candle.mid_ohlc().unwrap();
+    assert!((open - 1.1001).abs() < 1e-6);
+    assert!((high - 1.1005).abs() < 1e-6);
+    assert!((low - 1.0999).abs() < 1e-6);
+    assert!((close - 1.1003).abs() < 1e-6);
+
+    let partial = aggregator.flush().expect("the 10:01 candle is still open");
+    assert_eq!(partial.time, Some("2024-01-02T10:01:00Z".parse().unwrap()));
+    assert!(!partial.is_complete());
+    assert!(aggregator.flush().is_none());
+    assert!(CandleAggregator::new(CandlestickGranularity::W).is_none());
+}
+
+#[tokio::test]
+async fn test_remote_paged_splits_range_and_dedups_boundaries() {
+    use chrono::TimeZone;
+
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let minute = |n: i64| from + Duration::minutes(n);
+    let candles_json = |minutes: &[i64]| {
+        let candles: Vec<String> = minutes.iter()
+            .map(|&n| format!(r#"{{"time":"{}","complete":true,"volume":1,"mid":{{"o":"1.1","h":"1.1","l":"1.1","c":"1.1"}}}}"#, minute(n).to_rfc3339()))
+            .collect();
+        format!(r#"{{"instrument":"EUR_USD","granularity":"M1","candles":[{}]}}"#, candles.join(","))
+    };
+    let window_mock = |server: &mut mockito::ServerGuard, start: i64, end: i64, body: String| {
+        server.mock("GET", "/v3/instruments/EUR_USD/candles")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("from".to_string(), minute(start).to_rfc3339()),
+                mockito::Matcher::UrlEncoded("to".to_string(), minute(end).to_rfc3339()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .expect(1)
+    };
+
+    // 6000 minutes of M1 candles need two windows of at most 5000 candles
+    let mut server = mockito::Server::new_async().await;
+    let first = window_mock(&mut server, 0, 4999, candles_json(&[4998, 4997, 4999])).create_async().await;
+    let second = window_mock(&mut server, 4999, 6000, candles_json(&[4999, 5000])).create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let candles = GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD".to_string())
+        .with_granularity(CandlestickGranularity::M1)
+        .with_from(minute(0))
+        .with_to(minute(6000))
+        .remote_paged(&client)
+        .await
+        .expect("paged candles request should succeed");
+
+    first.assert_async().await;
+    second.assert_async().await;
+    let times: Vec<_> = candles.iter().map(|candle| candle.time.unwrap()).collect();
+    assert_eq!(times, vec![minute(4997), minute(4998), minute(4999), minute(5000)]);
+}
+
+#[test]
+fn test_instrument_tradeable_hours_and_financing_days() {
+    use chrono::TimeZone;
+
+    let json = r#"{
+        "name": "EUR_USD",
+        "type": "CURRENCY",
+        "financing": {
+            "longRate": "-0.0345",
+            "shortRate": "0.0112",
+            "financingDaysOfWeek": [
+                {"dayOfWeek": "MONDAY", "daysCharged": 1},
+                {"dayOfWeek": "WEDNESDAY", "daysCharged": 3},
+                {"dayOfWeek": "SATURDAY", "daysCharged": 0}
+            ]
+        }
+    }"#;
+    let instrument: Instrument = serde_json::from_str(json).expect("instrument with financing should deserialize");
+
+    // Saturday is outside the trading week, midweek is inside it
+    assert!(!instrument.is_tradeable_at(Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap()));
+    assert!(instrument.is_tradeable_at(Utc.with_ymd_and_hms(2024, 6, 12, 12, 0, 0).unwrap()));
+    assert!(!instrument.is_tradeable_at(Utc.with_ymd_and_hms(2024, 6, 14, 21, 30, 0).unwrap()));
+    assert!(instrument.is_tradeable_at(Utc.with_ymd_and_hms(2024, 6, 16, 22, 0, 0).unwrap()));
+
+    assert_eq!(
+        instrument.financing_days(),
+        vec![("MONDAY".to_string(), 1), ("WEDNESDAY".to_string(), 3)]
+    );
+}
+
+#[tokio::test]
+async fn test_candles_unix_datetime_format() {
+    use chrono::TimeZone;
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/instruments/EUR_USD/candles")
+        .match_header("Accept-Datetime-Format", "UNIX")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("from".to_string(), "1516240000.000000000".to_string()),
+            mockito::Matcher::UrlEncoded("to".to_string(), "1516243600.500000000".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"instrument":"EUR_USD","granularity":"M1","candles":[
+            {"time":"1516240000.000000000","complete":true,"volume":4,
+             "mid":{"o":"1.22005","h":"1.22105","l":"1.21905","c":"1.22055"}}
+        ]}"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let response = GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD".to_string())
+        .with_granularity(CandlestickGranularity::M1)
+        .with_accept_datetime_format("UNIX".to_string())
+        .with_from(Utc.timestamp_opt(1516240000, 0).unwrap())
+        .with_to(Utc.timestamp_opt(1516243600, 500_000_000).unwrap())
+        .remote(&client)
+        .await
+        .expect("UNIX-format candles request should succeed");
+
+    mock.assert_async().await;
+    let candles = response.candles.unwrap();
+    assert_eq!(candles[0].time, Utc.timestamp_opt(1516240000, 0).single());
+}
+
+#[test]
+fn test_instrument_name_parsing_and_builders() {
+    let name = InstrumentName::parse("USD_JPY").expect("well-formed instrument should parse");
+    assert_eq!(name.base(), "USD");
+    assert_eq!(name.quote(), "JPY");
+    assert_eq!(name.to_string(), "USD_JPY");
+    assert_eq!(InstrumentName::new("XAU", "USD"), "XAU_USD".parse().unwrap());
+
+    for bad in ["EURUSD", "EUR_", "_USD", "EUR_USD_X", ""] {
+        assert_eq!(InstrumentName::parse(bad), Err(RequestValidationError::InvalidInstrument(bad.to_string())), "{:?} should be rejected", bad);
+    }
+
+    assert_eq!(serde_json::to_string(&name).unwrap(), r#""USD_JPY""#);
+    assert_eq!(serde_json::from_str::<InstrumentName>(r#""EUR_USD""#).unwrap().base(), "EUR");
+    assert!(serde_json::from_str::<InstrumentName>(r#""EURUSD""#).is_err(), "Deserializing should validate too");
+
+    // Request builders take either form
+    let _ = GetInstrumentCandlesRequest::new().with_instrument(name.clone());
+    let _ = GetInstrumentCandlesRequest::new().with_instrument("EUR_USD");
+    let _ = GetPositionRequest::new().with_instrument(name);
+}
+
+#[tokio::test]
+async fn test_cloned_candles_request_varies_instrument() {
+    let mut server = mockito::Server::new_async().await;
+    let mut mocks = Vec::new();
+    for instrument in ["EUR_USD", "USD_JPY"] {
+        mocks.push(server.mock("GET", format!("/v3/instruments/{}/candles", instrument).as_str())
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("granularity".to_string(), "H1".to_string()),
+                mockito::Matcher::UrlEncoded("count".to_string(), "5".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(r#"{{"instrument": "{}", "granularity": "H1", "candles": []}}"#, instrument))
+            .create_async()
+            .await);
+    }
+    let client = create_mock_server_client(&server);
+
+    let base = GetInstrumentCandlesRequest::new()
+        .with_granularity(CandlestickGranularity::H1)
+        .with_count(5);
+    for instrument in ["EUR_USD", "USD_JPY"] {
+        let response = base.clone()
+            .with_instrument(instrument)
+            .remote(&client)
+            .await
+            .expect("each clone should carry the base settings");
+        assert_eq!(response.instrument.as_deref(), Some(instrument));
+    }
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_order_book_remote_with_headers_returns_links() {
+    let mut server = mockito::Server::new_async().await;
+    let prev = "https://api-fxpractice.oanda.com/v3/instruments/EUR_USD/orderBook?time=2024-01-02T09%3A40%3A00Z";
+    let next = "https://api-fxpractice.oanda.com/v3/instruments/EUR_USD/orderBook?time=2024-01-02T10%3A20%3A00Z";
+    let _mock = server.mock("GET", "/v3/instruments/EUR_USD/orderBook")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("Link", &format!(r#"<{}>; rel="prev", <{}>; rel="next""#, prev, next))
+        .with_body(r#"{"orderBook": {"instrument": "EUR_USD", "time": "2024-01-02T10:00:00Z", "buckets": []}}"#)
+        .create_async()
+        .await;
+    let _latest = server.mock("GET", "/v3/instruments/EUR_USD/positionBook")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("Link", &format!(r#"<{}>; rel="prev""#, prev))
+        .with_body(r#"{"positionBook": {"instrument": "EUR_USD", "time": "2024-01-02T10:00:00Z", "buckets": []}}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let (response, links) = GetOrderBookRequest::new()
+        .with_instrument("EUR_USD")
+        .remote_with_headers(&client)
+        .await
+        .unwrap();
+    assert_eq!(response.order_book.unwrap().instrument.as_deref(), Some("EUR_USD"));
+    assert_eq!(links.prev.as_deref(), Some(prev));
+    assert_eq!(links.next.as_deref(), Some(next));
+
+    // The latest snapshot has nothing after it
+    let (_, links) = GetPositionBookRequest::new()
+        .with_instrument("EUR_USD")
+        .remote_with_headers(&client)
+        .await
+        .unwrap();
+    assert_eq!(links, BookLinks { prev: Some(prev.to_string()), next: None });
+}
+
+#[tokio::test]
+async fn test_order_book_follows_prev_link() {
+    use chrono::{TimeZone, Utc};
+
+    let prev = "https://api-fxpractice.oanda.com/v3/instruments/EUR_USD/orderBook?time=2024-01-02T09%3A40%3A00Z";
+    let request = GetOrderBookRequest::from_link(prev).expect("a prev link should give a request");
+    assert!(GetOrderBookRequest::from_link("https://api-fxpractice.oanda.com/v3/instruments/EUR_USD/orderBook").is_none());
+    assert!(GetPositionBookRequest::from_link(prev).is_none(), "An order book link isn't a position book link");
+    assert!(GetPositionBookRequest::from_link(
+        "https://api-fxpractice.oanda.com/v3/instruments/EUR_USD/positionBook?time=1704188400.000000000").is_some());
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/instruments/EUR_USD/orderBook")
+        .match_query(mockito::Matcher::UrlEncoded("time".to_string(), "2024-01-02T09:40:00+00:00".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"orderBook": {"instrument": "EUR_USD", "time": "2024-01-02T09:40:00Z", "buckets": []}}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let response = request.remote(&client).await.unwrap();
+    assert_eq!(response.order_book.unwrap().time, Some(Utc.with_ymd_and_hms(2024, 1, 2, 9, 40, 0).unwrap()));
+
+    GetOrderBookRequest::new()
+        .with_instrument("EUR_USD")
+        .with_snapshot_before(Utc.with_ymd_and_hms(2024, 1, 2, 9, 40, 0).unwrap())
+        .remote(&client)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[cfg(feature = "decimal")]
+#[tokio::test]
+async fn test_get_candles_remote_decimal() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/instruments/EUR_USD/candles")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"instrument": "EUR_USD", "granularity": "M1", "candles": [
+            {"time": "2024-01-02T10:00:00.000000000Z", "complete": true, "volume": 5,
+             "mid": {"o": "1.10000", "h": "1.10020", "l": "1.09990", "c": "1.10010"}}
+        ]}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let candles = GetInstrumentCandlesRequest::new()
+        .with_instrument("EUR_USD")
+        .with_granularity(CandlestickGranularity::M1)
+        .remote_decimal(&client)
+        .await
+        .expect("candles should parse into decimals");
+
+    let (open, _, _, _) = candles[0].mid_ohlc().unwrap();
+    assert_eq!(open.to_string(), "1.10000");
+}
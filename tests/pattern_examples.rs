@@ -88,7 +88,7 @@ async fn test_integration_create_and_cancel_limit_order() {
 /// Test market order execution handling both market open and closed scenarios
 /// 
 /// When markets are OPEN: Market orders fill immediately and create positions
-/// When markets are CLOSED: Market orders are created but remain pending until market opens
+/// When markets are CLOSED: OANDA cancels the order and it surfaces as `FxError::MarketClosed`
 #[tokio::test]
 async fn test_integration_market_order_execution() {
     let ctx = TestContext::new().await;
@@ -109,8 +109,20 @@ async fn test_integration_market_order_execution() {
         .with_order(market_order)
         .remote(&ctx.client).await;
     
-    assert!(order_result.is_ok(), "Failed to create market order: {:?}", order_result);
-    let order_response = order_result.unwrap();
+    let order_response = match order_result {
+        Err(FxError::MarketClosed { instrument }) => {
+            println!("Market is closed: OANDA halted the order for {}", instrument);
+            
+            // Verify no position change occurred
+            let new_position = get_position_for_instrument(&ctx, "EUR_USD").await;
+            verify_position_change(&initial_position, &new_position, 0);
+            return;
+        }
+        other => {
+            assert!(other.is_ok(), "Failed to create market order: {:?}", other);
+            other.unwrap()
+        }
+    };
     
     // Verify OUR specific order was created
     assert!(order_response.order_create_transaction.is_some());
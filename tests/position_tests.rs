@@ -369,4 +369,190 @@ async fn test_position_modification_workflow() {
     }
     
     println!("Position modification workflow structure validated successfully");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_currency_exposure_aggregation() {
+    let positions = vec![
+        // Long 10,000 EUR_USD at 1.10: +10,000 EUR, -11,000 USD
+        Position::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_long(PositionSide::new().with_units(10000.0).with_average_price(1.10)),
+        // Short 5,000 USD_JPY at 150.0: -5,000 USD, +750,000 JPY
+        Position::new()
+            .with_instrument("USD_JPY".to_string())
+            .with_short(PositionSide::new().with_units(-5000.0).with_average_price(150.0)),
+    ];
+
+    let exposure = currency_exposure(&positions);
+
+    assert_eq!(exposure.len(), 3);
+    assert!((exposure["EUR"] - 10000.0).abs() < 0.01, "EUR exposure was {}", exposure["EUR"]);
+    assert!((exposure["USD"] - (-16000.0)).abs() < 0.01, "USD exposure was {}", exposure["USD"]);
+    assert!((exposure["JPY"] - 750000.0).abs() < 0.01, "JPY exposure was {}", exposure["JPY"]);
+}
+
+#[tokio::test]
+async fn test_position_monitor_follows_fills() {
+    let lines = [
+        r#"{"id":"10","type":"ORDER_FILL","instrument":"EUR_USD","units":"1000","price":"1.10000","time":"2024-01-02T10:00:00.000000000Z","tradeOpened":{"tradeID":"10","units":"1000","price":"1.10000"}}"#,
+        r#"{"id":"12","type":"ORDER_FILL","instrument":"USD_JPY","units":"-500","price":"150.000","time":"2024-01-02T10:01:00.000000000Z","tradeOpened":{"tradeID":"12","units":"-500","price":"150.000"}}"#,
+        r#"{"type":"HEARTBEAT","lastTransactionID":"12","time":"2024-01-02T10:01:05.000000000Z"}"#,
+        r#"{"id":"14","type":"ORDER_FILL","instrument":"EUR_USD","units":"-400","price":"1.10100","time":"2024-01-02T10:02:00.000000000Z","tradeReduced":{"tradeID":"10","units":"-400","price":"1.10100"}}"#,
+        r#"{"id":"15","type":"DAILY_FINANCING","time":"2024-01-02T10:02:30.000000000Z"}"#,
+        r#"{"id":"16","type":"ORDER_FILL","instrument":"USD_JPY","units":"500","price":"149.900","time":"2024-01-02T10:03:00.000000000Z","tradesClosed":[{"tradeID":"12","units":"500","price":"149.900"}]}"#,
+        r#"{"id":"18","type":"ORDER_FILL","instrument":"EUR_USD","units":"200","price":"1.10200","time":"2024-01-02T10:04:00.000000000Z","tradeOpened":{"tradeID":"18","units":"200","price":"1.10200"}}"#,
+        // A replay of an already applied fill must not reopen the closed trade
+        r#"{"id":"12","type":"ORDER_FILL","instrument":"USD_JPY","units":"-500","price":"150.000","time":"2024-01-02T10:01:00.000000000Z","tradeOpened":{"tradeID":"12","units":"-500","price":"150.000"}}"#,
+    ];
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/transactions/stream")
+        .with_status(200)
+        .with_body(lines.join("\n") + "\n")
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let monitor = PositionMonitor::new();
+    monitor.follow(&client, "101-004-1234567-001").await.expect("stream should be followed to its end");
+
+    let trades = monitor.trades();
+    assert_eq!(trades.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), vec!["10", "18"]);
+    assert_eq!(trades[0].units, 600.0, "Trade 10 should be reduced by 400 units");
+    assert_eq!(trades[0].price, 1.1);
+    assert_eq!(trades[1].instrument, "EUR_USD");
+
+    let positions = monitor.positions();
+    assert_eq!(positions.len(), 1, "The USD_JPY position was closed: {:?}", positions);
+    assert_eq!(positions["EUR_USD"], 800.0);
+}
+
+#[test]
+fn test_position_monitor_skips_transactions_in_snapshot() {
+    let snapshot = AccountSnapshot {
+        summary: AccountSummary::new().with_last_transaction_id("20".to_string()),
+        trades: vec![Trade::new()
+            .with_id("20".to_string())
+            .with_instrument("GBP_USD".to_string())
+            .with_current_units(-300.0)
+            .with_price(1.27)],
+        positions: Vec::new(),
+        pending_orders: Vec::new(),
+    };
+    let monitor = PositionMonitor::from_snapshot(&snapshot);
+
+    // Trade 20's opening fill is already in the snapshot; 21 is new and closes it
+    monitor.apply(&serde_json::json!({"id": "20", "type": "ORDER_FILL", "instrument": "GBP_USD",
+        "tradeOpened": {"tradeID": "20", "units": "-300", "price": "1.27000"}})).unwrap();
+    assert_eq!(monitor.positions()["GBP_USD"], -300.0);
+
+    monitor.apply(&serde_json::json!({"id": "21", "type": "ORDER_FILL", "instrument": "GBP_USD",
+        "tradesClosed": [{"tradeID": "20", "units": "300"}]})).unwrap();
+    assert!(monitor.trades().is_empty());
+    assert!(monitor.positions().is_empty());
+}
+
+#[tokio::test]
+async fn test_flatten_position_closes_only_open_sides() {
+    let mut server = mockito::Server::new_async().await;
+    let _position = server.mock("GET", "/v3/accounts/101-004-1234567-001/positions/EUR_USD")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"position": {"instrument": "EUR_USD",
+            "long": {"units": "1500", "averagePrice": "1.10000"},
+            "short": {"units": "0"}}, "lastTransactionID": "9"}"#)
+        .create_async()
+        .await;
+    let close = server.mock("PUT", "/v3/accounts/101-004-1234567-001/positions/EUR_USD/close")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"longUnits": "ALL"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"longOrderCreateTransaction": {"id": "10", "type": "MARKET_ORDER", "instrument": "EUR_USD", "units": "-1500"},
+            "relatedTransactionIDs": ["10", "11"], "lastTransactionID": "11"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let response = client.flatten_position("101-004-1234567-001", "EUR_USD").await
+        .expect("flattening an open long should succeed")
+        .expect("an open position should be closed");
+
+    close.assert_async().await;
+    assert_eq!(response.long_order_create_transaction.and_then(|t| t.id).as_deref(), Some("10"));
+}
+
+#[tokio::test]
+async fn test_flatten_position_without_position_is_noop() {
+    let mut server = mockito::Server::new_async().await;
+    let _flat = server.mock("GET", "/v3/accounts/101-004-1234567-001/positions/EUR_USD")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"position": {"instrument": "EUR_USD", "long": {"units": "0"}, "short": {"units": "0"}}, "lastTransactionID": "9"}"#)
+        .create_async()
+        .await;
+    let _missing = server.mock("GET", "/v3/accounts/101-004-1234567-001/positions/XAU_USD")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorCode": "NO_SUCH_POSITION", "errorMessage": "The requested position does not exist"}"#)
+        .create_async()
+        .await;
+    let close = server.mock("PUT", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    assert!(client.flatten_position("101-004-1234567-001", "EUR_USD").await.unwrap().is_none());
+    assert!(client.flatten_position("101-004-1234567-001", "XAU_USD").await.unwrap().is_none());
+    close.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_close_all_positions_continues_past_failures() {
+    let mut server = mockito::Server::new_async().await;
+    let _positions = server.mock("GET", "/v3/accounts/101-004-1234567-001/openPositions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"positions": [
+            {"instrument": "EUR_USD", "long": {"units": "1000"}, "short": {"units": "0"}},
+            {"instrument": "GBP_USD", "long": {"units": "200"}, "short": {"units": "-300"}},
+            {"instrument": "USD_JPY", "long": {"units": "0"}, "short": {"units": "-500"}}
+        ], "lastTransactionID": "20"}"#)
+        .create_async()
+        .await;
+    let eur = server.mock("PUT", "/v3/accounts/101-004-1234567-001/positions/EUR_USD/close")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"longUnits": "ALL"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"relatedTransactionIDs": ["21"], "lastTransactionID": "21"}"#)
+        .create_async()
+        .await;
+    let gbp = server.mock("PUT", "/v3/accounts/101-004-1234567-001/positions/GBP_USD/close")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"longUnits": "ALL", "shortUnits": "ALL"})))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorCode": "MARKET_HALTED", "errorMessage": "The market is halted"}"#)
+        .create_async()
+        .await;
+    let jpy = server.mock("PUT", "/v3/accounts/101-004-1234567-001/positions/USD_JPY/close")
+        .match_body(mockito::Matcher::Json(serde_json::json!({"shortUnits": "ALL"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"relatedTransactionIDs": ["22"], "lastTransactionID": "22"}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let results = client.close_all_positions("101-004-1234567-001").await
+        .expect("listing the open positions should succeed");
+
+    eur.assert_async().await;
+    gbp.assert_async().await;
+    jpy.assert_async().await;
+    let instruments: Vec<&str> = results.iter().map(|(instrument, _)| instrument.as_str()).collect();
+    assert_eq!(instruments, vec!["EUR_USD", "GBP_USD", "USD_JPY"]);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err(), "The halted market's close should fail on its own");
+    assert!(results[2].1.is_ok(), "Closes after a failure should still be attempted");
+}
@@ -25,16 +25,10 @@ async fn test_get_current_prices() {
         assert!(price.time.is_some(), "Price should have timestamp");
         
         // Should have at least bid or ask prices
-        let has_bids = price.bids.is_some() && !price.bids.as_ref().unwrap().is_empty();
-        let has_asks = price.asks.is_some() && !price.asks.as_ref().unwrap().is_empty();
+        assert!(price.best_bid().is_some() || price.best_ask().is_some(), "Price should have bid or ask data");
         
-        assert!(has_bids || has_asks, "Price should have bid or ask data");
-        
-        if has_bids {
-            let bid = &price.bids.as_ref().unwrap()[0];
-            if let Some(bid_price) = bid.price {
-                assert_price_precision(bid_price.into(), "EUR_USD");
-            }
+        if let Some(bid_price) = price.best_bid() {
+            assert_price_precision(bid_price, "EUR_USD");
         }
     }
 }
@@ -55,13 +49,8 @@ async fn test_pricing_precision() {
     let response = result.unwrap();
     if let Some(prices) = &response.prices {
         if !prices.is_empty() {
-            let price = &prices[0];
-            if let Some(bids) = &price.bids {
-                if !bids.is_empty() {
-                    if let Some(bid_price) = bids[0].price {
-                        assert_price_precision(bid_price.into(), "USD_JPY");
-                    }
-                }
+            if let Some(bid_price) = prices[0].best_bid() {
+                assert_price_precision(bid_price, "USD_JPY");
             }
         }
     }
@@ -89,4 +78,431 @@ async fn test_pricing_error_handling() {
         .await;
     
     assert!(result.is_err(), "Request with invalid account ID should fail");
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_price_stream_ends_cleanly_on_close() {
+    use futures::StreamExt;
+
+    let body = concat!(
+        r#"{"type":"PRICE","instrument":"EUR_USD","time":"2024-01-02T10:00:00.000000000Z","bids":[{"price":"1.10000","liquidity":1000000}],"asks":[{"price":"1.10010","liquidity":1000000}],"tradeable":true}"#, "\n",
+        r#"{"type":"HEARTBEAT","time":"2024-01-02T10:00:05.000000000Z"}"#, "\n",
+    );
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::UrlEncoded("instruments".into(), "EUR_USD,USD_JPY".into()))
+        .with_status(200)
+        .with_body(body)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let items: Vec<_> = StreamPricingRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .with_instruments(vec!["EUR_USD".to_string(), "USD_JPY".to_string()])
+        .stream(&client)
+        .collect()
+        .await;
+
+    // The server closing the body ends the stream without an error item
+    assert_eq!(items.len(), 2, "Expected one price and one heartbeat, got {:?}", items);
+    let price = items[0].as_ref().expect("price line should parse");
+    assert_eq!(price.price.as_ref().and_then(|p| p.instrument.as_deref()), Some("EUR_USD"));
+    assert!(price.heartbeat.is_none());
+    let heartbeat = items[1].as_ref().expect("heartbeat line should parse");
+    assert!(heartbeat.heartbeat.is_some() && heartbeat.price.is_none());
+}
+
+#[tokio::test]
+async fn test_price_stream_yields_error_response_once() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::Any)
+        .with_status(401)
+        .with_body(r#"{"errorMessage":"Insufficient authorization to perform request."}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let items: Vec<_> = StreamPricingRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .stream(&client)
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 1);
+    assert!(matches!(items[0], Err(FxError::ApiError { status_code: 401, .. })));
+}
+
+#[tokio::test]
+async fn test_price_stream_items_split_across_chunks() {
+    use futures::StreamExt;
+
+    const HEARTBEAT: &str = r#"{"type":"HEARTBEAT","time":"2024-01-02T10:00:00.000000000Z"}"#;
+    const EUR_USD: &str = r#"{"type":"PRICE","instrument":"EUR_USD","time":"2024-01-02T10:00:01.000000000Z","bids":[{"price":"1.10000","liquidity":1000000}],"asks":[{"price":"1.10010","liquidity":1000000}]}"#;
+    const USD_JPY: &str = r#"{"type":"PRICE","instrument":"USD_JPY","time":"2024-01-02T10:00:01.000000000Z","bids":[{"price":"150.000","liquidity":1000000}],"asks":[{"price":"150.010","liquidity":1000000}]}"#;
+
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_chunked_body(|w| {
+            // A heartbeat and the first half of a price in one chunk...
+            let (head, tail) = EUR_USD.split_at(EUR_USD.len() / 2);
+            w.write_all(format!("{}\n{}", HEARTBEAT, head).as_bytes())?;
+            w.flush()?;
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            // ...then the rest of it, a second price and a heartbeat in the next
+            w.write_all(format!("{}\n{}\n{}\n", tail, USD_JPY, HEARTBEAT).as_bytes())
+        })
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let items: Vec<StreamPriceItem> = StreamPricingRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .items(&client)
+        .map(|item| item.expect("every line should parse"))
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 4, "Expected two prices and two heartbeats, got {:?}", items);
+    assert!(matches!(items[0], StreamPriceItem::Heartbeat(_)));
+    assert!(matches!(&items[1], StreamPriceItem::Price(p) if p.instrument.as_deref() == Some("EUR_USD")));
+    assert!(matches!(&items[2], StreamPriceItem::Price(p) if p.instrument.as_deref() == Some("USD_JPY")));
+    assert!(matches!(items[3], StreamPriceItem::Heartbeat(_)));
+}
+
+#[tokio::test]
+async fn test_stream_pricing_into_channel() {
+    const HEARTBEAT: &str = r#"{"type":"HEARTBEAT","time":"2024-01-02T10:00:00.000000000Z"}"#;
+    const EUR_USD: &str = r#"{"type":"PRICE","instrument":"EUR_USD","time":"2024-01-02T10:00:01.000000000Z","bids":[{"price":"1.10000","liquidity":1000000}],"asks":[{"price":"1.10010","liquidity":1000000}]}"#;
+
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body(format!("{}\n{}\n{}\n{}\n", HEARTBEAT, EUR_USD, EUR_USD, HEARTBEAT))
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    // A one-item buffer: the task can only read ahead as fast as items are received
+    let (handle, mut receiver) = StreamPricingRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .into_channel(&client, 1);
+
+    let mut items = Vec::new();
+    while let Some(item) = receiver.recv().await {
+        items.push(item.expect("every line should parse"));
+    }
+    handle.await.expect("the stream task should finish once the stream ends");
+
+    assert_eq!(items.len(), 4, "Expected two prices and two heartbeats, got {:?}", items);
+    assert!(matches!(items[0], StreamPriceItem::Heartbeat(_)));
+    assert!(matches!(&items[1], StreamPriceItem::Price(p) if p.instrument.as_deref() == Some("EUR_USD")));
+    assert!(matches!(items[3], StreamPriceItem::Heartbeat(_)));
+}
+
+#[tokio::test]
+async fn test_into_channel_stops_when_receiver_dropped() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_chunked_body(|w| {
+            // A heartbeat every 20ms for ten seconds, far longer than the test waits
+            for _ in 0..500 {
+                w.write_all(b"{\"type\":\"HEARTBEAT\",\"time\":\"2024-01-02T10:00:00.000000000Z\"}\n")?;
+                w.flush()?;
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            }
+            Ok(())
+        })
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let (handle, receiver) = StreamPricingRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .into_channel(&client, 1);
+    drop(receiver);
+
+    tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+        .await
+        .expect("the task should stop once nothing is receiving")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_prices_only_skips_heartbeats() {
+    use futures::StreamExt;
+
+    let items = vec![
+        Ok(StreamPriceItem::Heartbeat(PricingHeartbeat::new())),
+        Ok(StreamPriceItem::Price(ClientPrice::new().with_instrument("EUR_USD".to_string()))),
+        Err(FxError::Timeout(None)),
+        Ok(StreamPriceItem::Heartbeat(PricingHeartbeat::new())),
+        Ok(StreamPriceItem::Price(ClientPrice::new().with_instrument("USD_JPY".to_string()))),
+    ];
+
+    let prices: Vec<_> = prices_only(futures::stream::iter(items)).collect().await;
+
+    assert_eq!(prices.len(), 3, "Heartbeats should be dropped, errors kept");
+    assert_eq!(prices[0].as_ref().unwrap().instrument.as_deref(), Some("EUR_USD"));
+    assert!(matches!(prices[1], Err(FxError::Timeout(None))));
+    assert_eq!(prices[2].as_ref().unwrap().instrument.as_deref(), Some("USD_JPY"));
+}
+
+#[tokio::test]
+async fn test_per_request_timeout_overrides_client() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|_| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            br#"{"prices": []}"#.to_vec()
+        })
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let result = GetPricesRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_instruments("EUR_USD".to_string())
+        .with_timeout(std::time::Duration::from_millis(50))
+        .remote(&client)
+        .await;
+
+    assert!(matches!(result, Err(FxError::Timeout(Some(_)))), "{:?}", result);
+}
+
+#[test]
+fn test_client_price_spread_helpers() {
+    let price: ClientPrice = serde_json::from_str(r#"{"type":"PRICE","instrument":"EUR_USD",
+        "bids":[{"price":"1.10000","liquidity":1000000},{"price":"1.09990","liquidity":5000000}],
+        "asks":[{"price":"1.10020","liquidity":1000000},{"price":"1.10030","liquidity":5000000}]}"#).unwrap();
+    assert_eq!(price.best_bid(), Some(1.1));
+    assert_eq!(price.best_ask(), Some(1.1002));
+    assert!((price.spread().unwrap() - 0.0002).abs() < 1e-6);
+    assert!((price.mid().unwrap() - 1.1001).abs() < 1e-6);
+
+    // A side with no buckets gives None rather than panicking
+    let one_sided: ClientPrice = serde_json::from_str(r#"{"type":"PRICE","instrument":"EUR_USD",
+        "bids":[],"asks":[{"price":"1.10020","liquidity":1000000}]}"#).unwrap();
+    assert_eq!(one_sided.best_bid(), None);
+    assert_eq!(one_sided.best_ask(), Some(1.1002));
+    assert_eq!(one_sided.spread(), None);
+    assert_eq!(one_sided.mid(), None);
+}
+
+#[tokio::test]
+async fn test_prices_for_chunks_dedupes_and_keeps_order() {
+    let mut server = mockito::Server::new_async().await;
+    // Answers each request with a price for every instrument asked for, in reverse
+    let mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|request| {
+            let url = reqwest::Url::parse(&format!("http://localhost{}", request.path_and_query())).unwrap();
+            let instruments = url.query_pairs()
+                .find(|(key, _)| key == "instruments")
+                .map(|(_, value)| value.into_owned())
+                .unwrap();
+            let prices: Vec<serde_json::Value> = instruments.split(',').rev()
+                .map(|instrument| serde_json::json!({
+                    "type": "PRICE", "instrument": instrument, "time": "2024-01-02T10:00:00.000000000Z",
+                }))
+                .collect();
+            serde_json::json!({ "prices": prices, "time": "2024-01-02T10:00:00.000000000Z" }).to_string().into_bytes()
+        })
+        .expect(2)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let names: Vec<String> = (0..MAX_PRICING_INSTRUMENTS + 10).map(|i| format!("X{:03}_USD", i)).collect();
+    let mut instruments: Vec<&str> = names.iter().map(String::as_str).collect();
+    instruments.push("X001_USD");
+
+    let prices = client.prices_for("101-001-1234567-001", &instruments).await.unwrap();
+
+    mock.assert_async().await;
+    let returned: Vec<&str> = prices.iter().map(|p| p.instrument.as_deref().unwrap()).collect();
+    assert_eq!(returned, instruments[..names.len()], "One price per instrument, in the order asked for");
+}
+
+#[test]
+fn test_client_price_home_conversion() {
+    // EUR_GBP priced for a USD account: GBP amounts convert at the GBP_USD rate
+    let price: ClientPrice = serde_json::from_str(r#"{"type":"PRICE","instrument":"EUR_GBP",
+        "bids":[{"price":"0.85000","liquidity":1000000}],
+        "asks":[{"price":"0.85020","liquidity":1000000}],
+        "quoteHomeConversionFactors":{"positiveUnits":"1.25000","negativeUnits":"1.25100"}}"#).unwrap();
+
+    let bought = price.home_conversion(1000.0, true).unwrap();
+    assert!((bought - 1000.0 * 0.8502 * 1.25).abs() < 1e-9, "{}", bought);
+    let sold = price.home_conversion(1000.0, false).unwrap();
+    assert!((sold + 1000.0 * 0.85 * 1.251).abs() < 1e-9, "{}", sold);
+    assert_eq!(price.home_conversion(-1000.0, false), Some(sold), "The direction comes from is_long alone");
+
+    let without_factors = ClientPrice { quote_home_conversion_factors: None, ..price };
+    assert_eq!(without_factors.home_conversion(1000.0, true), None);
+}
+
+#[tokio::test]
+async fn test_reconnecting_stream_resumes_after_drop() {
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let mut server = mockito::Server::new_async().await;
+    let connections = AtomicUsize::new(0);
+    // Each connection sends one price and closes, as a dropped stream would
+    let _mock = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::UrlEncoded("instruments".to_string(), "EUR_USD,USD_JPY".to_string()))
+        .with_status(200)
+        .with_body_from_request(move |_| {
+            let n = connections.fetch_add(1, Ordering::SeqCst);
+            format!("{{\"type\":\"PRICE\",\"instrument\":\"EUR_USD\",\"time\":\"2024-01-02T10:00:0{}Z\",\"closeoutBid\":\"1.1000{}\"}}\n", n, n)
+                .into_bytes()
+        })
+        .create_async()
+        .await;
+    let _unauthorized = server.mock("GET", "/v3/accounts/101-004-7654321-001/pricing/stream")
+        .match_query(mockito::Matcher::Any)
+        .with_status(401)
+        .with_body(r#"{"errorMessage": "Insufficient authorization to perform request."}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let events: Vec<_> = client.pricing_stream_reconnecting("101-004-1234567-001", &["EUR_USD", "USD_JPY"])
+        .take(5)
+        .collect()
+        .await;
+    let closeouts: Vec<String> = events.iter().map(|event| match event {
+        Ok(StreamEvent::Price(price)) => format!("{}", price.closeout_bid.unwrap()),
+        Ok(StreamEvent::Reconnected) => "reconnected".to_string(),
+        other => panic!("Expected prices and reconnections, got {:?}", other),
+    }).collect();
+    assert_eq!(closeouts, ["1.1", "reconnected", "1.10001", "reconnected", "1.10002"]);
+
+    // A rejected token won't be fixed by reconnecting
+    let events: Vec<_> = client.pricing_stream_reconnecting("101-004-7654321-001", &["EUR_USD"])
+        .collect()
+        .await;
+    assert!(matches!(&events[..], [Err(FxError::ApiError { status_code: 401, .. })]), "{:?}", events);
+}
+
+#[tokio::test]
+async fn test_stream_pricing_snapshot_flag_in_query() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let without_snapshot = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("instruments".to_string(), "EUR_USD".to_string()),
+            mockito::Matcher::UrlEncoded("snapshot".to_string(), "false".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("{\"type\":\"HEARTBEAT\",\"time\":\"2024-01-02T10:00:00.000000000Z\"}\n")
+        .create_async()
+        .await;
+    // OANDA's default applies when the flag isn't set, so it isn't sent at all
+    let default = server.mock("GET", "/v3/accounts/101-004-1234567-001/pricing/stream")
+        .match_query(mockito::Matcher::Regex("^instruments=EUR_USD$".to_string()))
+        .with_status(200)
+        .with_body("{\"type\":\"HEARTBEAT\",\"time\":\"2024-01-02T10:00:00.000000000Z\"}\n")
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let request = StreamPricingRequest::new()
+        .with_account_id("101-004-1234567-001")
+        .with_instruments(vec!["EUR_USD".to_string()]);
+    let items: Vec<_> = request.clone().with_snapshot(false).items(&client).collect().await;
+    assert!(matches!(&items[..], [Ok(StreamPriceItem::Heartbeat(_))]), "{:?}", items);
+    let items: Vec<_> = request.items(&client).collect().await;
+    assert!(matches!(&items[..], [Ok(StreamPriceItem::Heartbeat(_))]), "{:?}", items);
+
+    without_snapshot.assert_async().await;
+    default.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_prices_include_home_conversions() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("instruments".to_string(), "EUR_GBP".to_string()),
+            mockito::Matcher::UrlEncoded("includeHomeConversions".to_string(), "true".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"prices": [], "time": "2024-01-02T10:00:00.000000000Z", "homeConversions": [
+            {"currency": "GBP", "accountGain": "1.25000", "accountLoss": "1.25100", "positionValue": "1.25050"},
+            {"currency": "USD", "accountGain": "1.0", "accountLoss": "1.0", "positionValue": "1.0"}]}"#)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let response = GetPricesRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_instruments("EUR_GBP".to_string())
+        .with_include_home_conversions(true)
+        .remote(&client)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    let conversions = response.home_conversions.expect("the conversions asked for");
+    assert_eq!(conversions[0].currency.as_deref(), Some("GBP"));
+    assert_eq!(conversions[0].account_gain, Some(1.25));
+    assert_eq!(conversions[0].position_value, Some(1.2505));
+}
+
+#[tokio::test]
+async fn test_get_prices_since_follows_datetime_format() {
+    use chrono::{TimeZone, Utc};
+
+    let since = Utc.with_ymd_and_hms(2018, 1, 18, 1, 46, 40).unwrap();
+    let mut server = mockito::Server::new_async().await;
+    let rfc3339 = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::UrlEncoded("since".to_string(), "2018-01-18T01:46:40+00:00".to_string()))
+        .match_header("Accept-Datetime-Format", "RFC3339")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"prices": [], "time": "2018-01-18T01:46:41Z"}"#)
+        .create_async()
+        .await;
+    let unix = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::UrlEncoded("since".to_string(), "1516240000.000000000".to_string()))
+        .match_header("Accept-Datetime-Format", "UNIX")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"prices": [], "time": "1516240001.000000000"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+    let request = GetPricesRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_instruments("EUR_USD".to_string())
+        .with_since(since);
+
+    let response = request.clone().remote(&client).await.unwrap();
+    assert_eq!(response.time, Some(since + chrono::Duration::seconds(1)));
+    let response = request.clone().with_accept_datetime_format("UNIX".to_string()).remote(&client).await.unwrap();
+    assert_eq!(response.time, Some(since + chrono::Duration::seconds(1)), "The next poll's since, in either format");
+    let unix_client = create_mock_server_client(&server).with_datetime_format(DatetimeFormat::Unix);
+    request.remote(&unix_client).await.unwrap();
+
+    rfc3339.assert_async().await;
+    unix.assert_async().await;
+}
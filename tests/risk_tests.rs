@@ -0,0 +1,97 @@
+mod common;
+
+use fxoanda::*;
+use common::*;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-6, "Expected {}, got {}", expected, actual);
+}
+
+#[test]
+fn test_pip_distance() {
+    // EUR_USD: pipLocation -4
+    assert_close(pip_distance(1.1050, 1.1075, -4), 25.0);
+    assert_close(pip_distance(1.1075, 1.1050, -4), -25.0);
+
+    // USD_JPY: pipLocation -2
+    assert_close(pip_distance(150.25, 150.75, -2), 50.0);
+    assert_close(pip_distance(150.75, 150.25, -2), -50.0);
+}
+
+#[test]
+fn test_breakeven_price() {
+    // 10,000 EUR_USD long at 1.1000 that has paid 2.00 USD of financing needs 2 pips.
+    let long = Trade::new()
+        .with_price(1.1)
+        .with_current_units(10000.0)
+        .with_financing(-2.0);
+    assert_close(breakeven_price(&long, 1.0).unwrap(), 1.1002);
+
+    // The same cost on a short pushes the breakeven below the entry.
+    let short = Trade::new()
+        .with_price(1.1)
+        .with_current_units(-10000.0)
+        .with_financing(-2.0);
+    assert_close(breakeven_price(&short, 1.0).unwrap(), 1.0998);
+
+    // Collected financing brings it in, and no financing leaves it at the entry.
+    let collected = Trade::new()
+        .with_price(1.1)
+        .with_current_units(10000.0)
+        .with_financing(2.0);
+    assert_close(breakeven_price(&collected, 1.0).unwrap(), 1.0998);
+    let unfinanced = Trade::new().with_price(1.1).with_current_units(10000.0);
+    assert_close(breakeven_price(&unfinanced, 1.0).unwrap(), 1.1);
+
+    assert!(breakeven_price(&Trade::new().with_price(1.1), 1.0).is_none());
+    assert!(breakeven_price(&long, 0.0).is_none());
+}
+
+#[tokio::test]
+async fn test_units_for_risk_converts_loss_to_home_currency() {
+    let mut server = mockito::Server::new_async().await;
+    let _summary = server.mock("GET", "/v3/accounts/101-001-1234567-001/summary")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"account": {"id": "101-001-1234567-001", "currency": "GBP", "balance": "10000.0000"}, "lastTransactionID": "1"}"#)
+        .create_async().await;
+    let _prices = server.mock("GET", "/v3/accounts/101-001-1234567-001/pricing")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("instruments".to_string(), "EUR_USD".to_string()),
+            mockito::Matcher::UrlEncoded("includeHomeConversions".to_string(), "true".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "prices": [{"type": "PRICE", "instrument": "EUR_USD", "bids": [{"price": "1.10000", "liquidity": 1000000}], "asks": [{"price": "1.10010", "liquidity": 1000000}]}],
+            "homeConversions": [
+                {"currency": "EUR", "accountGain": "0.85", "accountLoss": "0.86", "positionValue": "0.855"},
+                {"currency": "USD", "accountGain": "0.79", "accountLoss": "0.80", "positionValue": "0.795"}
+            ],
+            "time": "2024-01-02T10:00:00.000000000Z"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    // Risking 1% of 10,000 GBP over a 50 pip stop: 100 GBP / (0.0050 USD * 0.80 GBP/USD) per unit
+    let units = units_for_risk(&client, "101-001-1234567-001", "EUR_USD", 0.0050, 0.01).await.unwrap();
+    assert_eq!(units, 25_000.0);
+
+    for stop_distance in [0.0, -0.0050, f64::NAN, f64::INFINITY] {
+        let result = units_for_risk(&client, "101-001-1234567-001", "EUR_USD", stop_distance, 0.01).await;
+        assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidStopDistance(_)))),
+            "stop distance {} should be rejected: {:?}", stop_distance, result);
+    }
+    let result = units_for_risk(&client, "101-001-1234567-001", "EUR_USD", 0.0050, 1.5).await;
+    assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidRiskFraction(_)))));
+}
+
+#[tokio::test]
+async fn test_units_for_risk_demo() {
+    let ctx = TestContext::new().await;
+
+    let units = units_for_risk(&ctx.client, &ctx.account_id, "EUR_USD", 0.0020, 0.01)
+        .await
+        .expect("Failed to size the position");
+    assert!(units.is_finite() && units > 0.0, "Expected positive, finite units, got {}", units);
+}
@@ -4,7 +4,7 @@ use fxoanda::*;
 use common::*;
 use serde_json;
 use chrono::prelude::*;
-use fxoanda_serdes::{serfloats, serdates};
+use fxoanda_serdes::{serfloats, serdates, serdates_unix, serints64};
 
 #[tokio::test]
 async fn test_json_serialization() {
@@ -89,9 +89,9 @@ fn test_custom_float_serialization() {
     #[derive(Serialize, Deserialize, Debug, PartialEq)]
     struct TestFloatStruct {
         #[serde(with = "serfloats")]
-        price: Option<f32>,
+        price: Option<f64>,
         #[serde(with = "serfloats")]
-        amount: Option<f32>,
+        amount: Option<f64>,
     }
     
     // Test serialization with valid floats
@@ -203,6 +203,90 @@ fn test_custom_date_serialization() {
     assert_eq!(from_zero.updated_time, None, "String \"0\" should deserialize to None");
 }
 
+#[test]
+fn test_unix_date_deserialization() {
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    struct TestDateStruct {
+        #[serde(with = "serdates")]
+        time: Option<DateTime<Utc>>,
+    }
+
+    // The same instant in both formats OANDA can send
+    let unix: TestDateStruct = serde_json::from_str(r#"{"time":"1516240000.123456789"}"#).unwrap();
+    let rfc3339: TestDateStruct = serde_json::from_str(r#"{"time":"2018-01-18T01:46:40.123456789Z"}"#).unwrap();
+    assert_eq!(unix.time, rfc3339.time);
+    assert_eq!(unix.time.unwrap().timestamp_subsec_nanos(), 123456789, "Nanoseconds should be kept");
+
+    let whole: TestDateStruct = serde_json::from_str(r#"{"time":"1516240000"}"#).unwrap();
+    assert_eq!(whole.time, Utc.timestamp_opt(1516240000, 0).single());
+    let short_fraction: TestDateStruct = serde_json::from_str(r#"{"time":"1516240000.5"}"#).unwrap();
+    assert_eq!(short_fraction.time.unwrap().timestamp_subsec_millis(), 500);
+
+    let invalid = serde_json::from_str::<TestDateStruct>(r#"{"time":"yesterday"}"#);
+    assert!(invalid.is_err(), "Unparseable datetimes should be an error, not a panic");
+}
+
+#[test]
+fn test_unix_date_serialization_round_trip() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct UnixDates {
+        #[serde(with = "serdates_unix")]
+        time: Option<DateTime<Utc>>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Rfc3339Dates {
+        #[serde(with = "serdates")]
+        time: Option<DateTime<Utc>>,
+    }
+
+    let time = Utc.timestamp_opt(1516240000, 123456789).single();
+    let unix_json = serde_json::to_string(&UnixDates { time }).unwrap();
+    assert_eq!(unix_json, r#"{"time":"1516240000.123456789"}"#);
+    assert_eq!(serde_json::from_str::<UnixDates>(&unix_json).unwrap().time, time);
+    // serdates reads either format, so a UNIX body still parses with the default module
+    assert_eq!(serde_json::from_str::<Rfc3339Dates>(&unix_json).unwrap().time, time);
+
+    let rfc3339_json = serde_json::to_string(&Rfc3339Dates { time }).unwrap();
+    assert_eq!(serde_json::from_str::<Rfc3339Dates>(&rfc3339_json).unwrap().time, time);
+
+    let whole = Utc.timestamp_opt(1516240000, 0).single();
+    assert_eq!(serde_json::to_string(&UnixDates { time: whole }).unwrap(), r#"{"time":"1516240000.000000000"}"#);
+    assert_eq!(serde_json::to_string(&UnixDates { time: None }).unwrap(), r#"{"time":null}"#);
+}
+
+#[test]
+fn test_i64_ids_round_trip() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Ids {
+        #[serde(default, with = "serints64")]
+        id: Option<i64>,
+    }
+
+    // Past i32::MAX, sent as a string or as an integer
+    for json in [r#"{"id":"9999999999"}"#, r#"{"id":9999999999}"#] {
+        let ids: Ids = serde_json::from_str(json).unwrap();
+        assert_eq!(ids.id, Some(9_999_999_999));
+        assert_eq!(serde_json::to_string(&ids).unwrap(), r#"{"id":9999999999}"#);
+    }
+    assert_eq!(serde_json::from_str::<Ids>(r#"{"id":null}"#).unwrap().id, None);
+    assert!(serde_json::from_str::<Ids>(r#"{"id":"abc"}"#).is_err());
+
+    let transaction: CreateTransaction = serde_json::from_str(r#"{
+        "id": "1", "type": "CREATE", "userID": 9999999999, "accountUserID": "9999999999",
+        "siteID": 101, "divisionID": 4, "accountNumber": 1
+    }"#).unwrap();
+    assert_eq!(transaction.user_id, Some(9_999_999_999));
+    assert_eq!(transaction.account_user_id, Some(9_999_999_999));
+    assert_eq!(transaction.site_id, Some(101));
+}
+
 #[test]
 fn test_financial_precision_validation() {
     use serde::{Deserialize, Serialize};
@@ -211,11 +295,11 @@ fn test_financial_precision_validation() {
     #[derive(Serialize, Deserialize, Debug)]
     struct FinancialData {
         #[serde(with = "serfloats")]
-        bid_price: Option<f32>,
+        bid_price: Option<f64>,
         #[serde(with = "serfloats")]
-        ask_price: Option<f32>,
+        ask_price: Option<f64>,
         #[serde(with = "serfloats")]
-        units: Option<f32>,
+        units: Option<f64>,
     }
     
     // Test with typical forex precision (5 decimal places for EUR/USD)
@@ -232,15 +316,15 @@ fn test_financial_precision_validation() {
     // Deserialize and validate precision
     let deserialized: FinancialData = serde_json::from_str(&json_str).unwrap();
     
-    // Verify precision is maintained (within float32 accuracy)
+    // Verify precision is maintained (within float64 accuracy)
     if let (Some(orig_bid), Some(deser_bid)) = (forex_data.bid_price, deserialized.bid_price) {
         let diff = (orig_bid - deser_bid).abs();
-        assert!(diff < 0.00001, "Bid price precision should be maintained within float32 limits");
+        assert!(diff < 0.00001, "Bid price precision should be maintained within float64 limits");
     }
     
     if let (Some(orig_ask), Some(deser_ask)) = (forex_data.ask_price, deserialized.ask_price) {
         let diff = (orig_ask - deser_ask).abs();
-        assert!(diff < 0.00001, "Ask price precision should be maintained within float32 limits");
+        assert!(diff < 0.00001, "Ask price precision should be maintained within float64 limits");
     }
     
     // Test edge cases for financial data
@@ -260,6 +344,66 @@ fn test_financial_precision_validation() {
     println!("Edge case validation completed successfully");
 }
 
+#[test]
+fn test_price_round_trips_beyond_f32_precision() {
+    use serde_json;
+
+    // 123456.789012 needs 12 significant digits; f32 holds about 7
+    let details = StopLossDetails::new().with_price(123456.789012);
+    let json_str = serde_json::to_string(&details).unwrap();
+    assert!(json_str.contains("\"123456.789012\""), "Price should serialize exactly: {}", json_str);
+
+    let deserialized: StopLossDetails = serde_json::from_str(&json_str).unwrap();
+    assert_eq!(deserialized.price, Some(123456.789012));
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_serialization_is_exact() {
+    use fxoanda_serdes::serdecimal;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct DecimalData {
+        #[serde(with = "serdecimal")]
+        price: Option<Decimal>,
+        #[serde(with = "serdecimal")]
+        pl: Option<Decimal>,
+    }
+
+    // 20 significant digits, beyond what any binary float holds
+    let json_str = r#"{"price":"1.10005","pl":"-12345678.901234567891"}"#;
+    let data: DecimalData = serde_json::from_str(json_str).unwrap();
+    assert_eq!(data.price, Some("1.10005".parse().unwrap()));
+    assert_eq!(data.pl.unwrap().to_string(), "-12345678.901234567891");
+    assert_eq!(serde_json::to_string(&data).unwrap(), json_str);
+
+    let none: DecimalData = serde_json::from_str(r#"{"price":null,"pl":null}"#).unwrap();
+    assert_eq!(none, DecimalData { price: None, pl: None });
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_candlestick_is_exact() {
+    let candle: DecimalCandlestick = serde_json::from_str(r#"{
+        "time":"2024-01-02T10:00:00.000000000Z","complete":true,"volume":10,
+        "mid":{"o":"1.10005","h":"1.10105","l":"1.09905","c":"1.10055"},
+        "ask":{"o":"157.123","h":"157.456","l":"156.789","c":"157.100"}
+    }"#).unwrap();
+
+    let decimal = |s: &str| s.parse::<Decimal>().unwrap();
+    let (open, high, low, close) = candle.mid_ohlc().unwrap();
+    assert_eq!((open, high, low, close), (decimal("1.10005"), decimal("1.10105"), decimal("1.09905"), decimal("1.10055")));
+    // A pip of EUR_USD sums exactly, where f64 would leave a remainder
+    assert_eq!(close - open, decimal("0.0005"));
+
+    let (_, _, _, ask_close) = candle.ask_ohlc().unwrap();
+    // The quoted scale survives, where a float round-trip would print "157.1"
+    assert_eq!(ask_close.to_string(), "157.100");
+    assert_eq!(candle.bid_ohlc(), None);
+    assert!(candle.is_complete());
+}
+
 #[tokio::test]
 async fn test_real_api_data_serialization_roundtrip() {
     let client = create_test_client();
@@ -342,4 +486,27 @@ async fn test_real_api_data_serialization_roundtrip() {
         
         println!("Real API data serialization roundtrip validation completed");
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_account_summary_fixture_strict() {
+    let json = load_fixture("account_summary.json");
+
+    let response = deserialize_strict::<GetAccountSummaryResponse>(&json)
+        .unwrap_or_else(|e| panic!("Fixture has fields the crate doesn't model: {}", e));
+
+    let account = response.account.expect("Fixture should have an account");
+    assert_eq!(account.id.as_deref(), Some("101-004-1435156-002"));
+    assert_eq!(account.currency.as_deref(), Some("CAD"));
+    assert!(account.balance.is_some(), "Fixture balance should parse");
+}
+
+#[test]
+fn test_strict_deserialization_flags_unknown_fields() {
+    let mut json: serde_json::Value = serde_json::from_str(&load_fixture("account_summary.json")).unwrap();
+    json["account"]["someNewField"] = serde_json::json!("added by OANDA");
+
+    let result = deserialize_strict::<GetAccountSummaryResponse>(&json.to_string());
+    let error = result.expect_err("Strict mode should reject an unmodeled field");
+    assert!(error.contains("someNewField"), "Error should name the unknown field: {}", error);
+}
@@ -149,6 +149,50 @@ async fn test_close_trade_workflow_simulation() {
     // If it fails, that's acceptable for invalid trade IDs
 }
 
+#[tokio::test]
+async fn test_tag_trade_then_close() {
+    let ctx = TestContext::new().await;
+    let tag = ctx.unique_trade_comment("close_with_tag");
+
+    // Market may be closed, in which case there is no trade to close
+    let trade_id = match create_test_position(&ctx, "EUR_USD", 1).await {
+        Ok(trade_id) => trade_id,
+        Err(e) => {
+            println!("Could not open a trade to close (market may be closed): {}", e);
+            return;
+        }
+    };
+
+    // The close itself can't carry client extensions, so tag the trade first
+    let tagged = SetTradeClientExtensionsRequest::new()
+        .with_account_id(ctx.account_id.clone())
+        .with_trade_specifier(trade_id.clone())
+        .with_client_extensions(ClientExtensions::new().with_tag(tag.clone()))
+        .remote(&ctx.client)
+        .await;
+    assert!(tagged.is_ok(), "Failed to tag trade: {:?}", tagged);
+
+    let result = CloseTradeRequest::new()
+        .with_account_id(ctx.account_id.clone())
+        .with_trade_specifier(trade_id.clone())
+        .with_units("ALL".to_string())
+        .remote(&ctx.client)
+        .await;
+    assert!(result.is_ok(), "Failed to close trade: {:?}", result);
+
+    let closed = GetTradeRequest::new()
+        .with_account_id(ctx.account_id.clone())
+        .with_trade_specifier(trade_id)
+        .remote(&ctx.client)
+        .await
+        .expect("the closed trade should still be retrievable");
+    assert_eq!(
+        closed.trade.and_then(|trade| trade.client_extensions).and_then(|ext| ext.tag),
+        Some(tag),
+        "Closed trade should keep the tag set before closing"
+    );
+}
+
 #[tokio::test]
 async fn test_trade_client_extensions_workflow() {
     let client = create_test_client();
@@ -249,7 +293,7 @@ async fn test_trade_calculations_validation() {
                 
                 // Validate precision based on instrument
                 if let Some(instrument) = &trade.instrument {
-                    assert_price_precision(price.into(), instrument);
+                    assert_price_precision(price, instrument);
                 }
             }
             
@@ -413,10 +457,18 @@ async fn test_trade_consistency_with_positions() {
     
     // Get positions
     let positions_result = ListOpenPositionsRequest::new()
-        .with_account_id(account_id)
+        .with_account_id(account_id.clone())
         .remote(&client)
         .await;
     
+    let hedging = GetAccountSummaryRequest::new()
+        .with_account_id(account_id)
+        .remote(&client)
+        .await
+        .ok()
+        .and_then(|summary| summary.account)
+        .and_then(|account| account.is_hedging());
+    
     if trades_result.is_ok() && positions_result.is_ok() {
         let trades_response = trades_result.unwrap();
         let positions_response = positions_result.unwrap();
@@ -438,5 +490,15 @@ async fn test_trade_consistency_with_positions() {
                 }
             }
         }
+        
+        // A netting account holds each instrument on one side only; hedging accounts may hold both
+        if hedging == Some(false) {
+            for position in positions.iter() {
+                let long_units = position.long.as_ref().and_then(|side| side.units).unwrap_or(0.0);
+                let short_units = position.short.as_ref().and_then(|side| side.units).unwrap_or(0.0);
+                assert!(long_units == 0.0 || short_units == 0.0,
+                    "Netting account should not hold both sides of {:?}", position.instrument);
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -188,8 +188,17 @@ async fn test_end_to_end_trading_workflow() {
         .remote(&client)
         .await;
 
-    assert!(create_order_result.is_ok(), "Failed to create market order: {:?}", create_order_result);
-    let order_response = create_order_result.unwrap();
+    let order_response = match create_order_result {
+        Err(FxError::MarketClosed { instrument }) => {
+            println!("Market is closed: OANDA halted the order for {}", instrument);
+            println!("End-to-end trading workflow completed successfully (market closed scenario)!");
+            return;
+        }
+        other => {
+            assert!(other.is_ok(), "Failed to create market order: {:?}", other);
+            other.unwrap()
+        }
+    };
 
     // Step 3: Verify the order was created successfully
     assert!(order_response.order_create_transaction.is_some(), "Order create transaction should be present");
@@ -339,3 +348,813 @@ async fn test_end_to_end_trading_workflow() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_insufficient_liquidity_cancel_is_typed_error() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCreateTransaction": {"id": "6372", "type": "MARKET_ORDER", "instrument": "EUR_USD", "units": "1000000"},
+            "orderCancelTransaction": {"id": "6373", "type": "ORDER_CANCEL", "orderID": "6372", "reason": "INSUFFICIENT_LIQUIDITY"},
+            "relatedTransactionIDs": ["6372", "6373"],
+            "lastTransactionID": "6373"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = CreateMarketOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(
+            MarketOrder::new()
+                .with_instrument("EUR_USD".to_string())
+                .with_units(1000000.0)
+                .with_time_in_force("FOK".to_string())
+                .with_otype("MARKET".to_string())
+        )
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::InsufficientLiquidity { instrument, units, order_id }) => {
+            assert_eq!(instrument, "EUR_USD");
+            assert_eq!(units, "1000000");
+            assert_eq!(order_id, "6372");
+        }
+        other => panic!("Expected InsufficientLiquidity, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_partial_fill_with_insufficient_liquidity_cancel_returns_fill() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCreateTransaction": {"id": "6372", "type": "MARKET_ORDER", "instrument": "EUR_USD", "units": "1000000", "timeInForce": "IOC"},
+            "orderFillTransaction": {"id": "6373", "type": "ORDER_FILL", "orderID": "6372", "instrument": "EUR_USD", "units": "400000"},
+            "orderCancelTransaction": {"id": "6374", "type": "ORDER_CANCEL", "orderID": "6372", "reason": "INSUFFICIENT_LIQUIDITY"},
+            "relatedTransactionIDs": ["6372", "6373", "6374"],
+            "lastTransactionID": "6374"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let response = CreateMarketOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(
+            MarketOrder::new()
+                .with_instrument("EUR_USD".to_string())
+                .with_units(1000000.0)
+                .with_time_in_force("IOC".to_string())
+                .with_otype("MARKET".to_string())
+        )
+        .remote(&client)
+        .await
+        .expect("a partial fill should not be reported as an error");
+
+    mock.assert_async().await;
+    let fill = response.order_fill_transaction.expect("the fill should be returned");
+    assert_eq!(fill.id.as_deref(), Some("6373"));
+    assert_eq!(fill.units, Some(400000.0));
+    assert!(response.order_cancel_transaction.is_some());
+}
+
+#[tokio::test]
+async fn test_market_halted_is_typed_error() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCreateTransaction": {"id": "6380", "type": "MARKET_ORDER", "instrument": "EUR_USD", "units": "1"},
+            "orderCancelTransaction": {"id": "6381", "type": "ORDER_CANCEL", "orderID": "6380", "reason": "MARKET_HALTED"},
+            "relatedTransactionIDs": ["6380", "6381"],
+            "lastTransactionID": "6381"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = CreateMarketOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(
+            MarketOrder::new()
+                .with_instrument("EUR_USD".to_string())
+                .with_units(1.0)
+                .with_time_in_force("FOK".to_string())
+                .with_otype("MARKET".to_string())
+        )
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::MarketClosed { instrument }) => assert_eq!(instrument, "EUR_USD"),
+        other => panic!("Expected MarketClosed, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_limit_order_reject_transaction_is_order_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderRejectTransaction": {"id": "6390", "type": "LIMIT_ORDER_REJECT", "instrument": "EUR_USD", "units": "5000000", "rejectReason": "INSUFFICIENT_MARGIN"},
+            "relatedTransactionIDs": ["6390"],
+            "lastTransactionID": "6390"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = CreateLimitOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(LimitOrder::new().with_instrument("EUR_USD".to_string()).with_units(5000000.0).with_price(1.05))
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::OrderRejection { instrument, units, reject_reason, .. }) => {
+            assert_eq!(instrument, "EUR_USD");
+            assert_eq!(units, "5000000");
+            assert_eq!(reject_reason, "INSUFFICIENT_MARGIN");
+        }
+        other => panic!("Expected OrderRejection, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_stop_order_error_response_with_reject_is_order_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderRejectTransaction": {"id": "6391", "type": "STOP_ORDER_REJECT", "instrument": "EUR_USD", "units": "100", "rejectReason": "PRICE_PRECISION_EXCEEDED"},
+            "errorCode": "PRICE_PRECISION_EXCEEDED",
+            "errorMessage": "The price specified contains more precision than is allowed for the instrument"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = CreateStopOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(StopOrder::new().with_instrument("EUR_USD".to_string()).with_units(100.0).with_price(1.123456))
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::OrderRejection { reject_reason, error_code, error_message, .. }) => {
+            assert_eq!(reject_reason, "PRICE_PRECISION_EXCEEDED");
+            assert_eq!(error_code, "PRICE_PRECISION_EXCEEDED");
+            assert!(error_message.contains("precision"));
+        }
+        other => panic!("Expected OrderRejection, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unfilled_market_order_cancel_is_order_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCreateTransaction": {"id": "6392", "type": "MARKET_ORDER", "instrument": "EUR_USD", "units": "100"},
+            "orderCancelTransaction": {"id": "6393", "type": "ORDER_CANCEL", "orderID": "6392", "reason": "STOP_LOSS_ON_FILL_LOSS"},
+            "relatedTransactionIDs": ["6392", "6393"],
+            "lastTransactionID": "6393"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = CreateMarketOrderRequest::new()
+        .with_account_id("101-001-1234567-001".to_string())
+        .with_order(
+            MarketOrder::new()
+                .with_instrument("EUR_USD".to_string())
+                .with_units(100.0)
+                .with_time_in_force("FOK".to_string())
+                .with_otype("MARKET".to_string())
+        )
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::OrderRejection { instrument, units, reject_reason, error_code, .. }) => {
+            assert_eq!(instrument, "EUR_USD");
+            assert_eq!(units, "100");
+            assert_eq!(reject_reason, "STOP_LOSS_ON_FILL_LOSS");
+            assert_eq!(error_code, "ORDER_CANCELLED");
+        }
+        other => panic!("Expected OrderRejection, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_market_buy_and_sell() {
+    let ctx = TestContext::new().await;
+
+    for buy in [true, false] {
+        let result = if buy {
+            ctx.client.market_buy(&ctx.account_id, "EUR_USD", 1.0).await
+        } else {
+            ctx.client.market_sell(&ctx.account_id, "EUR_USD", 1.0).await
+        };
+
+        let fill = match result {
+            Err(FxError::MarketClosed { instrument }) => {
+                println!("Market is closed for {}; nothing to verify", instrument);
+                return;
+            }
+            other => other.expect("Market order should fill"),
+        };
+
+        assert_eq!(fill.instrument, "EUR_USD");
+        assert_eq!(fill.units, if buy { 1.0 } else { -1.0 }, "Fill units should carry the order's direction");
+        assert!(fill.price > 0.0, "Fill should report a price");
+        assert!(!fill.order_id.is_empty(), "Fill should reference the order");
+
+        // Close whatever the fill opened so the demo account is left as found
+        if let Some(trade_id) = fill.trade_opened {
+            let close_result = CloseTradeRequest::new()
+                .with_account_id(ctx.account_id.clone())
+                .with_trade_specifier(trade_id)
+                .with_units("ALL".to_string())
+                .remote(&ctx.client)
+                .await;
+            assert!(close_result.is_ok(), "Failed to close trade: {:?}", close_result);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_protected_limit_order_create_and_cancel() {
+    let ctx = TestContext::new().await;
+
+    // Far below market so it stays pending
+    let result = ctx.client
+        .limit_order(&ctx.account_id, "EUR_USD", 1.0, 0.5, Some(0.45), Some(0.55))
+        .await;
+    assert!(result.is_ok(), "Failed to place limit order: {:?}", result);
+    let order_id = result.unwrap();
+
+    let order = GetOrderRequest::new()
+        .with_account_id(ctx.account_id.clone())
+        .with_order_specifier(order_id.clone())
+        .remote(&ctx.client)
+        .await
+        .expect("Failed to fetch the new order")
+        .order
+        .expect("Order should be returned");
+    assert_eq!(order.state.as_deref(), Some("PENDING"));
+
+    ctx.cleanup_test_orders(vec![order_id]).await;
+}
+
+#[tokio::test]
+async fn test_protected_limit_order_rejects_misplaced_sl_tp() {
+    let client = create_mock_client();
+    let account_id = "101-001-1234567-001";
+
+    // Buy: stop loss must be below the entry, take profit above
+    let result = client.limit_order(account_id, "EUR_USD", 100.0, 1.10, Some(1.12), Some(1.15)).await;
+    assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidStopLoss))));
+    let result = client.limit_order(account_id, "EUR_USD", 100.0, 1.10, Some(1.05), Some(1.08)).await;
+    assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidTakeProfit))));
+
+    // Sell: the reverse
+    let result = client.limit_order(account_id, "EUR_USD", -100.0, 1.10, Some(1.05), None).await;
+    assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidStopLoss))));
+    let result = client.limit_order(account_id, "EUR_USD", -100.0, 1.10, None, Some(1.15)).await;
+    assert!(matches!(result, Err(FxError::Validation(RequestValidationError::InvalidTakeProfit))));
+}
+
+#[test]
+fn test_validate_sl_tp_both_directions() {
+    // Long: stop loss below the entry, take profit above
+    assert_eq!(validate_sl_tp(TradeDirection::Long, 1.10, Some(1.08), Some(1.12)), Ok(()));
+    assert_eq!(validate_sl_tp(TradeDirection::Long, 1.10, Some(1.12), Some(1.15)), Err(RequestValidationError::InvalidStopLoss));
+    assert_eq!(validate_sl_tp(TradeDirection::Long, 1.10, Some(1.08), Some(1.09)), Err(RequestValidationError::InvalidTakeProfit));
+
+    // Short: the reverse
+    assert_eq!(validate_sl_tp(TradeDirection::Short, 1.10, Some(1.12), Some(1.08)), Ok(()));
+    assert_eq!(validate_sl_tp(TradeDirection::Short, 1.10, Some(1.08), None), Err(RequestValidationError::InvalidStopLoss));
+    assert_eq!(validate_sl_tp(TradeDirection::Short, 1.10, None, Some(1.12)), Err(RequestValidationError::InvalidTakeProfit));
+
+    // A level at the entry is on neither side; an absent one isn't checked
+    assert_eq!(validate_sl_tp(TradeDirection::Long, 1.10, Some(1.10), None), Err(RequestValidationError::InvalidStopLoss));
+    assert_eq!(validate_sl_tp(TradeDirection::Short, 1.10, None, None), Ok(()));
+    assert_eq!(TradeDirection::from_units(-5.0), TradeDirection::Short);
+}
+
+#[test]
+fn test_trigger_condition_wire_values() {
+    for (condition, wire) in [
+        (TriggerCondition::Default, "DEFAULT"),
+        (TriggerCondition::Inverse, "INVERSE"),
+        (TriggerCondition::Bid, "BID"),
+        (TriggerCondition::Ask, "ASK"),
+        (TriggerCondition::Mid, "MID"),
+    ] {
+        assert_eq!(serde_json::to_value(condition).unwrap(), wire);
+        assert_eq!(condition.as_str(), wire);
+        assert_eq!(wire.parse::<TriggerCondition>(), Ok(condition));
+
+        let order = serde_json::to_value(
+            LimitOrder::new()
+                .with_instrument("EUR_USD".to_string())
+                .with_trigger_condition(condition)
+        ).unwrap();
+        assert_eq!(order["triggerCondition"], wire, "Limit order should carry the trigger condition");
+    }
+}
+
+#[test]
+fn test_position_fill_wire_values() {
+    for (fill, wire) in [
+        (PositionFill::OpenOnly, "OPEN_ONLY"),
+        (PositionFill::ReduceFirst, "REDUCE_FIRST"),
+        (PositionFill::ReduceOnly, "REDUCE_ONLY"),
+        (PositionFill::Default, "DEFAULT"),
+    ] {
+        assert_eq!(serde_json::to_value(fill).unwrap(), wire);
+        assert_eq!(fill.as_str(), wire);
+        assert_eq!(wire.parse::<PositionFill>(), Ok(fill));
+
+        let order = serde_json::to_value(
+            MarketOrder::new()
+                .with_instrument("EUR_USD".to_string())
+                .with_position_fill(fill)
+        ).unwrap();
+        assert_eq!(order["positionFill"], wire, "Market order should carry the position fill");
+    }
+}
+
+#[test]
+fn test_validate_order_reports_every_violation() {
+    let eur_usd = InstrumentMetadata::new()
+        .with_name("EUR_USD".to_string())
+        .with_display_precision(5)
+        .with_minimum_trade_size(1.0)
+        .with_maximum_order_units(100_000_000.0)
+        .with_minimum_trailing_stop_distance(0.0005);
+
+    let valid = CreateLimitOrderRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .with_order(LimitOrder::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_units(1000.0)
+            .with_price(1.10005)
+            .with_trigger_condition(TriggerCondition::Default));
+    assert_eq!(valid.validate_order(&eur_usd), Ok(()));
+
+    // Too many units and a price quoted past the display precision
+    let invalid = CreateLimitOrderRequest::new()
+        .with_account_id("101-004-1234567-001".to_string())
+        .with_order(LimitOrder::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_units(500_000_000.0)
+            .with_price(1.100055)
+            .with_trigger_condition(TriggerCondition::Default));
+    let violations = invalid.validate_order(&eur_usd).expect_err("order breaks two constraints");
+    assert_eq!(violations.len(), 2, "Expected both violations, got {:?}", violations);
+    assert!(violations.iter().any(|v| matches!(v, RequestValidationError::UnitsOutOfRange { .. })));
+    assert!(violations.iter().any(|v| matches!(v, RequestValidationError::PricePrecision { display_precision: 5, .. })));
+
+    // Zero units and a trailing stop inside the minimum distance
+    let market = CreateMarketOrderRequest::new()
+        .with_order(MarketOrder::new()
+            .with_units(0.0)
+            .with_trailing_stop_loss_on_fill(TrailingStopLossDetails::new().with_distance(0.0001)));
+    let violations = market.validate_order(&eur_usd).expect_err("order breaks two constraints");
+    assert!(violations.contains(&RequestValidationError::UnitsOutOfRange { units: 0.0, minimum: 1.0, maximum: 100_000_000.0 }));
+    assert!(violations.iter().any(|v| matches!(v, RequestValidationError::StopDistanceTooSmall { .. })));
+
+    let stop = CreateStopOrderRequest::new()
+        .with_order(StopOrder::new()
+            .with_units(1000.0)
+            .with_price(1.2)
+            .with_trigger_condition("LAST".to_string()));
+    assert_eq!(
+        stop.validate_order(&eur_usd),
+        Err(vec![RequestValidationError::InvalidTriggerCondition("LAST".to_string())])
+    );
+}
+
+#[test]
+fn test_validate_order_checks_stop_distances_for_each_order_type() {
+    let eur_usd = InstrumentMetadata::new()
+        .with_name("EUR_USD".to_string())
+        .with_minimum_trailing_stop_distance(0.0005)
+        .with_minimum_guaranteed_stop_loss_distance(0.0010);
+    let guaranteed = |distance| StopLossDetails::new().with_distance(distance).with_guaranteed(true);
+    let trailing = |distance| TrailingStopLossDetails::new().with_distance(distance);
+    let too_close = |distance, minimum| Err(vec![RequestValidationError::StopDistanceTooSmall { distance, minimum }]);
+
+    let market = |stop_loss: StopLossDetails, trailing_stop: TrailingStopLossDetails| CreateMarketOrderRequest::new()
+        .with_order(MarketOrder::new()
+            .with_units(1000.0)
+            .with_stop_loss_on_fill(stop_loss)
+            .with_trailing_stop_loss_on_fill(trailing_stop))
+        .validate_order(&eur_usd);
+    assert_eq!(market(guaranteed(0.0010), trailing(0.0005)), Ok(()));
+    assert_eq!(market(guaranteed(0.0005), trailing(0.0005)), too_close(0.0005, 0.0010));
+    assert_eq!(market(guaranteed(0.0010), trailing(0.0001)), too_close(0.0001, 0.0005));
+    // No minimum applies to a stop loss that isn't guaranteed
+    assert_eq!(market(StopLossDetails::new().with_distance(0.0001), trailing(0.0005)), Ok(()));
+
+    let limit = |stop_loss: StopLossDetails, trailing_stop: TrailingStopLossDetails| CreateLimitOrderRequest::new()
+        .with_order(LimitOrder::new()
+            .with_units(1000.0)
+            .with_price(1.1)
+            .with_stop_loss_on_fill(stop_loss)
+            .with_trailing_stop_loss_on_fill(trailing_stop))
+        .validate_order(&eur_usd);
+    assert_eq!(limit(guaranteed(0.0010), trailing(0.0005)), Ok(()));
+    assert_eq!(limit(guaranteed(0.0005), trailing(0.0005)), too_close(0.0005, 0.0010));
+    assert_eq!(limit(guaranteed(0.0010), trailing(0.0001)), too_close(0.0001, 0.0005));
+
+    let stop = |stop_loss: StopLossDetails, trailing_stop: TrailingStopLossDetails| CreateStopOrderRequest::new()
+        .with_order(StopOrder::new()
+            .with_units(1000.0)
+            .with_price(1.2)
+            .with_stop_loss_on_fill(stop_loss)
+            .with_trailing_stop_loss_on_fill(trailing_stop))
+        .validate_order(&eur_usd);
+    assert_eq!(stop(guaranteed(0.0010), trailing(0.0005)), Ok(()));
+    assert_eq!(stop(guaranteed(0.0005), trailing(0.0005)), too_close(0.0005, 0.0010));
+    assert_eq!(stop(guaranteed(0.0010), trailing(0.0001)), too_close(0.0001, 0.0005));
+}
+
+#[tokio::test]
+async fn test_twap_execute_small_slices() {
+    let ctx = TestContext::new().await;
+
+    let fills = match twap_execute(&ctx.client, &ctx.account_id, "EUR_USD", 3.0, 3, std::time::Duration::from_millis(500)).await {
+        Err(FxError::MarketClosed { instrument }) => {
+            println!("Market is closed for {}; nothing to verify", instrument);
+            return;
+        }
+        other => other.expect("TWAP slices should fill"),
+    };
+
+    assert_eq!(fills.len(), 3, "Each slice should produce a fill");
+    assert!(fills.iter().all(|fill| fill.units == Some(1.0)), "Each slice should be one unit");
+    assert!(fills.windows(2).all(|pair| pair[0].time <= pair[1].time), "Fills should be in execution order");
+
+    // Close what the slices opened so the demo account is left as found
+    for trade_id in fills.into_iter().filter_map(|fill| fill.trade_opened.and_then(|trade| trade.trade_id)) {
+        let close_result = CloseTradeRequest::new()
+            .with_account_id(ctx.account_id.clone())
+            .with_trade_specifier(trade_id)
+            .with_units("ALL".to_string())
+            .remote(&ctx.client)
+            .await;
+        assert!(close_result.is_ok(), "Failed to close trade: {:?}", close_result);
+    }
+}
+
+#[tokio::test]
+async fn test_twap_execute_aborts_on_rejection() {
+    use std::sync::{Arc, Mutex};
+
+    let slice_units = Arc::new(Mutex::new(Vec::new()));
+    let recorded_units = slice_units.clone();
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/v3/accounts/101-001-1234567-001/orders")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(move |request| {
+            let body: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+            let mut units = recorded_units.lock().unwrap();
+            units.push(body["order"]["units"].as_str().unwrap().to_string());
+            if units.len() < 3 {
+                format!(r#"{{
+                    "orderCreateTransaction": {{"id": "{id}", "type": "MARKET_ORDER", "instrument": "EUR_USD"}},
+                    "orderFillTransaction": {{"id": "{fill}", "type": "ORDER_FILL", "orderID": "{id}", "instrument": "EUR_USD", "units": "{units}", "price": "1.10000"}},
+                    "lastTransactionID": "{fill}"
+                }}"#, id = units.len() * 2, fill = units.len() * 2 + 1, units = units.last().unwrap()).into_bytes()
+            } else {
+                br#"{
+                    "orderCreateTransaction": {"id": "6", "type": "MARKET_ORDER", "instrument": "EUR_USD"},
+                    "orderCancelTransaction": {"id": "7", "type": "ORDER_CANCEL", "orderID": "6", "reason": "FIFO_VIOLATION"},
+                    "lastTransactionID": "7"
+                }"#.to_vec()
+            }
+        })
+        .expect(3)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = twap_execute(&client, "101-001-1234567-001", "EUR_USD", 10.0, 4, std::time::Duration::from_millis(1)).await;
+
+    mock.assert_async().await;
+    // 10 units over 4 slices rounds to 3, 2, 3, 2; the third slice is rejected and the fourth never sent
+    assert_eq!(*slice_units.lock().unwrap(), vec!["3", "2", "3"]);
+    match result {
+        Err(FxError::OrderRejection { reject_reason, .. }) => assert_eq!(reject_reason, "FIFO_VIOLATION"),
+        other => panic!("Expected the rejected slice to abort execution, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_has_order_with_client_id_by_specifier() {
+    let mut server = mockito::Server::new_async().await;
+    let found = server.mock("GET", "/v3/accounts/101-001-1234567-001/orders/@bot-order-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "order": {"id": "42", "type": "MARKET", "state": "FILLED", "clientExtensions": {"id": "bot-order-1"}},
+            "lastTransactionID": "43"
+        }"#)
+        .create_async().await;
+    let missing = server.mock("GET", "/v3/accounts/101-001-1234567-001/orders/@bot-order-2")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorCode": "ORDER_DOESNT_EXIST", "errorMessage": "The order specified does not exist"}"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    assert!(has_order_with_client_id(&client, "101-001-1234567-001", "bot-order-1").await.unwrap());
+    assert!(!has_order_with_client_id(&client, "101-001-1234567-001", "bot-order-2").await.unwrap());
+    found.assert_async().await;
+    missing.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_has_order_with_client_id_encodes_specifier() {
+    let mut server = mockito::Server::new_async().await;
+    let found = server.mock("GET", "/v3/accounts/101-001-1234567-001/orders/@bot%2Forder%3F1%23a%20b")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "order": {"id": "42", "type": "MARKET", "state": "FILLED", "clientExtensions": {"id": "bot/order?1#a b"}},
+            "lastTransactionID": "43"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    assert!(has_order_with_client_id(&client, "101-001-1234567-001", "bot/order?1#a b").await.unwrap());
+    found.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_has_order_with_client_id_demo() {
+    let ctx = TestContext::new().await;
+    let client_id = ctx.unique_order_id("client_id_lookup");
+    assert!(!has_order_with_client_id(&ctx.client, &ctx.account_id, &client_id).await.unwrap(),
+        "A fresh client ID should not match any order");
+
+    // Far below market so it stays pending
+    let response = CreateLimitOrderRequest::new()
+        .with_account_id(ctx.account_id.clone())
+        .with_order(LimitOrder::new()
+            .with_instrument("EUR_USD".to_string())
+            .with_units(1.0)
+            .with_price(0.5)
+            .with_client_extensions(ClientExtensions::new().with_id(client_id.clone())))
+        .remote(&ctx.client)
+        .await
+        .expect("Failed to place limit order");
+
+    assert!(has_order_with_client_id(&ctx.client, &ctx.account_id, &client_id).await.unwrap(),
+        "The submitted order should be found by its client ID");
+
+    let order_id = response.order_create_transaction.and_then(|t| t.id);
+    ctx.cleanup_test_orders(order_id.into_iter().collect()).await;
+}
+
+#[test]
+fn test_order_convenience_constructors() {
+    let market = serde_json::to_value(MarketOrder::market("EUR_USD", -100.0)).unwrap();
+    assert_eq!(market["type"], "MARKET");
+    assert_eq!(market["instrument"], "EUR_USD");
+    assert_eq!(market["units"], "-100");
+    assert_eq!(market["timeInForce"], "FOK");
+
+    let limit = LimitOrder::limit(InstrumentName::new("USD", "JPY"), 500.0, 149.5);
+    assert_eq!(limit.otype.as_deref(), Some("LIMIT"));
+    assert_eq!(limit.instrument.as_deref(), Some("USD_JPY"));
+    assert_eq!(limit.price, Some(149.5));
+    assert_eq!(limit.time_in_force.as_deref(), Some("GTC"));
+
+    let stop = StopOrder::stop("EUR_USD", 100.0, 1.12);
+    assert_eq!(stop.otype.as_deref(), Some("STOP"));
+    assert_eq!(stop.units, Some(100.0));
+    assert_eq!(stop.price, Some(1.12));
+    assert_eq!(stop.time_in_force.as_deref(), Some("GTC"));
+}
+
+#[test]
+fn test_typed_time_in_force_and_order_type() {
+    let order = LimitOrderRequest::new()
+        .with_type_typed(OrderType::Limit)
+        .with_time_in_force_typed(TimeInForce::Gtd);
+    let json = serde_json::to_value(&order).unwrap();
+    assert_eq!(json["type"], "LIMIT");
+    assert_eq!(json["timeInForce"], "GTD");
+
+    // The enums serialize to the same strings the setters store
+    for tif in [TimeInForce::Gtc, TimeInForce::Gtd, TimeInForce::Gfd, TimeInForce::Fok, TimeInForce::Ioc] {
+        assert_eq!(serde_json::to_value(tif).unwrap(), tif.as_str());
+        assert_eq!(tif.as_str().parse::<TimeInForce>(), Ok(tif));
+    }
+    for order_type in [OrderType::Market, OrderType::Limit, OrderType::Stop, OrderType::MarketIfTouched,
+        OrderType::TakeProfit, OrderType::StopLoss, OrderType::TrailingStopLoss, OrderType::FixedPrice] {
+        assert_eq!(serde_json::to_value(order_type).unwrap(), order_type.as_str());
+        assert_eq!(order_type.as_str().parse::<OrderType>(), Ok(order_type));
+    }
+
+    // The string setters still work alongside
+    let market = MarketOrder::new().with_time_in_force(TimeInForce::Ioc.into());
+    assert_eq!(market.time_in_force.as_deref(), Some("IOC"));
+}
+
+#[tokio::test]
+async fn test_cancel_all_pending_orders_tolerates_vanished_orders() {
+    let mut server = mockito::Server::new_async().await;
+    let _pending = server.mock("GET", "/v3/accounts/101-004-1234567-001/pendingOrders")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"orders": [
+            {"id": "31", "type": "LIMIT", "state": "PENDING"},
+            {"id": "32", "type": "STOP", "state": "PENDING"},
+            {"id": "33", "type": "LIMIT", "state": "PENDING"}
+        ], "lastTransactionID": "33"}"#)
+        .create_async()
+        .await;
+    let cancelled = |order_id: &str, transaction_id: &str| format!(
+        r#"{{"orderCancelTransaction": {{"id": "{}", "type": "ORDER_CANCEL", "orderID": "{}", "reason": "CLIENT_REQUEST"}}, "lastTransactionID": "{}"}}"#,
+        transaction_id, order_id, transaction_id);
+    let first = server.mock("PUT", "/v3/accounts/101-004-1234567-001/orders/31/cancel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cancelled("31", "40"))
+        .create_async()
+        .await;
+    // Filled between the listing and the cancel
+    let filled = server.mock("PUT", "/v3/accounts/101-004-1234567-001/orders/32/cancel")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorCode": "ORDER_DOESNT_EXIST", "errorMessage": "The Order specified does not exist"}"#)
+        .create_async()
+        .await;
+    let third = server.mock("PUT", "/v3/accounts/101-004-1234567-001/orders/33/cancel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cancelled("33", "41"))
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let responses = client.cancel_all_pending_orders("101-004-1234567-001").await
+        .expect("an order that is already gone should not fail the call");
+
+    first.assert_async().await;
+    filled.assert_async().await;
+    third.assert_async().await;
+    let order_ids: Vec<_> = responses.iter().map(|(order_id, _)| order_id.as_str()).collect();
+    assert_eq!(order_ids, vec!["31", "33"]);
+    let cancelled_ids: Vec<_> = responses.into_iter()
+        .filter_map(|(_, result)| result.ok()?.order_cancel_transaction?.order_id)
+        .collect();
+    assert_eq!(cancelled_ids, vec!["31", "33"]);
+}
+
+#[tokio::test]
+async fn test_cancel_all_pending_orders_reports_other_failures() {
+    let mut server = mockito::Server::new_async().await;
+    let _pending = server.mock("GET", "/v3/accounts/101-004-1234567-001/pendingOrders")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"orders": [{"id": "51", "type": "LIMIT"}, {"id": "52", "type": "LIMIT"}], "lastTransactionID": "52"}"#)
+        .create_async()
+        .await;
+    let _broken = server.mock("PUT", "/v3/accounts/101-004-1234567-001/orders/51/cancel")
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errorMessage": "Internal server error"}"#)
+        .create_async()
+        .await;
+    let other = server.mock("PUT", "/v3/accounts/101-004-1234567-001/orders/52/cancel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"orderCancelTransaction": {"id": "60", "orderID": "52"}, "lastTransactionID": "60"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = create_mock_server_client(&server);
+
+    let outcomes = client.cancel_all_pending_orders("101-004-1234567-001").await
+        .expect("listing succeeded, so each order gets its own outcome");
+
+    other.assert_async().await;
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[0].0, "51");
+    assert!(matches!(outcomes[0].1, Err(FxError::ApiError { status_code: 500, .. })), "{:?}", outcomes[0].1);
+    assert_eq!(outcomes[1].0, "52");
+    let cancelled = outcomes[1].1.as_ref().expect("the other order is still cancelled");
+    assert_eq!(cancelled.order_cancel_transaction.as_ref().and_then(|t| t.order_id.as_deref()), Some("52"));
+}
+
+#[tokio::test]
+async fn test_replace_of_filled_order_is_order_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("PUT", "/v3/accounts/101-001-1234567-001/orders/6372")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCancelTransaction": {"id": "6380", "type": "ORDER_CANCEL", "orderID": "6372", "reason": "CLIENT_REQUEST"},
+            "relatedTransactionIDs": ["6380"],
+            "lastTransactionID": "6380"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = ReplaceOrderRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_order_specifier("6372".to_string())
+        .with_order(OrderRequest::new())
+        .remote(&client)
+        .await;
+
+    mock.assert_async().await;
+    match result {
+        Err(FxError::OrderRejection { reject_reason, error_code, .. }) => {
+            assert_eq!(reject_reason, "CLIENT_REQUEST");
+            assert_eq!(error_code, "ORDER_DOESNT_EXIST");
+        }
+        other => panic!("Expected OrderRejection, got {:?}", other),
+    }
+
+    let _mock = server.mock("PUT", "/v3/accounts/101-001-1234567-001/orders/6373")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"relatedTransactionIDs": [], "lastTransactionID": "6380"}"#)
+        .create_async().await;
+    let result = ReplaceOrderRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_order_specifier("6373".to_string())
+        .remote(&client)
+        .await;
+    assert!(matches!(&result, Err(FxError::OrderRejection { reject_reason, .. }) if reject_reason == "ORDER_DOESNT_EXIST"), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_replace_order_succeeds_with_replacement() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("PUT", "/v3/accounts/101-001-1234567-001/orders/6372")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCancelTransaction": {"id": "6380", "type": "ORDER_CANCEL", "orderID": "6372", "reason": "CLIENT_REQUEST_REPLACED", "replacedByOrderID": "6381"},
+            "orderCreateTransaction": {"id": "6381", "type": "LIMIT_ORDER", "instrument": "EUR_USD", "units": "100", "replacesOrderID": "6372"},
+            "relatedTransactionIDs": ["6380", "6381"],
+            "lastTransactionID": "6381"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let response = ReplaceOrderRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_order_specifier("6372".to_string())
+        .remote(&client)
+        .await
+        .expect("replacement should succeed");
+
+    mock.assert_async().await;
+    assert_eq!(response.last_transaction_id.as_deref(), Some("6381"));
+    assert!(response.order_create_transaction.is_some());
+}
+
+#[tokio::test]
+async fn test_replacement_cancelled_on_creation_is_order_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server.mock("PUT", "/v3/accounts/101-001-1234567-001/orders/6372")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{
+            "orderCancelTransaction": {"id": "6380", "type": "ORDER_CANCEL", "orderID": "6372", "reason": "CLIENT_REQUEST_REPLACED"},
+            "orderCreateTransaction": {"id": "6381", "type": "MARKET_IF_TOUCHED_ORDER", "instrument": "EUR_USD", "units": "100"},
+            "replacingOrderCancelTransaction": {"id": "6382", "type": "ORDER_CANCEL", "orderID": "6381", "reason": "MARKET_HALTED"},
+            "lastTransactionID": "6382"
+        }"#)
+        .create_async().await;
+    let client = create_mock_server_client(&server);
+
+    let result = ReplaceOrderRequest::new()
+        .with_account_id("101-001-1234567-001")
+        .with_order_specifier("6372".to_string())
+        .remote(&client)
+        .await;
+
+    assert!(matches!(result, Err(FxError::MarketClosed { .. })), "{:?}", result);
+}